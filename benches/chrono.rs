@@ -3,6 +3,7 @@
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
+use chrono::format::Format;
 use chrono::prelude::*;
 use chrono::{DateTime, FixedOffset, Local, Utc, __BenchYearFlags};
 
@@ -60,6 +61,26 @@ fn bench_datetime_to_rfc3339(c: &mut Criterion) {
     c.bench_function("bench_datetime_to_rfc3339", |b| b.iter(|| black_box(dt).to_rfc3339()));
 }
 
+fn bench_datetime_format_numeric(c: &mut Criterion) {
+    let dt = NaiveDate::from_ymd_opt(2018, 1, 11)
+        .unwrap()
+        .and_hms_nano_opt(10, 5, 13, 84_660_000)
+        .unwrap()
+        .and_utc();
+    c.bench_function("bench_datetime_format_numeric", |b| {
+        b.iter(|| black_box(dt).format("%Y%m%d%H%M%S").to_string())
+    });
+}
+
+fn bench_format_reuse(c: &mut Criterion) {
+    let dt = Utc.with_ymd_and_hms(2018, 1, 11, 10, 5, 13).unwrap();
+    let fmt = "%Y-%m-%dT%H:%M:%S%.f%:z";
+    let mut group = c.benchmark_group("format_reuse");
+    group.bench_function("format", |b| b.iter(|| black_box(dt).format(fmt).to_string()));
+    let format = Format::parse(fmt).unwrap();
+    group.bench_function("parsed", |b| b.iter(|| format.apply(&black_box(dt)).to_string()));
+}
+
 fn bench_year_flags_from_year(c: &mut Criterion) {
     c.bench_function("bench_year_flags_from_year", |b| {
         b.iter(|| {
@@ -129,6 +150,8 @@ criterion_group!(
     bench_datetime_from_str,
     bench_datetime_to_rfc2822,
     bench_datetime_to_rfc3339,
+    bench_datetime_format_numeric,
+    bench_format_reuse,
     bench_year_flags_from_year,
     bench_num_days_from_ce,
     bench_get_local_time,
@@ -25,15 +25,19 @@ use rkyv::{Archive, Deserialize, Serialize};
 use crate::format::DelayedFormat;
 #[cfg(feature = "unstable-locales")]
 use crate::format::Locale;
-use crate::format::{parse, parse_and_remainder, ParseError, ParseResult, Parsed, StrftimeItems};
+use crate::format::{
+    parse, parse_and_remainder, parse_http_date, parse_rfc2822_strict,
+    parse_rfc3339_two_digit_year, parse_rfc3339_with_precision, ParseError, ParseResult, Parsed,
+    StrftimeItems,
+};
 use crate::format::{Fixed, Item};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
-use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
+use crate::offset::{AmbiguityStrategy, FixedOffset, LocalResult, Offset, TimeZone, Utc};
 #[allow(deprecated)]
 use crate::Date;
-use crate::{Datelike, Months, TimeDelta, Timelike, Weekday};
+use crate::{Datelike, Months, OverflowPolicy, TimeDelta, Timelike, Weekday};
 
 /// documented at re-export site
 #[cfg(feature = "serde")]
@@ -70,6 +74,21 @@ pub enum SecondsFormat {
     AutoSi,
 }
 
+/// When to use `Z` instead of a numeric offset for UTC in RFC 3339 and ISO 8601 strings.
+///
+/// See the [`DateTime::to_rfc3339_opts_zulu`] function for usage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ZuluPolicy {
+    /// Always use `Z`, converting the date and time to UTC first if the offset is not zero.
+    AlwaysZ,
+
+    /// Never use `Z`, but always a numeric offset such as `+00:00`.
+    NeverZ,
+
+    /// Use `Z` if the offset is exactly zero, and a numeric offset such as `+08:00` otherwise.
+    ZForUtcOnly,
+}
+
 /// ISO 8601 combined date and time with time zone.
 ///
 /// There are some constructors implemented here (the `from_*` methods), but
@@ -198,6 +217,37 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.time() + self.offset.fix()
     }
 
+    /// Returns an iterator over the start of each local day, beginning with the day containing
+    /// `self`.
+    ///
+    /// Each day is resolved in the time zone separately, so days that are not 24 hours long
+    /// because of an offset transition are handled correctly. A midnight that occurs twice yields
+    /// the earlier instant. A midnight that does not exist because of a gap yields the first
+    /// instant after the gap instead, e.g. 01:00 if the clock moves from 00:00 to 01:00. This is
+    /// the [`AmbiguityStrategy::Compatible`] resolution.
+    ///
+    /// The iterator ends when the next day is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2023, 3, 30, 15, 20, 0).unwrap();
+    /// let mut days = dt.iter_local_days();
+    /// assert_eq!(days.next(), Some(Utc.with_ymd_and_hms(2023, 3, 30, 0, 0, 0).unwrap()));
+    /// assert_eq!(days.next(), Some(Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap()));
+    /// assert_eq!(days.next(), Some(Utc.with_ymd_and_hms(2023, 4, 1, 0, 0, 0).unwrap()));
+    /// ```
+    pub fn iter_local_days(self) -> impl Iterator<Item = DateTime<Tz>> {
+        let tz = self.timezone();
+        let days = core::iter::successors(Some(self.date_naive()), NaiveDate::succ_opt);
+        days.filter_map(move |day| {
+            let midnight = day.and_time(NaiveTime::MIN);
+            midnight.and_local_timezone_with(tz.clone(), AmbiguityStrategy::Compatible)
+        })
+    }
+
     /// Returns the number of non-leap seconds since January 1, 1970 0:00:00 UTC
     /// (aka "UNIX timestamp").
     #[inline]
@@ -271,6 +321,29 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.timestamp_nanos()
     }
 
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC, as an `i128`.
+    ///
+    /// Unlike [`DateTime::timestamp_nanos`] this can not overflow: every `DateTime` can be
+    /// represented, from `-8_334_632_851_200_000_000_000` for [`DateTime::MIN_UTC`] to
+    /// `8_210_298_412_799_999_999_999` for [`DateTime::MAX_UTC`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Utc};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2001, 9, 9).unwrap().and_hms_nano_opt(1, 46, 40, 555).unwrap().and_local_timezone(Utc).unwrap();
+    /// assert_eq!(dt.timestamp_nanos_i128(), 1_000_000_000_000_000_555);
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(1066, 10, 14).unwrap().and_hms_opt(9, 0, 0).unwrap().and_local_timezone(Utc).unwrap();
+    /// assert_eq!(dt.timestamp_nanos_i128(), -28_502_722_800_000_000_000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn timestamp_nanos_i128(&self) -> i128 {
+        i128::from(self.timestamp()) * 1_000_000_000 + i128::from(self.timestamp_subsec_nanos())
+    }
+
     /// Returns the number of milliseconds since the last second boundary.
     ///
     /// In event of a leap second this may exceed 999.
@@ -321,6 +394,36 @@ impl<Tz: TimeZone> DateTime<Tz> {
         tz.from_utc_datetime(&self.datetime)
     }
 
+    /// Changes the associated time zone like [`DateTime::with_timezone`], and also returns whether
+    /// the local date and time changed.
+    ///
+    /// The local date and time stays the same if and only if the new offset from UTC equals the
+    /// current one, so the returned `bool` is `true` when the offsets differ. This can be used to
+    /// decide whether a displayed time has to be updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+    ///
+    /// let (same, changed) = dt.with_timezone_reporting(&FixedOffset::east_opt(0).unwrap());
+    /// assert_eq!(same.to_string(), "2023-06-01 12:00:00 +00:00");
+    /// assert!(!changed);
+    ///
+    /// let (other, changed) = dt.with_timezone_reporting(&FixedOffset::east_opt(7200).unwrap());
+    /// assert_eq!(other.to_string(), "2023-06-01 14:00:00 +02:00");
+    /// assert!(changed);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_timezone_reporting<Tz2: TimeZone>(&self, tz: &Tz2) -> (DateTime<Tz2>, bool) {
+        let converted = self.with_timezone(tz);
+        let changed = converted.offset.fix() != self.offset.fix();
+        (converted, changed)
+    }
+
     /// Fix the offset from UTC to its current value, dropping the associated timezone information.
     /// This it useful for converting a generic `DateTime<Tz: Timezone>` to `DateTime<FixedOffset>`.
     #[inline]
@@ -329,6 +432,119 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.with_timezone(&self.offset().fix())
     }
 
+    /// Changes the offset from UTC to the given `offset`, keeping the same instant of time.
+    ///
+    /// This is the same as `self.with_timezone(&offset)`. The local date and time of the result
+    /// are those of the instant as seen from `offset`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Timelike, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2023, 6, 1, 22, 30, 0).unwrap();
+    /// let kst = dt.with_fixed_offset(FixedOffset::east_opt(9 * 3600).unwrap());
+    /// assert_eq!(kst, dt);
+    /// assert_eq!(kst.to_rfc3339(), "2023-06-02T07:30:00+09:00");
+    /// assert_eq!(kst.hour(), 7);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_fixed_offset(&self, offset: FixedOffset) -> DateTime<FixedOffset> {
+        DateTime::from_utc(self.datetime, offset)
+    }
+
+    /// Makes a new `DateTime` with the local date and time replaced, in the same time zone.
+    ///
+    /// This resolves the new local date and time in one step, so it does not pass through
+    /// intermediate values that may not exist like chaining `with_*` methods can.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LocalResult::None`] if the local date and time does not exist in the time zone,
+    /// for example in the gap of a daylight saving time transition, and
+    /// [`LocalResult::Ambiguous`] if it occurs twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2015, 2, 18, 23, 16, 9).unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2016, 2, 29).unwrap();
+    /// let time = NaiveTime::from_hms_opt(1, 2, 3).unwrap();
+    /// assert_eq!(dt.with_date_time(date, time).unwrap(), Utc.with_ymd_and_hms(2016, 2, 29, 1, 2, 3).unwrap());
+    /// ```
+    #[must_use]
+    pub fn with_date_time(&self, date: NaiveDate, time: NaiveTime) -> LocalResult<DateTime<Tz>> {
+        self.timezone().from_local_datetime(&date.and_time(time))
+    }
+
+    /// Returns the `DateTime` with the seconds and fractional seconds of the local time set to
+    /// zero.
+    ///
+    /// The local time is truncated using the current offset, so the result always exists and is
+    /// never earlier than the start of the current local minute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2016, 7, 8, 9, 10, 11).unwrap();
+    /// assert_eq!(dt.truncate_to_minute(), tz.with_ymd_and_hms(2016, 7, 8, 9, 10, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn truncate_to_minute(self) -> DateTime<Tz> {
+        let offset = self.offset.fix();
+        let local = self.naive_local().truncate_to_minute();
+        self.timezone().from_utc_datetime(&(local - offset))
+    }
+
+    /// Returns the `DateTime` with the minutes, seconds and fractional seconds of the local time
+    /// set to zero.
+    ///
+    /// The local time is truncated using the current offset, so the result always exists. In the
+    /// hour after a daylight saving time transition this gives the instant of the transition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2016, 7, 8, 9, 10, 11).unwrap();
+    /// assert_eq!(dt.truncate_to_hour(), tz.with_ymd_and_hms(2016, 7, 8, 9, 0, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn truncate_to_hour(self) -> DateTime<Tz> {
+        let offset = self.offset.fix();
+        let local = self.naive_local().truncate_to_hour();
+        self.timezone().from_utc_datetime(&(local - offset))
+    }
+
+    /// Returns the `DateTime` at local midnight at the start of the same local date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LocalResult::None`] if midnight does not exist in the time zone on this date,
+    /// for example when a daylight saving time transition skips it, and
+    /// [`LocalResult::Ambiguous`] if it occurs twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2016, 7, 8, 9, 10, 11).unwrap();
+    /// assert_eq!(dt.truncate_to_day().unwrap(), Utc.with_ymd_and_hms(2016, 7, 8, 0, 0, 0).unwrap());
+    /// ```
+    #[must_use]
+    pub fn truncate_to_day(self) -> LocalResult<DateTime<Tz>> {
+        self.timezone().from_local_datetime(&self.naive_local().truncate_to_day())
+    }
+
     /// Adds given `Duration` to the current date and time.
     ///
     /// # Errors
@@ -362,6 +578,30 @@ impl<Tz: TimeZone> DateTime<Tz> {
             .single()
     }
 
+    /// Adds given `Months` to the current date and time, with an explicit [`OverflowPolicy`] for
+    /// days that do not exist in the resulting month.
+    ///
+    /// See [`NaiveDate::add_months_with_policy`] for more details on behavior.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The resulting date would be out of range.
+    /// - The day does not exist in the resulting month and the policy is [`OverflowPolicy::Error`].
+    /// - The local time at the resulting date does not exist or is ambiguous, for example during a
+    ///   daylight saving time transition.
+    #[must_use]
+    pub fn add_months_with_policy(
+        self,
+        rhs: Months,
+        policy: OverflowPolicy,
+    ) -> Option<DateTime<Tz>> {
+        self.naive_local()
+            .add_months_with_policy(rhs, policy)?
+            .and_local_timezone(Tz::from_offset(&self.offset))
+            .single()
+    }
+
     /// Subtracts given `Duration` from the current date and time.
     ///
     /// # Errors
@@ -397,6 +637,9 @@ impl<Tz: TimeZone> DateTime<Tz> {
 
     /// Add a duration in [`Days`] to the date part of the `DateTime`.
     ///
+    /// This rejects a resulting local time that is ambiguous; use
+    /// [`DateTime::checked_add_local_days`] to get the earlier of the two instants instead.
+    ///
     /// # Errors
     ///
     /// Returns `None` if:
@@ -427,6 +670,47 @@ impl<Tz: TimeZone> DateTime<Tz> {
             .single()
     }
 
+    /// Add a number of [`Days`] to the local date, keeping the local clock time.
+    ///
+    /// The new local date and time is resolved through the time zone again, so the offset of the
+    /// result may differ from the offset of `self`. This gives "the same clock time `n` days
+    /// later", which is usually what a daily recurring event wants.
+    ///
+    /// Compare this with adding `TimeDelta::days(1)`, which adds exactly 24 hours of physical time
+    /// and keeps the instant in UTC: across a daylight saving time transition the local clock
+    /// time of the result then moves by the size of the transition. For example with a time zone
+    /// that moves from UTC+9 to UTC+8 overnight, 12:00 plus `TimeDelta::days(1)` is 11:00 the
+    /// next day, while `checked_add_local_days(Days::new(1))` is 12:00 the next day.
+    ///
+    /// This is the same as [`DateTime::checked_add_days`], except when the local time at the
+    /// resulting date is ambiguous: `checked_add_days` returns `None`, while this uses the earlier
+    /// of the two instants (the [`AmbiguityStrategy::Earliest`] resolution).
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The resulting date would be out of range.
+    /// - The local time at the resulting date does not exist, because it falls in the gap of a
+    ///   daylight saving time transition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Days, FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(2 * 60 * 60).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2023, 3, 25, 9, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.checked_add_local_days(Days::new(2)),
+    ///     Some(tz.with_ymd_and_hms(2023, 3, 27, 9, 0, 0).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn checked_add_local_days(self, days: Days) -> Option<Self> {
+        let datetime = self.naive_local().checked_add_days(days)?;
+        datetime.and_local_timezone_with(self.timezone(), AmbiguityStrategy::Earliest)
+    }
+
     /// Subtracts another `DateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     #[inline]
@@ -438,6 +722,26 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.signed_duration_since(rhs.borrow().datetime)
     }
 
+    /// Returns the absolute difference between two `DateTime`s as a non-negative `TimeDelta`.
+    ///
+    /// The values are compared in UTC, so the time zones do not have to match. Like
+    /// [`NaiveDateTime::abs_diff`], this never overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeDelta, TimeZone, Utc};
+    ///
+    /// let a = Utc.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+    /// let b = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2023, 3, 1, 14, 0, 0).unwrap();
+    /// assert_eq!(a.abs_diff(b), TimeDelta::hours(1));
+    /// assert_eq!(b.abs_diff(a), TimeDelta::hours(1));
+    /// ```
+    #[must_use]
+    pub fn abs_diff<Tz2: TimeZone>(self, other: impl Borrow<DateTime<Tz2>>) -> TimeDelta {
+        self.datetime.abs_diff(other.borrow().datetime)
+    }
+
     /// Returns a view to the naive UTC datetime.
     #[inline]
     #[must_use]
@@ -458,11 +762,39 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime + self.offset.fix()
     }
 
+    /// Compares two `DateTime`s by their local date and time, the wall-clock time shown in their
+    /// own time zones.
+    ///
+    /// The `PartialEq`, `PartialOrd` and `Ord` implementations of `DateTime` instead compare the
+    /// instants in time, regardless of the time zones. The two orderings differ when the values
+    /// have different offsets: 10:00 in Paris is an earlier instant than 09:00 in New York, but a
+    /// later wall-clock time. Values that are equal by this method need not be the same instant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    /// use std::cmp::Ordering;
+    ///
+    /// let paris = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2023, 1, 2, 10, 0, 0).unwrap();
+    /// let new_york = FixedOffset::west_opt(5 * 3600).unwrap().with_ymd_and_hms(2023, 1, 2, 9, 0, 0).unwrap();
+    ///
+    /// assert!(paris < new_york);
+    /// assert_eq!(paris.cmp_local(&new_york), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub fn cmp_local<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> Ordering {
+        self.naive_local().cmp(&other.naive_local())
+    }
+
     /// Retrieve the elapsed years from now to the given [`DateTime`].
     ///
+    /// Like [`NaiveDate::years_since`], a year that started on February 29 is only complete on
+    /// March 1 in common years.
+    ///
     /// # Errors
     ///
-    /// Returns `None` if `base < self`.
+    /// Returns `None` if `base > self`.
     #[must_use]
     pub fn years_since(&self, base: Self) -> Option<u32> {
         let mut years = self.year() - base.year();
@@ -480,6 +812,113 @@ impl<Tz: TimeZone> DateTime<Tz> {
         }
     }
 
+    /// Splits the difference `self - other` into whole years, months and days plus a residual
+    /// time, computed on the local date and time in the time zone of `self`.
+    ///
+    /// `other` is first converted to the time zone of `self`. Then:
+    ///
+    /// - The number of months is the month difference between the two local dates, minus one if
+    ///   the day and time of `self` are earlier in the month than those of `other`. Like
+    ///   [`DateTime::years_since`], a month that started on January 31 is only complete on
+    ///   March 1.
+    /// - That number of months is added to `other` with [`NaiveDateTime::shift_months`], which
+    ///   uses the last day of the month if the day does not exist in the resulting month.
+    /// - The number of days and the residual time are the remaining wall-clock difference
+    ///   between that intermediate value and `self`.
+    ///
+    /// The result is floored like [`TimeDelta::hms`]: only the years can be negative, the months
+    /// are in `0..12`, the days are in `0..31` and the residual is in `0..24` hours. So if `self`
+    /// lies before `other`, the breakdown is something like "-1 years, 11 months, 3 days".
+    ///
+    /// Because everything is computed on wall-clock time, the residual is not affected by
+    /// daylight saving time transitions in between: from 12:00 on the day before a transition
+    /// to 12:00 on the day after is exactly two days, even though 47 or 49 hours have elapsed.
+    /// Use [`DateTime::signed_duration_since`] for the exact elapsed time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the intermediate value is out of range for a [`NaiveDateTime`], which
+    /// can only happen if `self` lies before `other` and less than a month after
+    /// [`NaiveDateTime::MIN`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// let a = Utc.with_ymd_and_hms(2020, 1, 31, 10, 0, 0).unwrap();
+    /// let b = Utc.with_ymd_and_hms(2022, 5, 4, 15, 6, 7).unwrap();
+    /// assert_eq!(b.calendar_diff(&a), Some((2, 3, 4, TimeDelta::seconds(5 * 3600 + 6 * 60 + 7))));
+    /// assert_eq!(a.calendar_diff(&b), Some((-3, 8, 26, TimeDelta::seconds(18 * 3600 + 53 * 60 + 53))));
+    /// ```
+    #[must_use]
+    pub fn calendar_diff(&self, other: &DateTime<Tz>) -> Option<(i32, u32, u32, TimeDelta)> {
+        let start = other.with_timezone(&self.timezone()).naive_local();
+        let end = self.naive_local();
+
+        let mut months =
+            (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+        if (end.day(), end.time()) < (start.day(), start.time()) {
+            months -= 1;
+        }
+        let intermediate = start.shift_months(months)?;
+
+        let rest = end.signed_duration_since(intermediate);
+        let days = rest.num_days();
+        Some((
+            months.div_euclid(12),
+            months.rem_euclid(12) as u32,
+            days as u32,
+            rest - TimeDelta::days(days),
+        ))
+    }
+
+    /// Parses a string with the specified format string as a local date and time in the given
+    /// time zone.
+    ///
+    /// Unlike [`DateTime::parse_from_str`] the input does not need an offset: the parsed date and
+    /// time are interpreted as wall-clock time in `tz`, resolving daylight saving time through
+    /// [`TimeZone::from_local_datetime`]. The result is a [`LocalResult`], which is
+    /// [`LocalResult::None`] if the local time falls in a gap and [`LocalResult::Ambiguous`] if
+    /// it occurs twice.
+    ///
+    /// If the input does contain an offset or a timestamp, it picks the instant, and the result
+    /// is [`LocalResult::Single`] if the offset is valid in `tz` at that time.
+    ///
+    /// See the [`format::strftime` module](crate::format::strftime) for supported format
+    /// sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the input does not match `fmt`, does not contain a complete
+    /// date and time, or contains an offset that does not occur in `tz` at that time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, LocalResult, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// assert_eq!(
+    ///     DateTime::parse_in_timezone("2023-06-01 12:30", "%Y-%m-%d %H:%M", &tz),
+    ///     Ok(LocalResult::Single(tz.with_ymd_and_hms(2023, 6, 1, 12, 30, 0).unwrap()))
+    /// );
+    /// assert!(DateTime::parse_in_timezone("2023-06-01 12:30 +0200", "%Y-%m-%d %H:%M %z", &tz)
+    ///     .is_err());
+    /// ```
+    pub fn parse_in_timezone(
+        s: &str,
+        fmt: &str,
+        tz: &Tz,
+    ) -> ParseResult<LocalResult<DateTime<Tz>>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        if parsed.offset.is_some() || parsed.timestamp.is_some() {
+            return parsed.to_datetime_with_timezone(tz).map(LocalResult::Single);
+        }
+        Ok(tz.from_local_datetime(&parsed.to_naive_datetime_with_offset(0)?))
+    }
+
     /// The minimum possible `DateTime<Utc>`.
     pub const MIN_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MIN, offset: Utc };
     /// The maximum possible `DateTime<Utc>`.
@@ -615,6 +1054,68 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`, and
+    /// rejects the obsolete syntax that [`DateTime::parse_from_rfc2822`] accepts.
+    ///
+    /// This is meant for validating generated values, such as the `Date` header of an outgoing
+    /// email. Besides the canonical form `Day, DD Mon YYYY HH:MM:SS +ZZZZ` the day of week and
+    /// the seconds may be omitted, and the day may have a single digit. The input is rejected if
+    /// it contains:
+    ///
+    /// - whitespace other than a single space between the fields, or around the input;
+    /// - comments;
+    /// - two- or three-digit years;
+    /// - a named zone like `GMT` or `EST`, or a single-letter military zone.
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// assert_eq!(
+    ///     DateTime::<FixedOffset>::parse_from_rfc2822_strict("Wed, 18 Feb 2015 23:16:09 +0000"),
+    ///     Ok(FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2015, 2, 18, 23, 16, 9).unwrap())
+    /// );
+    /// assert!(DateTime::<FixedOffset>::parse_from_rfc2822_strict("Wed, 18 Feb 2015 23:16:09 GMT")
+    ///     .is_err());
+    /// assert!(DateTime::<FixedOffset>::parse_from_rfc2822_strict("Wed, 18 Feb 15 23:16:09 +0000")
+    ///     .is_err());
+    /// assert!(DateTime::<FixedOffset>::parse_from_rfc2822_strict(
+    ///     "Wed, 18 Feb 2015 23:16:09 +0000 (UTC)"
+    /// )
+    /// .is_err());
+    /// ```
+    pub fn parse_from_rfc2822_strict(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse_rfc2822_strict(&mut parsed, s)?;
+        parsed.to_datetime()
+    }
+
+    /// Parses an HTTP date such as `Sun, 06 Nov 1994 08:49:37 GMT`, as used in HTTP header
+    /// fields like `Date`, `Expires` or `Last-Modified`.
+    ///
+    /// [RFC 7231] requires recipients to accept three formats, which are all in UTC:
+    ///
+    /// - the preferred IMF-fixdate format: `Sun, 06 Nov 1994 08:49:37 GMT`;
+    /// - the obsolete RFC 850 format: `Sunday, 06-Nov-94 08:49:37 GMT`;
+    /// - the obsolete ANSI C `asctime()` format: `Sun Nov  6 08:49:37 1994`.
+    ///
+    /// The two-digit year of the RFC 850 format is interpreted like in
+    /// [`DateTime::parse_from_rfc2822`]: years below 50 are in the 21st century, the others in
+    /// the 20th century. No whitespace other than the single spaces of the formats is allowed.
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// let expected = FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(1994, 11, 6, 8, 49, 37).unwrap();
+    /// assert_eq!(DateTime::parse_from_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(expected));
+    /// assert_eq!(DateTime::parse_from_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(expected));
+    /// assert_eq!(DateTime::parse_from_http_date("Sun Nov  6 08:49:37 1994"), Ok(expected));
+    /// ```
+    ///
+    /// [RFC 7231]: https://www.rfc-editor.org/rfc/rfc7231#section-7.1.1.1
+    pub fn parse_from_http_date(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse_http_date(&mut parsed, s)?;
+        parsed.to_datetime()
+    }
+
     /// Parses an RFC 3339 and ISO 8601 date and time string such as `1996-12-19T16:39:57-08:00`,
     /// then returns a new [`DateTime`] with a parsed [`FixedOffset`].
     ///
@@ -629,6 +1130,17 @@ impl DateTime<FixedOffset> {
     /// instances (rather than periods, ranges, dates, or times). Some valid ISO 8601 values are
     /// also simultaneously valid RFC 3339 values, but not all RFC 3339 values are valid ISO 8601
     /// values (or the other way around).
+    ///
+    /// As in ISO 8601, the fractional seconds may be separated by a comma instead of a period.
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset};
+    /// assert_eq!(
+    ///     DateTime::<FixedOffset>::parse_from_rfc3339("2024-01-01T00:00:00,5Z")?,
+    ///     DateTime::<FixedOffset>::parse_from_rfc3339("2024-01-01T00:00:00.5Z")?
+    /// );
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
     pub fn parse_from_rfc3339(s: &str) -> ParseResult<DateTime<FixedOffset>> {
         const ITEMS: &[Item<'static>] = &[Item::Fixed(Fixed::RFC3339)];
         let mut parsed = Parsed::new();
@@ -636,6 +1148,90 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses an RFC 3339 date and time string like [`DateTime::parse_from_rfc3339`], and also
+    /// returns the number of fractional digits of the seconds in the input.
+    ///
+    /// The number of digits is 0 if the input has no fractional seconds. Trailing zeros are
+    /// counted, so that the value can be written back with the same precision. Digits past the
+    /// ninth are ignored by the parser, so the result is at most 9.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, SecondsFormat};
+    /// let (dt, digits) =
+    ///     DateTime::<FixedOffset>::parse_from_rfc3339_with_precision("2024-05-17T09:30:00.500Z")?;
+    /// assert_eq!(digits, 3);
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, true), "2024-05-17T09:30:00.500Z");
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_from_rfc3339_with_precision(s: &str) -> ParseResult<(DateTime<FixedOffset>, u8)> {
+        let mut parsed = Parsed::new();
+        let precision = parse_rfc3339_with_precision(&mut parsed, s)?;
+        Ok((parsed.to_datetime()?, precision))
+    }
+
+    /// Parses an RFC 3339 date and time string like [`DateTime::parse_from_rfc3339`], but also
+    /// accepts a two-digit year as written by some legacy systems, e.g. `23-01-18T00:00:00Z`.
+    ///
+    /// A two-digit year is interpreted like `%y` when parsing: it is a year from 1970 to 2069, so
+    /// `69` is 2069 and `70` is 1970. A four-digit year is parsed as usual.
+    ///
+    /// [`DateTime::parse_from_rfc3339`] itself keeps rejecting two-digit years.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset};
+    /// let parse = DateTime::<FixedOffset>::parse_from_rfc3339_two_digit_year;
+    /// assert_eq!(parse("23-01-18T00:00:00Z")?.to_rfc3339(), "2023-01-18T00:00:00+00:00");
+    /// assert_eq!(parse("99-12-31T23:59:59+01:00")?.to_rfc3339(), "1999-12-31T23:59:59+01:00");
+    /// assert_eq!(parse("2023-01-18T00:00:00Z")?, parse("23-01-18T00:00:00Z")?);
+    ///
+    /// assert!(DateTime::<FixedOffset>::parse_from_rfc3339("23-01-18T00:00:00Z").is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_from_rfc3339_two_digit_year(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse_rfc3339_two_digit_year(&mut parsed, s)?;
+        parsed.to_datetime()
+    }
+
+    /// Parses a string that is either in RFC 3339 or in RFC 2822 format, then returns a new
+    /// [`DateTime`] with a parsed [`FixedOffset`].
+    ///
+    /// This first tries [`DateTime::parse_from_rfc3339`], then [`DateTime::parse_from_rfc2822`].
+    ///
+    /// # Errors
+    ///
+    /// If both fail, the error of the format the input looks most like is returned: that of
+    /// RFC 3339 if the string starts with a four-digit year and a `-`, and that of RFC 2822
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// let expected = FixedOffset::east_opt(2 * 3600).unwrap().with_ymd_and_hms(2003, 7, 1, 10, 52, 37).unwrap();
+    /// assert_eq!(DateTime::<FixedOffset>::parse_from_any_rfc("2003-07-01T10:52:37+02:00"), Ok(expected));
+    /// assert_eq!(DateTime::<FixedOffset>::parse_from_any_rfc("Tue, 1 Jul 2003 10:52:37 +0200"), Ok(expected));
+    /// assert!(DateTime::<FixedOffset>::parse_from_any_rfc("2003/07/01 10:52:37").is_err());
+    /// ```
+    pub fn parse_from_any_rfc(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let rfc3339_err = match DateTime::<FixedOffset>::parse_from_rfc3339(s) {
+            Ok(dt) => return Ok(dt),
+            Err(e) => e,
+        };
+        let rfc2822_err = match DateTime::<FixedOffset>::parse_from_rfc2822(s) {
+            Ok(dt) => return Ok(dt),
+            Err(e) => e,
+        };
+        let bytes = s.as_bytes();
+        let looks_like_rfc3339 =
+            bytes.len() > 4 && bytes[..4].iter().all(u8::is_ascii_digit) && bytes[4] == b'-';
+        Err(if looks_like_rfc3339 { rfc3339_err } else { rfc2822_err })
+    }
+
     /// Parses a string with the specified format string and returns a new
     /// [`DateTime`] with a parsed [`FixedOffset`].
     ///
@@ -662,6 +1258,35 @@ impl DateTime<FixedOffset> {
         parsed.to_datetime()
     }
 
+    /// Parses a string with the specified format string and returns a new
+    /// [`DateTime`] with a parsed [`FixedOffset`], interpreting a two-digit year (`%y` or `%g`)
+    /// as a year within the 100 years starting at `pivot`.
+    ///
+    /// See [`NaiveDate::parse_from_str_with_pivot`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let dt = DateTime::<FixedOffset>::parse_from_str_with_pivot(
+    ///     "12/04/70 09:30 +0200", "%d/%m/%y %H:%M %z", 2000);
+    /// assert_eq!(
+    ///     dt,
+    ///     Ok(FixedOffset::east_opt(2 * 3600).unwrap().with_ymd_and_hms(2070, 4, 12, 9, 30, 0).unwrap())
+    /// );
+    /// ```
+    pub fn parse_from_str_with_pivot(
+        s: &str,
+        fmt: &str,
+        pivot: i32,
+    ) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.set_two_digit_year_pivot(pivot)?;
+        parsed.to_datetime()
+    }
+
     /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value, and a
     /// slice with the remaining portion of the string.
     ///
@@ -698,6 +1323,129 @@ impl DateTime<FixedOffset> {
 }
 
 impl DateTime<Utc> {
+    /// Makes a new `DateTime<Utc>` from a UTC datetime. Unlike [`DateTime::from_utc`] this is
+    /// usable in const contexts.
+    #[inline]
+    pub(crate) const fn from_naive_utc(datetime: NaiveDateTime) -> DateTime<Utc> {
+        DateTime { datetime, offset: Utc }
+    }
+
+    /// Makes a new `DateTime<Utc>` from the number of non-leap seconds since January 1, 1970 UTC
+    /// (a UNIX timestamp).
+    ///
+    /// This is the inverse of [`DateTime::timestamp`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `secs` is out of range for a `DateTime`, that is less than
+    /// `-8_334_632_851_200` ([`DateTime::MIN_UTC`]) or greater than `8_210_298_412_799`
+    /// ([`DateTime::MAX_UTC`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    ///
+    /// let dt = DateTime::<Utc>::from_timestamp_secs(1_431_648_000).unwrap();
+    /// assert_eq!(dt.to_string(), "2015-05-15 00:00:00 UTC");
+    /// assert_eq!(dt.timestamp(), 1_431_648_000);
+    ///
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_secs(i64::MAX), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_timestamp_secs(secs: i64) -> Option<DateTime<Utc>> {
+        NaiveDateTime::from_timestamp_opt(secs, 0).map(DateTime::from_naive_utc)
+    }
+
+    /// Returns the number of milliseconds since January 1, 1970 UTC as an `f64`, the time value
+    /// used by JavaScript `Date` (as returned by `Date.prototype.getTime()`).
+    ///
+    /// The sub-millisecond part of `self` is discarded, rounding towards the past like
+    /// [`DateTime::timestamp_millis`]. Like in JavaScript leap seconds are not counted, so a
+    /// leap second maps to the milliseconds of the following second. Every `DateTime<Utc>` is
+    /// exactly representable as an `f64` number of milliseconds, but values beyond
+    /// ±8,640,000,000,000,000 milliseconds (April 20, 271821 BCE ~ September 13, 275760 CE) are
+    /// invalid dates in JavaScript.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Utc};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2001, 9, 9)
+    ///     .unwrap()
+    ///     .and_hms_micro_opt(1, 46, 40, 555_999)
+    ///     .unwrap()
+    ///     .and_local_timezone(Utc)
+    ///     .unwrap();
+    /// assert_eq!(dt.to_js_millis(), 1_000_000_000_555.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_js_millis(&self) -> f64 {
+        self.timestamp_millis() as f64
+    }
+
+    /// Makes a new `DateTime<Utc>` from a JavaScript `Date` time value, the number of
+    /// milliseconds since January 1, 1970 UTC as an `f64`.
+    ///
+    /// A fractional part of `ms` is truncated towards zero, as JavaScript does when constructing a
+    /// `Date`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `ms` is not a valid JavaScript time value, i.e. it is NaN or its
+    /// magnitude is larger than 8,640,000,000,000,000, or if it is out of range for a `DateTime`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    ///
+    /// let dt = DateTime::<Utc>::from_js_millis(1_000_000_000_555.0).unwrap();
+    /// assert_eq!(dt.to_string(), "2001-09-09 01:46:40.555 UTC");
+    /// assert_eq!(dt.to_js_millis(), 1_000_000_000_555.0);
+    ///
+    /// assert!(DateTime::<Utc>::from_js_millis(f64::NAN).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_js_millis(ms: f64) -> Option<DateTime<Utc>> {
+        const MAX_JS_MILLIS: f64 = 8_640_000_000_000_000.0;
+        if !(-MAX_JS_MILLIS..=MAX_JS_MILLIS).contains(&ms) {
+            return None;
+        }
+        NaiveDateTime::from_timestamp_millis(ms as i64).map(DateTime::from_naive_utc)
+    }
+
+    /// Makes a new `DateTime<Utc>` from the number of non-leap nanoseconds since January 1, 1970
+    /// UTC, given as an `i128`.
+    ///
+    /// This is the inverse of [`DateTime::timestamp_nanos_i128`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `nanos` is out of range for a `DateTime`, that is less than
+    /// `-8_334_632_851_200_000_000_000` ([`DateTime::MIN_UTC`]) or greater than
+    /// `8_210_298_412_799_999_999_999` ([`DateTime::MAX_UTC`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    ///
+    /// let dt = DateTime::<Utc>::from_timestamp_nanos_i128(-28_502_722_800_000_000_000).unwrap();
+    /// assert_eq!(dt.to_string(), "1066-10-14 09:00:00 UTC");
+    ///
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(i128::MAX), None);
+    /// ```
+    #[must_use]
+    pub fn from_timestamp_nanos_i128(nanos: i128) -> Option<DateTime<Utc>> {
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+        let nsecs = nanos.rem_euclid(1_000_000_000) as u32;
+        NaiveDateTime::from_timestamp_opt(secs, nsecs).map(DateTime::from_naive_utc)
+    }
+
     /// Parses an RFC 2822 date-and-time string into a `DateTime<Utc>` value.
     ///
     /// This parses valid RFC 2822 datetime values (such as `Tue, 1 Jul 2003 10:52:37 +0200`)
@@ -751,6 +1499,45 @@ impl DateTime<Utc> {
     pub fn parse_from_str(s: &str, fmt: &str) -> ParseResult<DateTime<Utc>> {
         DateTime::<FixedOffset>::parse_from_str(s, fmt).map(|result| result.into())
     }
+
+    /// Parses a UNIX timestamp with an optional unit suffix into a `DateTime<Utc>` value.
+    ///
+    /// The timestamp is an integer, optionally followed by one of the units `s` (seconds),
+    /// `ms` (milliseconds), `us` (microseconds) or `ns` (nanoseconds). Without a suffix the
+    /// timestamp is taken to be in seconds.
+    ///
+    /// Returns `None` if the string is not an integer with one of the above suffixes, or if the
+    /// timestamp is out of range for a `DateTime`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let expected = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(DateTime::<Utc>::parse_epoch_with_unit("1672531200"), Some(expected));
+    /// assert_eq!(DateTime::<Utc>::parse_epoch_with_unit("1672531200s"), Some(expected));
+    /// assert_eq!(DateTime::<Utc>::parse_epoch_with_unit("1672531200000ms"), Some(expected));
+    /// assert_eq!(DateTime::<Utc>::parse_epoch_with_unit("1672531200 h"), None);
+    /// ```
+    #[must_use]
+    pub fn parse_epoch_with_unit(s: &str) -> Option<DateTime<Utc>> {
+        let (digits, per_sec) = if let Some(digits) = s.strip_suffix("ns") {
+            (digits, 1_000_000_000)
+        } else if let Some(digits) = s.strip_suffix("us") {
+            (digits, 1_000_000)
+        } else if let Some(digits) = s.strip_suffix("ms") {
+            (digits, 1_000)
+        } else if let Some(digits) = s.strip_suffix('s') {
+            (digits, 1)
+        } else {
+            (s, 1)
+        };
+        let value: i64 = digits.parse().ok()?;
+        let secs = value.div_euclid(per_sec);
+        let nsecs = value.rem_euclid(per_sec) as u32 * (1_000_000_000 / per_sec as u32);
+        NaiveDateTime::from_timestamp_opt(secs, nsecs).map(|dt| dt.and_utc())
+    }
 }
 
 impl<Tz: TimeZone> DateTime<Tz>
@@ -773,6 +1560,37 @@ where
         result
     }
 
+    /// Returns an HTTP date string such as `Sun, 06 Nov 1994 08:49:37 GMT`, as used in HTTP
+    /// header fields like `Last-Modified` or `Expires`.
+    ///
+    /// This is the IMF-fixdate format of [RFC 7231], which is always in UTC: the `DateTime` is
+    /// converted to UTC first. The day and month names are always in English.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date in UTC can not be represented in this format: the year may not be
+    /// negative and can not have more than 4 digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east_opt(9 * 3600).unwrap().with_ymd_and_hms(1994, 11, 6, 17, 49, 37).unwrap();
+    /// assert_eq!(dt.to_http_date(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// ```
+    ///
+    /// [RFC 7231]: https://www.rfc-editor.org/rfc/rfc7231#section-7.1.1.1
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[must_use]
+    pub fn to_http_date(&self) -> String {
+        let mut result = String::with_capacity(29);
+        crate::format::write_http_date(&mut result, self.datetime)
+            .expect("writing http date to string should never fail");
+        result
+    }
+
     /// Returns an RFC 3339 and ISO 8601 date and time string such as `1996-12-19T16:39:57-08:00`.
     #[cfg(any(feature = "alloc", feature = "std", test))]
     #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
@@ -812,10 +1630,52 @@ where
     #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
     #[must_use]
     pub fn to_rfc3339_opts(&self, secform: SecondsFormat, use_z: bool) -> String {
+        let zulu = match use_z {
+            true => ZuluPolicy::ZForUtcOnly,
+            false => ZuluPolicy::NeverZ,
+        };
+        self.to_rfc3339_opts_zulu(secform, zulu)
+    }
+
+    /// Return an RFC 3339 and ISO 8601 date and time string with subseconds
+    /// formatted as per `SecondsFormat`, and the offset as per `ZuluPolicy`.
+    ///
+    /// With [`ZuluPolicy::AlwaysZ`] the date and time is converted to UTC, so that the string
+    /// always ends in `Z`. With [`ZuluPolicy::ZForUtcOnly`] and [`ZuluPolicy::NeverZ`] this is the
+    /// same as [`DateTime::to_rfc3339_opts`] with `use_z` set to `true` and `false` respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, SecondsFormat, TimeZone, ZuluPolicy};
+    /// let utc = FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2018, 1, 26, 18, 30, 9).unwrap();
+    /// assert_eq!(utc.to_rfc3339_opts_zulu(SecondsFormat::Secs, ZuluPolicy::AlwaysZ),
+    ///            "2018-01-26T18:30:09Z");
+    /// assert_eq!(utc.to_rfc3339_opts_zulu(SecondsFormat::Secs, ZuluPolicy::ZForUtcOnly),
+    ///            "2018-01-26T18:30:09Z");
+    /// assert_eq!(utc.to_rfc3339_opts_zulu(SecondsFormat::Secs, ZuluPolicy::NeverZ),
+    ///            "2018-01-26T18:30:09+00:00");
+    ///
+    /// let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    /// let dt = pst.with_ymd_and_hms(2018, 1, 26, 10, 30, 9).unwrap();
+    /// assert_eq!(dt.to_rfc3339_opts_zulu(SecondsFormat::Secs, ZuluPolicy::AlwaysZ),
+    ///            "2018-01-26T02:30:09Z");
+    /// assert_eq!(dt.to_rfc3339_opts_zulu(SecondsFormat::Secs, ZuluPolicy::ZForUtcOnly),
+    ///            "2018-01-26T10:30:09+08:00");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[must_use]
+    pub fn to_rfc3339_opts_zulu(&self, secform: SecondsFormat, zulu: ZuluPolicy) -> String {
         use crate::format::Numeric::*;
         use crate::format::Pad::Zero;
         use crate::SecondsFormat::*;
 
+        if zulu == ZuluPolicy::AlwaysZ && self.offset().fix().local_minus_utc() != 0 {
+            let utc = self.with_timezone(&Utc);
+            return utc.to_rfc3339_opts_zulu(secform, ZuluPolicy::ZForUtcOnly);
+        }
+
         const PREFIX: &[Item<'static>] = &[
             Item::Numeric(Year, Zero),
             Item::Literal("-"),
@@ -838,10 +1698,9 @@ where
             AutoSi => Some(Item::Fixed(Fixed::Nanosecond)),
         };
 
-        let tzitem = Item::Fixed(if use_z {
-            Fixed::TimezoneOffsetColonZ
-        } else {
-            Fixed::TimezoneOffsetColon
+        let tzitem = Item::Fixed(match zulu {
+            ZuluPolicy::NeverZ => Fixed::TimezoneOffsetColon,
+            _ => Fixed::TimezoneOffsetColonZ,
         });
 
         match ssitem {
@@ -884,6 +1743,51 @@ where
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Formats the UTC instant of this date and time with the specified formatting items.
+    ///
+    /// The date, time and offset fields are those of `self.with_timezone(&Utc)`, regardless of
+    /// the time zone of `self`.
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[inline]
+    #[must_use]
+    pub fn format_utc_with_items<'a, I, B>(&self, items: I) -> DelayedFormat<I>
+    where
+        I: Iterator<Item = B> + Clone,
+        B: Borrow<Item<'a>>,
+    {
+        DelayedFormat::new_with_offset(
+            Some(self.datetime.date()),
+            Some(self.datetime.time()),
+            &Utc,
+            items,
+        )
+    }
+
+    /// Formats the UTC instant of this date and time per the specified format string.
+    ///
+    /// This is a shorthand for `self.with_timezone(&Utc).format(fmt)`: `%H:%M` shows the UTC
+    /// time and `%z` renders as `+0000`, whatever the time zone of `self` is.
+    ///
+    /// See the [`crate::format::strftime`] module for the supported escape sequences.
+    ///
+    /// # Example
+    /// ```rust
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// let dt = tz.with_ymd_and_hms(2017, 04, 02, 08, 50, 32).unwrap();
+    /// assert_eq!(dt.format("%d/%m/%Y %H:%M %z").to_string(), "02/04/2017 08:50 +0900");
+    /// assert_eq!(dt.format_utc("%d/%m/%Y %H:%M %z").to_string(), "01/04/2017 23:50 +0000");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[inline]
+    #[must_use]
+    pub fn format_utc<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
+        self.format_utc_with_items(StrftimeItems::new(fmt))
+    }
+
     /// Formats the combined date and time with the specified formatting items and locale.
     #[cfg(feature = "unstable-locales")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable-locales")))]
@@ -1116,6 +2020,7 @@ impl<Tz: TimeZone> Timelike for DateTime<Tz> {
     ///
     /// # Errors
     ///
+    /// Returns `None` if:
     /// - The value for `minute` is invalid.
     /// - The local time at the resulting date does not exist or is ambiguous, for example during a
     ///   daylight saving time transition.
@@ -1144,7 +2049,6 @@ impl<Tz: TimeZone> Timelike for DateTime<Tz> {
 
     /// Makes a new `DateTime` with nanoseconds since the whole non-leap second changed.
     ///
-    /// Returns `None` when the resulting `NaiveDateTime` would be invalid.
     /// As with the [`NaiveDateTime::nanosecond`] method,
     /// the input range can exceed 1,000,000,000 for leap seconds.
     ///
@@ -1152,7 +2056,10 @@ impl<Tz: TimeZone> Timelike for DateTime<Tz> {
     ///
     /// # Errors
     ///
-    /// Returns `None` if `nanosecond >= 2,000,000,000`.
+    /// Returns `None` if:
+    /// - `nanosecond >= 2,000,000,000`.
+    /// - The local time at the resulting date does not exist or is ambiguous, for example during a
+    ///   daylight saving time transition.
     #[inline]
     fn with_nanosecond(&self, nano: u32) -> Option<DateTime<Tz>> {
         map_local(self, |datetime| datetime.with_nanosecond(nano))
@@ -1226,8 +2133,28 @@ impl<Tz: TimeZone> AddAssign<TimeDelta> for DateTime<Tz> {
 impl<Tz: TimeZone> Add<Months> for DateTime<Tz> {
     type Output = DateTime<Tz>;
 
+    /// Add a number of months to the local date, keeping the local time of day.
+    ///
+    /// The day of month is clamped to the last day of the resulting month, and the result is
+    /// resolved in the time zone again, so the offset may differ from that of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases where [`DateTime::checked_add_months`] returns `None`: if the
+    /// resulting date would be out of range, or if the local time does not exist or is ambiguous
+    /// in the time zone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Months, TimeZone, Utc};
+    ///
+    /// let dt = Utc.with_ymd_and_hms(2023, 1, 31, 12, 0, 0).unwrap();
+    /// assert_eq!(dt + Months::new(1), Utc.with_ymd_and_hms(2023, 2, 28, 12, 0, 0).unwrap());
+    /// ```
     fn add(self, rhs: Months) -> Self::Output {
-        self.checked_add_months(rhs).unwrap()
+        self.checked_add_months(rhs)
+            .expect("`DateTime + Months` out of range, or local time is ambiguous or nonexistent")
     }
 }
 
@@ -1253,8 +2180,19 @@ impl<Tz: TimeZone> SubAssign<TimeDelta> for DateTime<Tz> {
 impl<Tz: TimeZone> Sub<Months> for DateTime<Tz> {
     type Output = DateTime<Tz>;
 
+    /// Subtract a number of months from the local date, keeping the local time of day.
+    ///
+    /// The day of month is clamped to the last day of the resulting month, and the result is
+    /// resolved in the time zone again, so the offset may differ from that of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases where [`DateTime::checked_sub_months`] returns `None`: if the
+    /// resulting date would be out of range, or if the local time does not exist or is ambiguous
+    /// in the time zone.
     fn sub(self, rhs: Months) -> Self::Output {
-        self.checked_sub_months(rhs).unwrap()
+        self.checked_sub_months(rhs)
+            .expect("`DateTime - Months` out of range, or local time is ambiguous or nonexistent")
     }
 }
 
@@ -1279,16 +2217,38 @@ impl<Tz: TimeZone> Sub<&DateTime<Tz>> for DateTime<Tz> {
 impl<Tz: TimeZone> Add<Days> for DateTime<Tz> {
     type Output = DateTime<Tz>;
 
+    /// Add a number of days to the local date, keeping the local time of day.
+    ///
+    /// The result is resolved in the time zone again, so across an offset transition this is not
+    /// the same as adding a multiple of 24 hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases where [`DateTime::checked_add_days`] returns `None`: if the
+    /// resulting date would be out of range, or if the local time does not exist or is ambiguous
+    /// in the time zone.
     fn add(self, days: Days) -> Self::Output {
-        self.checked_add_days(days).unwrap()
+        self.checked_add_days(days)
+            .expect("`DateTime + Days` out of range, or local time is ambiguous or nonexistent")
     }
 }
 
 impl<Tz: TimeZone> Sub<Days> for DateTime<Tz> {
     type Output = DateTime<Tz>;
 
+    /// Subtract a number of days from the local date, keeping the local time of day.
+    ///
+    /// The result is resolved in the time zone again, so across an offset transition this is not
+    /// the same as subtracting a multiple of 24 hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases where [`DateTime::checked_sub_days`] returns `None`: if the
+    /// resulting date would be out of range, or if the local time does not exist or is ambiguous
+    /// in the time zone.
     fn sub(self, days: Days) -> Self::Output {
-        self.checked_sub_days(days).unwrap()
+        self.checked_sub_days(days)
+            .expect("`DateTime - Days` out of range, or local time is ambiguous or nonexistent")
     }
 }
 
@@ -1456,7 +2416,7 @@ impl From<DateTime<Utc>> for js_sys::Date {
     /// any values that have a millisecond timestamp value greater/less than ±8,640,000,000,000,000
     /// (April 20, 271821 BCE ~ September 13, 275760 CE) will become invalid dates in JS.
     fn from(date: DateTime<Utc>) -> js_sys::Date {
-        let js_millis = wasm_bindgen::JsValue::from_f64(date.timestamp_millis() as f64);
+        let js_millis = wasm_bindgen::JsValue::from_f64(date.to_js_millis());
         js_sys::Date::new(&js_millis)
     }
 }
@@ -5,7 +5,9 @@ use crate::naive::{NaiveDate, NaiveTime};
 use crate::offset::{FixedOffset, TimeZone, Utc};
 #[cfg(feature = "clock")]
 use crate::offset::{Local, Offset};
-use crate::{Datelike, Days, LocalResult, Months, NaiveDateTime, TimeDelta};
+use crate::{
+    Datelike, Days, LocalResult, Months, NaiveDateTime, SecondsFormat, TimeDelta, Timelike,
+};
 
 #[derive(Clone)]
 struct DstTester;
@@ -125,6 +127,450 @@ impl TimeZone for DstTester {
     }
 }
 
+/// A time zone at UTC-3 that observes UTC-2 in the southern summer, with both transitions at
+/// local midnight, like Brazil used to: the clock moves from 00:00 to 01:00 on 2014-10-19, and
+/// from 00:00 back to 23:00 on 2015-02-22.
+#[derive(Clone)]
+struct MidnightDstTester;
+
+impl MidnightDstTester {
+    fn standard_offset() -> FixedOffset {
+        FixedOffset::west_opt(3 * 60 * 60).unwrap()
+    }
+    fn summer_offset() -> FixedOffset {
+        FixedOffset::west_opt(2 * 60 * 60).unwrap()
+    }
+}
+
+impl TimeZone for MidnightDstTester {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &Self::Offset) -> Self {
+        MidnightDstTester
+    }
+
+    fn offset_from_local_date(&self, _: &NaiveDate) -> LocalResult<Self::Offset> {
+        unimplemented!()
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<Self::Offset> {
+        let mut valid = [MidnightDstTester::summer_offset(), MidnightDstTester::standard_offset()]
+            .into_iter()
+            .filter(|&offset| self.offset_from_utc_datetime(&(*local - offset)) == offset);
+        match (valid.next(), valid.next()) {
+            (None, _) => LocalResult::None,
+            (Some(offset), None) => LocalResult::Single(offset),
+            (Some(earlier), Some(later)) => LocalResult::Ambiguous(earlier, later),
+        }
+    }
+
+    fn offset_from_utc_date(&self, _: &NaiveDate) -> Self::Offset {
+        unimplemented!()
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+        let ymdh = |y, m, d, h| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, 0, 0);
+        if *utc >= ymdh(2014, 10, 19, 3).unwrap() && *utc < ymdh(2015, 2, 22, 2).unwrap() {
+            MidnightDstTester::summer_offset()
+        } else {
+            MidnightDstTester::standard_offset()
+        }
+    }
+}
+
+/// A time zone at UTC+1 that moves to UTC at local 01:00 on 2014-10-26, so the clock goes back
+/// to midnight and the hour starting at local midnight occurs twice.
+#[derive(Clone)]
+struct MidnightFoldTester;
+
+impl MidnightFoldTester {
+    fn before_offset() -> FixedOffset {
+        FixedOffset::east_opt(60 * 60).unwrap()
+    }
+    fn after_offset() -> FixedOffset {
+        FixedOffset::east_opt(0).unwrap()
+    }
+}
+
+impl TimeZone for MidnightFoldTester {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &Self::Offset) -> Self {
+        MidnightFoldTester
+    }
+
+    fn offset_from_local_date(&self, _: &NaiveDate) -> LocalResult<Self::Offset> {
+        unimplemented!()
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<Self::Offset> {
+        let mut valid = [MidnightFoldTester::before_offset(), MidnightFoldTester::after_offset()]
+            .into_iter()
+            .filter(|&offset| self.offset_from_utc_datetime(&(*local - offset)) == offset);
+        match (valid.next(), valid.next()) {
+            (None, _) => LocalResult::None,
+            (Some(offset), None) => LocalResult::Single(offset),
+            (Some(earlier), Some(later)) => LocalResult::Ambiguous(earlier, later),
+        }
+    }
+
+    fn offset_from_utc_date(&self, _: &NaiveDate) -> Self::Offset {
+        unimplemented!()
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+        let transition = NaiveDate::from_ymd_opt(2014, 10, 26).unwrap().and_hms_opt(0, 0, 0);
+        if *utc < transition.unwrap() {
+            MidnightFoldTester::before_offset()
+        } else {
+            MidnightFoldTester::after_offset()
+        }
+    }
+}
+
+#[test]
+fn test_datetime_with_date_time() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    let dt = DstTester.with_ymd_and_hms(2014, 1, 6, 7, 8, 9).unwrap();
+
+    // summer and winter time
+    assert_eq!(
+        dt.with_date_time(ymd(2014, 6, 1), hms(12, 0, 0)),
+        LocalResult::Single(DstTester.with_ymd_and_hms(2014, 6, 1, 12, 0, 0).unwrap())
+    );
+    assert_eq!(
+        dt.with_date_time(ymd(2014, 6, 1), hms(12, 0, 0)).unwrap().offset(),
+        &DstTester::winter_offset()
+    );
+    assert_eq!(
+        dt.with_date_time(ymd(2014, 12, 1), hms(12, 0, 0)).unwrap().offset(),
+        &DstTester::summer_offset()
+    );
+
+    // ambiguous local time when moving to winter time
+    let ambiguous = dt.with_date_time(ymd(2014, 4, 15), hms(1, 30, 0));
+    match ambiguous {
+        LocalResult::Ambiguous(earliest, latest) => {
+            assert_eq!(earliest.naive_local(), ymd(2014, 4, 15).and_time(hms(1, 30, 0)));
+            assert_eq!(latest.naive_local(), ymd(2014, 4, 15).and_time(hms(1, 30, 0)));
+            assert_eq!(earliest.offset(), &DstTester::winter_offset());
+            assert_eq!(latest.offset(), &DstTester::summer_offset());
+        }
+        _ => panic!("expected an ambiguous result, got {:?}", ambiguous),
+    }
+
+    // non-existing local time when moving to summer time
+    assert_eq!(dt.with_date_time(ymd(2014, 9, 15), hms(2, 30, 0)), LocalResult::None);
+    assert!(dt.with_date_time(ymd(2014, 9, 15), hms(3, 0, 0)).single().is_some());
+
+    // a single step avoids the intermediate non-existing time of chaining setters
+    let before_gap = DstTester.with_ymd_and_hms(2014, 9, 14, 2, 30, 0).unwrap();
+    assert_eq!(before_gap.with_day(15), None);
+    assert!(before_gap.with_date_time(ymd(2014, 9, 15), hms(3, 30, 0)).single().is_some());
+}
+
+#[test]
+fn test_datetime_with_fields_dst() {
+    let naive = |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s);
+
+    // the setters never pick an offset for a local time in the gap when moving to summer time,
+    // 2014-09-15 02:00 to 03:00.
+    let dt = DstTester.with_ymd_and_hms(2014, 9, 15, 3, 30, 0).unwrap();
+    assert_eq!(dt.with_year(2013).unwrap().offset(), &DstTester::summer_offset());
+    let dt = DstTester.with_ymd_and_hms(2014, 8, 15, 2, 30, 0).unwrap();
+    assert_eq!(dt.with_month(9), None);
+    assert_eq!(dt.with_month0(8), None);
+    assert!(dt.with_month(10).is_some());
+    let dt = DstTester.with_ymd_and_hms(2014, 9, 10, 2, 30, 0).unwrap();
+    assert_eq!(dt.with_day(15), None);
+    assert_eq!(dt.with_day0(14), None);
+    assert!(dt.with_day(16).is_some());
+    let dt = DstTester.with_ymd_and_hms(2014, 1, 10, 2, 30, 0).unwrap();
+    assert_eq!(dt.with_ordinal(258), None);
+    assert_eq!(dt.with_ordinal0(257), None);
+    assert_eq!(dt.with_ordinal(257).unwrap().naive_local(), naive(2014, 9, 14, 2, 30, 0).unwrap());
+    let dt = DstTester.with_ymd_and_hms(2014, 9, 15, 3, 30, 0).unwrap();
+    assert_eq!(dt.with_hour(2), None);
+    assert_eq!(dt.with_hour(1).unwrap().offset(), &DstTester::winter_offset());
+    assert_eq!(dt.with_hour(3).unwrap(), dt);
+
+    // nor for an ambiguous local time when moving to winter time, 2014-04-15 01:00 to 02:00.
+    let dt = DstTester.with_ymd_and_hms(2014, 4, 14, 1, 30, 0).unwrap();
+    assert_eq!(dt.with_day(15), None);
+    assert_eq!(dt.with_ordinal(105), None);
+    assert_eq!(dt.with_month(3).unwrap().naive_local(), naive(2014, 3, 14, 1, 30, 0).unwrap());
+    let dt = DstTester.with_ymd_and_hms(2014, 4, 15, 2, 30, 0).unwrap();
+    assert_eq!(dt.with_hour(1), None);
+    let ambiguous = DstTester.with_ymd_and_hms(2014, 4, 15, 1, 30, 0);
+    for dt in [ambiguous.earliest().unwrap(), ambiguous.latest().unwrap()] {
+        assert_eq!(dt.with_minute(0), None);
+        assert_eq!(dt.with_second(30), None);
+        assert_eq!(dt.with_nanosecond(1), None);
+        assert!(dt.with_hour(0).is_some());
+    }
+}
+
+#[test]
+fn test_datetime_with_timezone_reporting() {
+    let utc = Utc.with_ymd_and_hms(2014, 6, 1, 12, 0, 0).unwrap();
+    let plus_nine = FixedOffset::east_opt(9 * 3600).unwrap();
+
+    // the same offset
+    let (dt, changed) = utc.with_timezone_reporting(&FixedOffset::east_opt(0).unwrap());
+    assert_eq!((dt, changed), (utc.fixed_offset(), false));
+    assert_eq!(dt.naive_local(), utc.naive_local());
+    let (dt, changed) = utc.with_timezone_reporting(&Utc);
+    assert_eq!((dt, changed), (utc, false));
+
+    // a different offset
+    let (dt, changed) = utc.with_timezone_reporting(&plus_nine);
+    assert_eq!(dt, utc);
+    assert!(changed);
+    assert_eq!(dt.naive_local(), utc.naive_local() + TimeDelta::hours(9));
+    let (back, changed) = dt.with_timezone_reporting(&Utc);
+    assert_eq!((back, changed), (utc, true));
+
+    // a time zone with daylight saving time has the same offset only part of the year
+    let (dt, changed) = utc.with_timezone_reporting(&DstTester);
+    assert_eq!(dt.offset(), &DstTester::winter_offset());
+    assert!(changed);
+    assert!(dt.with_timezone_reporting(&plus_nine).1);
+    let summer = Utc.with_ymd_and_hms(2014, 12, 1, 12, 0, 0).unwrap().with_timezone(&plus_nine);
+    let (dt, changed) = summer.with_timezone_reporting(&DstTester);
+    assert_eq!(dt.offset(), &DstTester::summer_offset());
+    assert!(!changed);
+    assert_eq!(dt.naive_local(), summer.naive_local());
+}
+
+#[test]
+fn test_datetime_cmp_local() {
+    use core::cmp::Ordering;
+
+    let plus_two = FixedOffset::east_opt(2 * 3600).unwrap();
+    let minus_five = FixedOffset::west_opt(5 * 3600).unwrap();
+
+    // the instant order and the local order differ
+    let a = plus_two.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+    let b = minus_five.with_ymd_and_hms(2023, 6, 1, 8, 0, 0).unwrap();
+    assert!(a < b);
+    assert_eq!(a.cmp_local(&b), Ordering::Greater);
+    assert_eq!(b.cmp_local(&a), Ordering::Less);
+
+    // the same instant is a different local time
+    let c = b.with_timezone(&plus_two);
+    assert_eq!(b, c);
+    assert_eq!(b.cmp_local(&c), Ordering::Less);
+
+    // the same local time is a different instant
+    let d = minus_five.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+    assert_ne!(a, d);
+    assert_eq!(a.cmp_local(&d), Ordering::Equal);
+    assert_eq!(a.cmp_local(&a), Ordering::Equal);
+
+    // different time zone types
+    let e = Utc.with_ymd_and_hms(2023, 6, 1, 11, 0, 0).unwrap();
+    assert!(e > a);
+    assert_eq!(a.cmp_local(&e), Ordering::Greater);
+    assert_eq!(e.cmp_local(&a), Ordering::Less);
+
+    // sorting by local time
+    let mut times = [a, b, c, d];
+    times.sort_by(|x, y| x.cmp_local(y));
+    assert_eq!(
+        times.map(|dt| dt.naive_local().time().to_string()),
+        ["08:00:00", "12:00:00", "12:00:00", "15:00:00"]
+    );
+}
+
+#[test]
+fn test_datetime_truncate() {
+    let dt =
+        DstTester.with_ymd_and_hms(2014, 6, 1, 12, 34, 56).unwrap() + TimeDelta::milliseconds(789);
+    assert_eq!(dt.truncate_to_minute(), DstTester.with_ymd_and_hms(2014, 6, 1, 12, 34, 0).unwrap());
+    assert_eq!(dt.truncate_to_hour(), DstTester.with_ymd_and_hms(2014, 6, 1, 12, 0, 0).unwrap());
+    assert_eq!(
+        dt.truncate_to_day(),
+        LocalResult::Single(DstTester.with_ymd_and_hms(2014, 6, 1, 0, 0, 0).unwrap())
+    );
+
+    // the hour after moving to summer time starts at the transition
+    let dt = DstTester.with_ymd_and_hms(2014, 9, 15, 3, 30, 0).unwrap();
+    assert_eq!(dt.truncate_to_hour(), DstTester.with_ymd_and_hms(2014, 9, 15, 3, 0, 0).unwrap());
+    assert_eq!(dt.truncate_to_hour(), dt - TimeDelta::minutes(30));
+
+    // truncation stays within the same occurrence of a repeated hour
+    let ambiguous = DstTester.with_ymd_and_hms(2014, 4, 15, 1, 30, 0);
+    let (earliest, latest) = (ambiguous.earliest().unwrap(), ambiguous.latest().unwrap());
+    assert_eq!(earliest.truncate_to_hour(), earliest - TimeDelta::minutes(30));
+    assert_eq!(latest.truncate_to_hour(), latest - TimeDelta::minutes(30));
+    assert_eq!(latest.truncate_to_minute(), latest);
+
+    // midnight does not exist when moving to summer time at midnight
+    let dt = MidnightDstTester.with_ymd_and_hms(2014, 10, 19, 12, 0, 0).unwrap();
+    assert_eq!(
+        dt.truncate_to_hour(),
+        MidnightDstTester.with_ymd_and_hms(2014, 10, 19, 12, 0, 0).unwrap()
+    );
+    assert_eq!(dt.truncate_to_day(), LocalResult::None);
+
+    // moving back to standard time repeats the last hour of the previous day, not midnight
+    let dt = MidnightDstTester.with_ymd_and_hms(2015, 2, 22, 12, 0, 0).unwrap();
+    let midnight = dt.truncate_to_day().single().unwrap();
+    assert_eq!(midnight.offset(), &MidnightDstTester::standard_offset());
+    assert_eq!(midnight, dt - TimeDelta::hours(12));
+    let repeated = MidnightDstTester.with_ymd_and_hms(2015, 2, 21, 23, 30, 0).latest().unwrap();
+    assert_eq!(
+        repeated.truncate_to_day(),
+        MidnightDstTester.with_ymd_and_hms(2015, 2, 21, 0, 0, 0)
+    );
+}
+
+#[test]
+fn test_and_local_timezone_with() {
+    use crate::AmbiguityStrategy::*;
+
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    let summer = DstTester::summer_offset();
+    let winter = DstTester::winter_offset();
+
+    // unique local time
+    let local = ymdhms(2014, 1, 6, 7, 8, 9);
+    for strategy in [Earliest, Latest, RejectAmbiguous, Compatible] {
+        let dt = local.and_local_timezone_with(DstTester, strategy).unwrap();
+        assert_eq!(dt.naive_local(), local);
+        assert_eq!(*dt.offset(), summer);
+    }
+
+    // fold when switching to winter time
+    let local = ymdhms(2014, 4, 15, 1, 30, 0);
+    let earliest = local.and_local_timezone_with(DstTester, Earliest).unwrap();
+    assert_eq!((earliest.naive_local(), *earliest.offset()), (local, summer));
+    let latest = local.and_local_timezone_with(DstTester, Latest).unwrap();
+    assert_eq!((latest.naive_local(), *latest.offset()), (local, winter));
+    assert!(earliest < latest);
+    assert_eq!(local.and_local_timezone_with(DstTester, Compatible), Some(earliest));
+    assert_eq!(local.and_local_timezone_with(DstTester, RejectAmbiguous), None);
+
+    // gap when switching to summer time
+    let local = ymdhms(2014, 9, 15, 2, 30, 0);
+    assert_eq!(local.and_local_timezone_with(DstTester, Earliest), None);
+    assert_eq!(local.and_local_timezone_with(DstTester, Latest), None);
+    assert_eq!(local.and_local_timezone_with(DstTester, RejectAmbiguous), None);
+    let compatible = local.and_local_timezone_with(DstTester, Compatible).unwrap();
+    assert_eq!(compatible.naive_local(), ymdhms(2014, 9, 15, 3, 30, 0));
+    assert_eq!(*compatible.offset(), summer);
+    assert_eq!(compatible.naive_utc(), local - winter);
+}
+
+#[test]
+fn test_iter_local_days() {
+    let local = |y, m, d, h| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+            .and_local_timezone(MidnightDstTester)
+            .unwrap()
+    };
+
+    // the midnight starting 2014-10-19 does not exist, so the day starts at 01:00
+    let days: Vec<_> = local(2014, 10, 17, 15).iter_local_days().take(4).collect();
+    assert_eq!(
+        days,
+        [
+            local(2014, 10, 17, 0),
+            local(2014, 10, 18, 0),
+            local(2014, 10, 19, 1),
+            local(2014, 10, 20, 0)
+        ]
+    );
+    assert_eq!(days[1].offset(), &MidnightDstTester::standard_offset());
+    assert_eq!(days[2].offset(), &MidnightDstTester::summer_offset());
+    assert_eq!(days[2] - days[1], TimeDelta::hours(24));
+    assert_eq!(days[3] - days[2], TimeDelta::hours(23));
+
+    // 2015-02-21 is 25 hours long
+    let days: Vec<_> = local(2015, 2, 21, 12).iter_local_days().take(3).collect();
+    assert_eq!(days, [local(2015, 2, 21, 0), local(2015, 2, 22, 0), local(2015, 2, 23, 0)]);
+    assert_eq!(days[1] - days[0], TimeDelta::hours(25));
+    assert_eq!(days[1].offset(), &MidnightDstTester::standard_offset());
+
+    // ambiguous midnights resolve to the earlier instant
+    let dt = MidnightFoldTester.with_ymd_and_hms(2014, 10, 25, 12, 0, 0).unwrap();
+    assert!(matches!(
+        MidnightFoldTester.with_ymd_and_hms(2014, 10, 26, 0, 0, 0),
+        LocalResult::Ambiguous(_, _)
+    ));
+    let days: Vec<_> = dt.iter_local_days().take(3).collect();
+    assert_eq!(days[1].offset(), &MidnightFoldTester::before_offset());
+    assert_eq!(days[1], Utc.with_ymd_and_hms(2014, 10, 25, 23, 0, 0).unwrap());
+    assert_eq!(days[1] - days[0], TimeDelta::hours(24));
+    assert_eq!(days[2].offset(), &MidnightFoldTester::after_offset());
+    assert_eq!(days[2] - days[1], TimeDelta::hours(25));
+
+    let last = Utc.from_utc_datetime(&NaiveDateTime::MAX).iter_local_days().last().unwrap();
+    assert_eq!(last.date_naive(), NaiveDate::MAX);
+}
+
+#[test]
+fn test_datetime_calendar_diff() {
+    let utc = |y, m, d, h, n, s| Utc.with_ymd_and_hms(y, m, d, h, n, s).unwrap();
+    let hms = |h, m, s| TimeDelta::from_hms(h, m, s).unwrap();
+
+    let a = utc(2020, 3, 10, 8, 0, 0);
+    assert_eq!(a.calendar_diff(&a), Some((0, 0, 0, TimeDelta::zero())));
+    assert_eq!(utc(2022, 6, 14, 13, 6, 7).calendar_diff(&a), Some((2, 3, 4, hms(5, 6, 7))));
+    assert_eq!(utc(2020, 3, 10, 7, 59, 59).calendar_diff(&a), Some((-1, 11, 28, hms(23, 59, 59))));
+    assert_eq!(utc(2020, 2, 10, 8, 0, 0).calendar_diff(&a), Some((-1, 11, 0, TimeDelta::zero())));
+
+    // a month is only complete once the day of the month is reached
+    let jan31 = utc(2021, 1, 31, 0, 0, 0);
+    assert_eq!(
+        utc(2021, 2, 28, 0, 0, 0).calendar_diff(&jan31),
+        Some((0, 0, 28, TimeDelta::zero()))
+    );
+    assert_eq!(utc(2021, 3, 1, 0, 0, 0).calendar_diff(&jan31), Some((0, 1, 1, TimeDelta::zero())));
+    let feb29 = utc(2020, 2, 29, 12, 0, 0);
+    assert_eq!(utc(2021, 2, 28, 11, 0, 0).calendar_diff(&feb29), Some((0, 11, 29, hms(23, 0, 0))));
+    assert_eq!(
+        utc(2021, 2, 28, 12, 0, 0).calendar_diff(&feb29),
+        Some((0, 11, 30, TimeDelta::zero()))
+    );
+    assert_eq!(utc(2021, 3, 1, 12, 0, 0).calendar_diff(&feb29), Some((1, 0, 1, TimeDelta::zero())));
+
+    // `other` is converted to the time zone of `self`
+    let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+    let b = a.with_timezone(&offset);
+    let c = offset.with_ymd_and_hms(2020, 4, 10, 14, 0, 0).unwrap();
+    assert_eq!(c.calendar_diff(&b), Some((0, 1, 0, hms(1, 0, 0))));
+    let d = a.with_timezone(&FixedOffset::west_opt(3600).unwrap());
+    assert_eq!(c.calendar_diff(&d), Some((0, 1, 0, hms(1, 0, 0))));
+
+    // the residual is wall-clock time, not elapsed time
+    let dst = |m, d, h, n| DstTester.with_ymd_and_hms(2014, m, d, h, n, 0).unwrap();
+    let (before, after) = (dst(9, 14, 12, 0), dst(9, 16, 12, 0));
+    assert_eq!(after.calendar_diff(&before), Some((0, 0, 2, TimeDelta::zero())));
+    assert_eq!(after - before, TimeDelta::hours(47));
+    let (before, after) = (dst(9, 15, 1, 0), dst(9, 15, 4, 0));
+    assert_eq!(after.calendar_diff(&before), Some((0, 0, 0, hms(3, 0, 0))));
+    assert_eq!(after - before, TimeDelta::hours(2));
+    let (before, after) = (dst(4, 15, 0, 30), dst(4, 15, 2, 30));
+    assert_eq!(after.calendar_diff(&before), Some((0, 0, 0, hms(2, 0, 0))));
+    assert_eq!(after - before, TimeDelta::hours(3));
+
+    // near the lower bound
+    let min = DateTime::<Utc>::MIN_UTC;
+    let (early, later) = (min + TimeDelta::days(1), min + TimeDelta::days(2));
+    assert_eq!(later.calendar_diff(&early), Some((0, 0, 1, TimeDelta::zero())));
+    assert_eq!(early.calendar_diff(&later), None);
+    assert_eq!(early.calendar_diff(&min), Some((0, 0, 1, TimeDelta::zero())));
+    let next_month = min.checked_add_months(Months::new(1)).unwrap();
+    assert_eq!(next_month.calendar_diff(&min), Some((0, 1, 0, TimeDelta::zero())));
+    assert_eq!(min.calendar_diff(&next_month), Some((-1, 11, 0, TimeDelta::zero())));
+}
+
 #[test]
 fn test_datetime_add_days() {
     let est = FixedOffset::west_opt(5 * 60 * 60).unwrap();
@@ -213,6 +659,69 @@ fn test_datetime_add_months() {
         format!("{}", kst.with_ymd_and_hms(2014, 5, 6, 7, 8, 9).unwrap() + Months::new(5)),
         "2014-10-06 07:08:09 +09:00"
     );
+
+    // the day is clamped and the local time is resolved again in the new month
+    assert_eq!(
+        format!("{}", DstTester.with_ymd_and_hms(2014, 3, 31, 7, 8, 9).unwrap() + Months::new(1)),
+        "2014-04-30 07:08:09 +08:00"
+    );
+    assert_eq!(
+        format!("{}", DstTester.with_ymd_and_hms(2014, 10, 31, 7, 8, 9).unwrap() - Months::new(1)),
+        "2014-09-30 07:08:09 +09:00"
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "`DateTime + Months` out of range, or local time is ambiguous or nonexistent"
+)]
+fn test_datetime_add_months_ambiguous() {
+    // 2014-04-15 01:30 occurs twice in `DstTester`
+    let _ = DstTester.with_ymd_and_hms(2014, 3, 15, 1, 30, 0).unwrap() + Months::new(1);
+}
+
+#[test]
+#[should_panic(
+    expected = "`DateTime + Days` out of range, or local time is ambiguous or nonexistent"
+)]
+fn test_datetime_add_days_nonexistent() {
+    // 2014-09-15 02:30 is skipped in `DstTester`
+    let _ = DstTester.with_ymd_and_hms(2014, 9, 14, 2, 30, 0).unwrap() + Days::new(1);
+}
+
+#[test]
+#[should_panic(
+    expected = "`DateTime - Days` out of range, or local time is ambiguous or nonexistent"
+)]
+fn test_datetime_sub_days_out_of_range() {
+    let _ = Utc.from_utc_datetime(&NaiveDateTime::MIN) - Days::new(1);
+}
+
+#[test]
+fn test_datetime_add_extreme_durations() {
+    let extremes = [
+        TimeDelta::max_value(),
+        TimeDelta::min_value(),
+        TimeDelta::max_value() - TimeDelta::nanoseconds(1),
+        TimeDelta::min_value() + TimeDelta::nanoseconds(1),
+    ];
+    let datetimes = [
+        NaiveDateTime::MIN,
+        NaiveDateTime::MAX,
+        NaiveDate::from_ymd_opt(2023, 3, 26).unwrap().and_hms_opt(1, 30, 0).unwrap(),
+    ];
+    for naive in datetimes {
+        for rhs in extremes {
+            let dt = Utc.from_utc_datetime(&naive);
+            assert_eq!(dt.checked_add_signed(rhs), None, "{} + {:?}", dt, rhs);
+            assert_eq!(dt.checked_sub_signed(rhs), None, "{} - {:?}", dt, rhs);
+            for secs in [-86_399, -5 * 3600, 0, 9 * 3600, 86_399] {
+                let dt = FixedOffset::east_opt(secs).unwrap().from_utc_datetime(&naive);
+                assert_eq!(dt.checked_add_signed(rhs), None, "{} + {:?}", dt, rhs);
+                assert_eq!(dt.checked_sub_signed(rhs), None, "{} - {:?}", dt, rhs);
+            }
+        }
+    }
 }
 
 #[test]
@@ -448,6 +957,23 @@ fn test_datetime_with_timezone() {
     assert_eq!(local_now, local_now2);
 }
 
+#[test]
+fn test_datetime_with_fixed_offset() {
+    let dt = Utc.with_ymd_and_hms(2014, 5, 6, 23, 8, 9).unwrap();
+    for secs in [-86_399, -5 * 3600, 0, 3600 + 1800, 86_399] {
+        let offset = FixedOffset::east_opt(secs).unwrap();
+        let fixed = dt.with_fixed_offset(offset);
+        assert_eq!(fixed, dt);
+        assert_eq!(*fixed.offset(), offset);
+        assert_eq!(fixed, dt.with_timezone(&offset));
+        assert_eq!(fixed.naive_local(), dt.naive_utc() + TimeDelta::seconds(i64::from(secs)));
+    }
+
+    let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+    let dt = DstTester.with_ymd_and_hms(2014, 9, 16, 2, 0, 0).unwrap().with_fixed_offset(edt);
+    assert_eq!(format!("{}", dt), "2014-09-15 13:00:00 -04:00");
+}
+
 #[test]
 fn test_datetime_rfc2822() {
     let edt = FixedOffset::east_opt(5 * 60 * 60).unwrap();
@@ -552,6 +1078,42 @@ fn test_datetime_rfc2822() {
     );
 }
 
+#[test]
+fn test_datetime_to_http_date() {
+    // the example of RFC 7231
+    let dt = Utc.with_ymd_and_hms(1994, 11, 6, 8, 49, 37).unwrap();
+    assert_eq!(dt.to_http_date(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    assert_eq!(DateTime::parse_from_http_date(&dt.to_http_date()), Ok(dt.fixed_offset()));
+
+    // converted to UTC first, which can change the date
+    let pst = FixedOffset::west_opt(8 * 60 * 60).unwrap();
+    let dt = pst.with_ymd_and_hms(1999, 12, 31, 16, 30, 0).unwrap();
+    assert_eq!(dt.to_http_date(), "Sat, 01 Jan 2000 00:30:00 GMT");
+
+    // fractional seconds are dropped, leap seconds are kept
+    let dt = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_500)
+        .unwrap()
+        .and_utc();
+    assert_eq!(dt.to_http_date(), "Sat, 31 Dec 2016 23:59:60 GMT");
+
+    assert_eq!(
+        Utc.with_ymd_and_hms(0, 1, 1, 0, 0, 0).unwrap().to_http_date(),
+        "Sat, 01 Jan 0000 00:00:00 GMT"
+    );
+    assert_eq!(
+        Utc.with_ymd_and_hms(9999, 12, 31, 23, 59, 59).unwrap().to_http_date(),
+        "Fri, 31 Dec 9999 23:59:59 GMT"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_datetime_to_http_date_out_of_range() {
+    let _ = Utc.with_ymd_and_hms(10000, 1, 1, 0, 0, 0).unwrap().to_http_date();
+}
+
 #[test]
 fn test_datetime_rfc3339() {
     let edt5 = FixedOffset::east_opt(5 * 60 * 60).unwrap();
@@ -677,9 +1239,371 @@ fn test_datetime_rfc3339() {
     );
 }
 
+#[test]
+fn test_datetime_parse_from_rfc3339_with_precision() {
+    let edt5 = FixedOffset::east_opt(5 * 60 * 60).unwrap();
+    let parse = DateTime::<FixedOffset>::parse_from_rfc3339_with_precision;
+
+    assert_eq!(parse("2015-02-18T23:59:59+05:00"), Ok((ymdhms(&edt5, 2015, 2, 18, 23, 59, 59), 0)));
+    assert_eq!(
+        parse("2015-02-18T23:59:59.123+05:00"),
+        Ok((ymdhms_milli(&edt5, 2015, 2, 18, 23, 59, 59, 123), 3))
+    );
+    assert_eq!(
+        parse("2015-02-18T23:59:59.123456+05:00"),
+        Ok((ymdhms_micro(&edt5, 2015, 2, 18, 23, 59, 59, 123_456), 6))
+    );
+    assert_eq!(
+        parse("2015-02-18T23:59:59.123456789+05:00"),
+        Ok((ymdhms_nano(&edt5, 2015, 2, 18, 23, 59, 59, 123_456_789), 9))
+    );
+
+    // trailing zeros count, and more than 9 digits are truncated
+    assert_eq!(parse("2015-02-18T23:59:59.000+05:00").map(|(_, p)| p), Ok(3));
+    assert_eq!(parse("2015-02-18T23:59:59,5Z").map(|(_, p)| p), Ok(1));
+    assert_eq!(
+        parse("2015-02-18T23:59:59.1234567891+05:00"),
+        Ok((ymdhms_nano(&edt5, 2015, 2, 18, 23, 59, 59, 123_456_789), 9))
+    );
+
+    // the same value as `parse_from_rfc3339`
+    for s in
+        ["2015-02-18T23:59:60.234567+05:00", "2015-02-18t23:16:09z", "1996-12-19T16:39:57-08:00"]
+    {
+        assert_eq!(parse(s).map(|(dt, _)| dt), DateTime::<FixedOffset>::parse_from_rfc3339(s));
+    }
+
+    // written back with the same precision
+    for s in [
+        "2015-02-18T23:59:59Z",
+        "2015-02-18T23:59:59.100Z",
+        "2015-02-18T23:59:59.000100Z",
+        "2015-02-18T23:59:59.000000000Z",
+    ] {
+        let (dt, digits) = parse(s).unwrap();
+        let format = match digits {
+            0 => SecondsFormat::Secs,
+            3 => SecondsFormat::Millis,
+            6 => SecondsFormat::Micros,
+            _ => SecondsFormat::Nanos,
+        };
+        assert_eq!(dt.to_rfc3339_opts(format, true), s);
+    }
+
+    assert!(parse("2015-02-18T23:59:59.+05:00").is_err());
+    assert!(parse("2015-02-18T23:59:59.123+05:00 ").is_err());
+    assert!(parse("2015-02-18T23:59:59.123").is_err());
+}
+
+#[test]
+fn test_datetime_checked_add_local_days() {
+    let summer = DstTester::summer_offset();
+    let winter = DstTester::winter_offset();
+    let local = |month, day, hour, min| {
+        NaiveDate::from_ymd_opt(2023, month, day).unwrap().and_hms_opt(hour, min, 0).unwrap()
+    };
+    let dst = |month, day, hour, min| DstTester.from_local_datetime(&local(month, day, hour, min));
+
+    // keeps the local clock time across the transition to winter time
+    let dt = dst(4, 14, 12, 0).single().unwrap();
+    let next = dt.checked_add_local_days(Days::new(1)).unwrap();
+    assert_eq!(next.naive_local(), local(4, 15, 12, 0));
+    assert_eq!(next.offset(), &winter);
+    assert_eq!(next - dt, TimeDelta::hours(25));
+    // while adding a `TimeDelta` keeps the physical duration
+    let shifted = dt + TimeDelta::days(1);
+    assert_eq!(shifted.naive_local(), local(4, 15, 11, 0));
+    assert_eq!(shifted.offset(), &winter);
+
+    // and across the transition to summer time
+    let dt = dst(9, 14, 12, 0).single().unwrap();
+    let next = dt.checked_add_local_days(Days::new(1)).unwrap();
+    assert_eq!(next.naive_local(), local(9, 15, 12, 0));
+    assert_eq!(next.offset(), &summer);
+    assert_eq!(next - dt, TimeDelta::hours(23));
+    assert_eq!((dt + TimeDelta::days(1)).naive_local(), local(9, 15, 13, 0));
+
+    // a local time in the gap does not exist
+    let dt = dst(9, 14, 2, 30).single().unwrap();
+    assert_eq!(dt.checked_add_local_days(Days::new(1)), None);
+    assert_eq!(dt.checked_add_days(Days::new(1)), None);
+    assert!(dt.checked_add_local_days(Days::new(2)).is_some());
+
+    // an ambiguous local time resolves to the earlier instant, which is still in summer time,
+    // while `checked_add_days` rejects it
+    let dt = dst(4, 14, 1, 30).single().unwrap();
+    let next = dt.checked_add_local_days(Days::new(1)).unwrap();
+    assert_eq!(next.naive_local(), local(4, 15, 1, 30));
+    assert_eq!(next.offset(), &summer);
+    assert_eq!(next - dt, TimeDelta::hours(24));
+    assert_eq!(dt.checked_add_days(Days::new(1)), None);
+
+    // otherwise both agree
+    let dt = dst(4, 14, 12, 0).single().unwrap();
+    assert_eq!(dt.checked_add_local_days(Days::new(1)), dt.checked_add_days(Days::new(1)));
+
+    // several days, and no days at all
+    let dt = dst(4, 10, 8, 15).single().unwrap();
+    assert_eq!(
+        dt.checked_add_local_days(Days::new(10)).unwrap().naive_local(),
+        local(4, 20, 8, 15)
+    );
+    assert_eq!(dt.checked_add_local_days(Days::new(0)), Some(dt));
+
+    // out of range
+    let max = Utc.from_utc_datetime(&NaiveDateTime::MAX);
+    assert_eq!(max.checked_add_local_days(Days::new(1)), None);
+}
+
+#[test]
+fn test_datetime_parse_in_timezone() {
+    let fmt = "%Y-%m-%d %H:%M:%S";
+    let parse = |s| DateTime::parse_in_timezone(s, fmt, &DstTester);
+    let local = |month, day, hour, min| {
+        NaiveDate::from_ymd_opt(2023, month, day).unwrap().and_hms_opt(hour, min, 0).unwrap()
+    };
+    let summer = DstTester::summer_offset();
+    let winter = DstTester::winter_offset();
+
+    // unambiguous local times
+    let dt = parse("2023-06-01 12:30:00").unwrap().single().unwrap();
+    assert_eq!(dt.naive_local(), local(6, 1, 12, 30));
+    assert_eq!(dt.offset(), &winter);
+    let dt = parse("2023-01-01 12:30:00").unwrap().single().unwrap();
+    assert_eq!(dt.naive_local(), local(1, 1, 12, 30));
+    assert_eq!(dt.offset(), &summer);
+
+    // a local time in the gap
+    assert_eq!(parse("2023-09-15 02:30:00"), Ok(LocalResult::None));
+
+    // an ambiguous local time
+    match parse("2023-04-15 01:30:00") {
+        Ok(LocalResult::Ambiguous(a, b)) => {
+            assert_eq!(a.naive_local(), local(4, 15, 1, 30));
+            assert_eq!(b.naive_local(), local(4, 15, 1, 30));
+            assert_eq!((a.offset(), b.offset()), (&winter, &summer));
+        }
+        result => panic!("expected an ambiguous result, got {:?}", result),
+    }
+
+    // an offset in the input picks one of them, or fails if it does not occur
+    let fmt_offset = "%Y-%m-%d %H:%M:%S %z";
+    let parse_offset = |s| DateTime::parse_in_timezone(s, fmt_offset, &DstTester);
+    let dt = parse_offset("2023-04-15 01:30:00 +0900").unwrap().single().unwrap();
+    assert_eq!((dt.naive_local(), dt.offset()), (local(4, 15, 1, 30), &summer));
+    let dt = parse_offset("2023-04-15 01:30:00 +0800").unwrap().single().unwrap();
+    assert_eq!((dt.naive_local(), dt.offset()), (local(4, 15, 1, 30), &winter));
+    assert!(parse_offset("2023-04-15 01:30:00 +0700").is_err());
+    assert!(parse_offset("2023-06-01 12:00:00 +0900").is_err());
+    // a timestamp is an instant as well
+    let dt = DateTime::parse_in_timezone("1686960000", "%s", &DstTester).unwrap().single().unwrap();
+    assert_eq!(dt, Utc.with_ymd_and_hms(2023, 6, 17, 0, 0, 0).unwrap());
+
+    // incomplete or invalid input
+    assert!(parse("2023-06-01").is_err());
+    assert!(DateTime::parse_in_timezone("2023-06-01", "%Y-%m-%d", &DstTester).is_err());
+    assert!(parse("2023-06-31 12:00:00").is_err());
+    assert!(parse("2023-06-01 12:00:00 trailing").is_err());
+
+    // works for any `TimeZone`
+    assert_eq!(
+        DateTime::parse_in_timezone("2023-04-15 01:30:00", fmt, &Utc),
+        Ok(LocalResult::Single(Utc.with_ymd_and_hms(2023, 4, 15, 1, 30, 0).unwrap()))
+    );
+}
+
+#[test]
+fn test_datetime_parse_from_rfc3339_two_digit_year() {
+    let edt5 = FixedOffset::east_opt(5 * 60 * 60).unwrap();
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let parse = DateTime::<FixedOffset>::parse_from_rfc3339_two_digit_year;
+
+    assert_eq!(parse("23-01-18T00:00:00Z"), Ok(ymdhms(&utc, 2023, 1, 18, 0, 0, 0)));
+    assert_eq!(parse("99-01-18T00:00:00Z"), Ok(ymdhms(&utc, 1999, 1, 18, 0, 0, 0)));
+    assert_eq!(parse("00-02-29T12:00:00Z"), Ok(ymdhms(&utc, 2000, 2, 29, 12, 0, 0)));
+    assert_eq!(parse("69-12-31T23:59:59Z"), Ok(ymdhms(&utc, 2069, 12, 31, 23, 59, 59)));
+    assert_eq!(parse("70-01-01T00:00:00Z"), Ok(ymdhms(&utc, 1970, 1, 1, 0, 0, 0)));
+    assert_eq!(
+        parse("15-02-18t23:59:59.123456+05:00"),
+        Ok(ymdhms_micro(&edt5, 2015, 2, 18, 23, 59, 59, 123_456))
+    );
+
+    // the strict parser rejects two-digit years
+    assert!(DateTime::<FixedOffset>::parse_from_rfc3339("23-01-18T00:00:00Z").is_err());
+
+    // four-digit years are parsed like `parse_from_rfc3339`
+    for s in [
+        "2023-01-18T00:00:00Z",
+        "1923-01-18T00:00:00Z",
+        "2015-02-18T23:59:60.234567+05:00",
+        "2015-02-18T23:59:60.234567+05:00 ",
+        "2015-02-18 23:59:60.234567+05:00",
+        "0023-01-18T00:00:00Z",
+    ] {
+        assert_eq!(parse(s), DateTime::<FixedOffset>::parse_from_rfc3339(s), "{}", s);
+    }
+
+    // other year lengths, and invalid remainders
+    assert!(parse("123-01-18T00:00:00Z").is_err());
+    assert!(parse("3-01-18T00:00:00Z").is_err());
+    assert!(parse("+23-01-18T00:00:00Z").is_err());
+    assert!(parse("23-02-29T00:00:00Z").is_err());
+    assert!(parse("23-01-18T00:00:00").is_err());
+    assert!(parse("23-01-18T00:00:00Z ").is_err());
+    assert!(parse("23-").is_err());
+    assert!(parse("23").is_err());
+    assert!(parse("").is_err());
+}
+
+#[test]
+fn test_datetime_rfc3339_leap_second() {
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_000)
+        .unwrap()
+        .and_utc();
+
+    let parsed = DateTime::<FixedOffset>::parse_from_rfc3339("2016-12-31T23:59:60Z").unwrap();
+    assert_eq!(parsed, leap);
+    assert_eq!((parsed.second(), parsed.nanosecond()), (59, 1_000_000_000));
+    assert_eq!(parsed.to_rfc3339(), "2016-12-31T23:59:60+00:00");
+    assert_eq!("2016-12-31T23:59:60Z".parse::<DateTime<Utc>>(), Ok(leap));
+
+    // the leap second sorts between 23:59:59 and midnight
+    let before = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+    let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+    assert!(before < parsed && parsed < after);
+    assert_eq!(parsed.timestamp(), before.timestamp());
+
+    let parsed =
+        DateTime::<FixedOffset>::parse_from_rfc3339("2016-12-31T23:59:60.999999999Z").unwrap();
+    assert_eq!(parsed.nanosecond(), 1_999_999_999);
+    assert!(DateTime::<FixedOffset>::parse_from_rfc3339("2016-12-31T23:59:61Z").is_err());
+}
+
+#[test]
+fn test_datetime_parse_from_any_rfc() {
+    let edt = FixedOffset::east_opt(5 * 60 * 60).unwrap();
+    let expected = edt.with_ymd_and_hms(2015, 2, 18, 23, 16, 9).unwrap();
+
+    // RFC 3339
+    assert_eq!(
+        DateTime::<FixedOffset>::parse_from_any_rfc("2015-02-18T23:16:09+05:00"),
+        Ok(expected)
+    );
+    assert_eq!(
+        DateTime::<FixedOffset>::parse_from_any_rfc("2015-02-18t23:16:09+05:00"),
+        Ok(expected)
+    );
+    assert_eq!(
+        DateTime::<FixedOffset>::parse_from_any_rfc("2015-02-18T23:16:09.150+05:00"),
+        Ok(expected + TimeDelta::milliseconds(150))
+    );
+
+    // RFC 2822
+    assert_eq!(
+        DateTime::<FixedOffset>::parse_from_any_rfc("Wed, 18 Feb 2015 23:16:09 +0500"),
+        Ok(expected)
+    );
+    assert_eq!(
+        DateTime::<FixedOffset>::parse_from_any_rfc("18 Feb 2015 23:16:09 +0500"),
+        Ok(expected)
+    );
+
+    // invalid in both formats, with the error of the format it looks most like
+    assert_eq!(
+        DateTime::<FixedOffset>::parse_from_any_rfc("2015-02-18 23:16:09+05:00"),
+        DateTime::<FixedOffset>::parse_from_rfc3339("2015-02-18 23:16:09+05:00")
+    );
+    assert_eq!(
+        DateTime::<FixedOffset>::parse_from_any_rfc("Wed, 18 Feb 2015 23:16:09"),
+        DateTime::<FixedOffset>::parse_from_rfc2822("Wed, 18 Feb 2015 23:16:09")
+    );
+    assert!(DateTime::<FixedOffset>::parse_from_any_rfc("").is_err());
+    assert!(DateTime::<FixedOffset>::parse_from_any_rfc("not a date").is_err());
+    assert!(DateTime::<FixedOffset>::parse_from_any_rfc("2015/02/18 23:16:09").is_err());
+}
+
+#[test]
+fn test_datetime_parse_from_str_with_pivot() {
+    let parse = DateTime::<FixedOffset>::parse_from_str_with_pivot;
+    let ymdhms = |y, m, d, h, n, s, off| {
+        FixedOffset::east_opt(off).unwrap().with_ymd_and_hms(y, m, d, h, n, s).unwrap()
+    };
+    let fmt = "%d/%m/%y %H:%M:%S %z";
+
+    assert_eq!(
+        parse("12/04/70 09:30:00 +0200", fmt, 1970),
+        Ok(ymdhms(1970, 4, 12, 9, 30, 0, 7200))
+    );
+    assert_eq!(
+        parse("12/04/70 09:30:00 +0200", fmt, 2000),
+        Ok(ymdhms(2070, 4, 12, 9, 30, 0, 7200))
+    );
+    assert_eq!(
+        parse("12/04/69 09:30:00 -0500", fmt, 1870),
+        Ok(ymdhms(1969, 4, 12, 9, 30, 0, -18000))
+    );
+    assert_eq!(
+        parse("12/04/70 09:30:00 +0200", fmt, 1970),
+        DateTime::<FixedOffset>::parse_from_str("12/04/70 09:30:00 +0200", fmt)
+    );
+    assert!(parse("12/04/70 09:30:00", "%d/%m/%y %H:%M:%S", 2000).is_err()); // no offset
+
+    assert_eq!(
+        NaiveDateTime::parse_from_str_with_pivot("12/04/70 09:30:00", "%d/%m/%y %H:%M:%S", 2000),
+        Ok(ymdhms(2070, 4, 12, 9, 30, 0, 0).naive_local())
+    );
+}
+
+#[test]
+fn test_datetime_parse_epoch_with_unit() {
+    let parse = DateTime::<Utc>::parse_epoch_with_unit;
+    let expected = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+
+    assert_eq!(parse("1672531200"), Some(expected));
+    assert_eq!(parse("1672531200s"), Some(expected));
+    assert_eq!(parse("1672531200000ms"), Some(expected));
+    assert_eq!(parse("1672531200000000us"), Some(expected));
+    assert_eq!(parse("1672531200000000000ns"), Some(expected));
+
+    assert_eq!(parse("1672531200123ms"), Some(expected + TimeDelta::milliseconds(123)));
+    assert_eq!(parse("1672531200123456us"), Some(expected + TimeDelta::microseconds(123_456)));
+    assert_eq!(
+        parse("1672531200123456789ns"),
+        Some(expected + TimeDelta::nanoseconds(123_456_789))
+    );
+
+    // before the UNIX epoch
+    let epoch = Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(parse("0"), Some(epoch));
+    assert_eq!(parse("-1"), Some(epoch - TimeDelta::seconds(1)));
+    assert_eq!(parse("-1ms"), Some(epoch - TimeDelta::milliseconds(1)));
+    assert_eq!(parse("-1500us"), Some(epoch - TimeDelta::microseconds(1500)));
+    assert_eq!(parse("-1ns"), Some(epoch - TimeDelta::nanoseconds(1)));
+
+    // invalid input
+    assert_eq!(parse(""), None);
+    assert_eq!(parse("s"), None);
+    assert_eq!(parse("ms"), None);
+    assert_eq!(parse("1.5s"), None);
+    assert_eq!(parse("1 s"), None);
+    assert_eq!(parse("1sec"), None);
+    assert_eq!(parse("1h"), None);
+    assert_eq!(parse("1ss"), None);
+    assert_eq!(parse(" 1"), None);
+    assert_eq!(parse("99999999999999999999"), None);
+
+    // out of range for a `DateTime`
+    assert_eq!(parse(&i64::MAX.to_string()), None);
+    assert_eq!(parse(&format!("{}s", i64::MIN)), None);
+    assert!(parse(&format!("{}ns", i64::MAX)).is_some());
+    assert!(parse(&format!("{}ns", i64::MIN)).is_some());
+}
+
 #[test]
 fn test_rfc3339_opts() {
-    use crate::SecondsFormat::*;
+    use SecondsFormat::*;
     let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
     let dt = pst
         .from_local_datetime(
@@ -706,6 +1630,57 @@ fn test_rfc3339_opts() {
     assert_eq!(ut.to_rfc3339_opts(AutoSi, true), "2018-01-11T02:05:13.084660Z");
 }
 
+#[test]
+fn test_rfc3339_opts_zulu() {
+    use crate::ZuluPolicy::*;
+    use SecondsFormat::*;
+
+    let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    let dt = pst
+        .from_local_datetime(
+            &NaiveDate::from_ymd_opt(2018, 1, 11)
+                .unwrap()
+                .and_hms_nano_opt(10, 5, 13, 84_660_000)
+                .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(dt.to_rfc3339_opts_zulu(Secs, AlwaysZ), "2018-01-11T02:05:13Z");
+    assert_eq!(dt.to_rfc3339_opts_zulu(Millis, AlwaysZ), "2018-01-11T02:05:13.084Z");
+    assert_eq!(dt.to_rfc3339_opts_zulu(Secs, ZForUtcOnly), "2018-01-11T10:05:13+08:00");
+    assert_eq!(dt.to_rfc3339_opts_zulu(Secs, NeverZ), "2018-01-11T10:05:13+08:00");
+
+    let west = FixedOffset::west_opt(5 * 60 * 60 + 30 * 60).unwrap();
+    let dt2 = dt.with_timezone(&west);
+    assert_eq!(dt2.to_rfc3339_opts_zulu(Micros, AlwaysZ), "2018-01-11T02:05:13.084660Z");
+    assert_eq!(dt2.to_rfc3339_opts_zulu(Micros, ZForUtcOnly), "2018-01-10T20:35:13.084660-05:30");
+
+    // an offset of zero, with any time zone type
+    let ut = dt.with_timezone(&Utc);
+    let fixed_zero = dt.with_timezone(&FixedOffset::east_opt(0).unwrap());
+    for s in
+        [ut.to_rfc3339_opts_zulu(Nanos, AlwaysZ), fixed_zero.to_rfc3339_opts_zulu(Nanos, AlwaysZ)]
+    {
+        assert_eq!(s, "2018-01-11T02:05:13.084660000Z");
+    }
+    assert_eq!(ut.to_rfc3339_opts_zulu(AutoSi, ZForUtcOnly), "2018-01-11T02:05:13.084660Z");
+    assert_eq!(fixed_zero.to_rfc3339_opts_zulu(AutoSi, ZForUtcOnly), "2018-01-11T02:05:13.084660Z");
+    assert_eq!(ut.to_rfc3339_opts_zulu(AutoSi, NeverZ), "2018-01-11T02:05:13.084660+00:00");
+
+    // the boolean variant is a wrapper
+    for secform in [Secs, Millis, Micros, Nanos, AutoSi] {
+        for dt in [dt, dt2, fixed_zero] {
+            assert_eq!(
+                dt.to_rfc3339_opts(secform, true),
+                dt.to_rfc3339_opts_zulu(secform, ZForUtcOnly)
+            );
+            assert_eq!(
+                dt.to_rfc3339_opts(secform, false),
+                dt.to_rfc3339_opts_zulu(secform, NeverZ)
+            );
+        }
+    }
+}
+
 #[test]
 fn test_datetime_from_str() {
     assert_eq!(
@@ -1011,6 +1986,33 @@ fn test_utc_datetime_from_str_with_spaces() {
     assert!(Utc.datetime_from_str("Aug 09 2013 23:54:35 !!!", "%b %d %Y %H:%M:%S ").is_err());
 }
 
+#[test]
+fn test_datetime_parse_and_remainder() {
+    let dt = ymdhms(&FixedOffset::east_opt(2 * 3600).unwrap(), 2015, 2, 18, 23, 16, 9);
+    let parse = DateTime::<FixedOffset>::parse_and_remainder;
+
+    assert_eq!(parse("2015-02-18 23:16:09 +0200", "%Y-%m-%d %H:%M:%S %z"), Ok((dt, "")));
+    assert_eq!(
+        parse("2015-02-18 23:16:09 +0200 INFO starting up", "%Y-%m-%d %H:%M:%S %z"),
+        Ok((dt, " INFO starting up"))
+    );
+    assert_eq!(parse("2015-02-18T23:16:09+02:00]", "%Y-%m-%dT%H:%M:%S%:z"), Ok((dt, "]")));
+    assert_eq!(
+        parse("[2015-02-18 23:16:09 +0200] GET /", "[%Y-%m-%d %H:%M:%S %z]"),
+        Ok((dt, " GET /"))
+    );
+
+    // whitespace in the format string consumes exactly that whitespace in the input
+    assert_eq!(
+        parse("2015-02-18 23:16:09 +0200  message", "%Y-%m-%d %H:%M:%S %z "),
+        Ok((dt, " message"))
+    );
+    assert!(parse("2015-02-18 23:16:09 +0200", "%Y-%m-%d %H:%M:%S %z ").is_err());
+
+    // an offset is still required
+    assert!(parse("2015-02-18 23:16:09 trailing", "%Y-%m-%d %H:%M:%S").is_err());
+}
+
 #[test]
 fn test_datetime_parse_from_str() {
     let dt = ymdhms(&FixedOffset::east_opt(-9 * 60 * 60).unwrap(), 2013, 8, 9, 23, 54, 35);
@@ -1106,8 +2108,12 @@ fn test_datetime_parse_from_str() {
     assert_eq!(parse("Aug 09 2013 23:54:35 -09:00", "%b %d %Y %H:%M:%S %:::z"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 23:54:35 -0900", "%b %d %Y %H:%M:%S %:::z"), Ok(dt));
     assert_eq!(parse("Aug 09 2013 23:54:35 -0900  ", "%b %d %Y %H:%M:%S %:::z  "), Ok(dt));
+    // minutes may be omitted
+    assert_eq!(parse("Aug 09 2013 23:54:35 -09", "%b %d %Y %H:%M:%S %:::z"), Ok(dt));
+    assert_eq!(parse("-09 Aug 09 2013 23:54:35", "%:::z %b %d %Y %H:%M:%S"), Ok(dt));
     // wrong timezone data
-    assert!(parse("Aug 09 2013 23:54:35 -09", "%b %d %Y %H:%M:%S %:::z").is_err());
+    assert!(parse("Aug 09 2013 23:54:35 -9", "%b %d %Y %H:%M:%S %:::z").is_err());
+    assert!(parse("Aug 09 2013 23:54:35 -09:00:00", "%b %d %Y %H:%M:%S %:::z").is_err());
 
     //
     // %::::z
@@ -1152,6 +2158,59 @@ fn test_datetime_parse_from_str() {
     assert!(parse("Aug 09 2013 -09:00:00 23:54:35", "%b %d %Y %#z %H:%M:%S").is_err());
     // timezone data ambiguous with hours
     assert!(parse("Aug 09 2013 -09:00:23:54:35", "%b %d %Y %#z%H:%M:%S").is_err());
+    // one-digit hours and minutes
+    assert_eq!(parse("Aug 09 2013 23:54:35 -9", "%b %d %Y %H:%M:%S %#z"), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -900", "%b %d %Y %H:%M:%S %#z"), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -9:00", "%b %d %Y %H:%M:%S %#z"), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -9:0", "%b %d %Y %H:%M:%S %#z"), Ok(dt));
+    for (offset, secs) in [("+5", 18_000), ("+530", 19_800), ("+5:30", 19_800), ("+5:3", 18_180)] {
+        let input = format!("Aug 09 2013 23:54:35 {}", offset);
+        let parsed = parse(&input, "%b %d %Y %H:%M:%S %#z");
+        assert_eq!(parsed.map(|dt| dt.offset().local_minus_utc()), Ok(secs));
+    }
+    // out of range
+    assert!(parse("Aug 09 2013 23:54:35 +99:99", "%b %d %Y %H:%M:%S %#z").is_err());
+    assert!(parse("Aug 09 2013 23:54:35 +99:59", "%b %d %Y %H:%M:%S %#z").is_err());
+    assert!(parse("Aug 09 2013 23:54:35 +5:60", "%b %d %Y %H:%M:%S %#z").is_err());
+
+    // formatting is the same as %z
+    assert_eq!(dt.format("%#z").to_string(), dt.format("%z").to_string());
+    assert_eq!(dt.format("%#z").to_string(), "-0900");
+
+    //
+    // %#:z
+    //
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let dt_utc = ymdhms(&utc, 2013, 8, 9, 23, 54, 35);
+    assert_eq!(dt.format("%#:z").to_string(), "-09:00");
+    assert_eq!(dt_utc.format("%#:z").to_string(), "Z");
+    assert_eq!(
+        dt_utc.format("%Y-%m-%dT%H:%M:%S%#:z").to_string(),
+        dt_utc.to_rfc3339_opts(SecondsFormat::Secs, true)
+    );
+    assert_eq!(
+        ymdhms(&FixedOffset::east_opt(30).unwrap(), 2013, 8, 9, 23, 54, 35)
+            .format("%#:z")
+            .to_string(),
+        "+00:00"
+    );
+    assert_eq!(parse("Aug 09 2013 23:54:35 -09:00", "%b %d %Y %H:%M:%S %#:z"), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -0900", "%b %d %Y %H:%M:%S %#:z"), Ok(dt));
+    assert_eq!(parse("Aug 09 2013 23:54:35 Z", "%b %d %Y %H:%M:%S %#:z"), Ok(dt_utc));
+    assert_eq!(parse("Aug 09 2013 23:54:35 z", "%b %d %Y %H:%M:%S %#:z"), Ok(dt_utc));
+    assert_eq!(parse("Aug 09 2013 23:54:35 +00:00", "%b %d %Y %H:%M:%S %#:z"), Ok(dt_utc));
+    assert_eq!(parse("Aug 09 2013 23:54:35 -00:00", "%b %d %Y %H:%M:%S %#:z"), Ok(dt_utc));
+    assert_eq!(parse("Aug 09 2013 23:54:35 UTC", "%b %d %Y %H:%M:%S %#:z"), Ok(dt_utc));
+    assert!(parse("Aug 09 2013 23:54:35 GMT", "%b %d %Y %H:%M:%S %#:z").is_err());
+    assert!(parse("Aug 09 2013 23:54:35 -09", "%b %d %Y %H:%M:%S %#:z").is_err());
+    for s in ["Z", "+00:00", "-09:00", "+05:30"] {
+        let input = format!("2013-08-09T23:54:35{}", s);
+        let parsed = parse(&input, "%Y-%m-%dT%H:%M:%S%#:z").unwrap();
+        assert_eq!(
+            parsed.format("%Y-%m-%dT%H:%M:%S%#:z").to_string(),
+            input.replace("+00:00", "Z")
+        );
+    }
 }
 
 #[test]
@@ -1181,6 +2240,139 @@ fn test_datetime_format_with_local() {
     assert_eq!(dt.format("%Y").to_string(), dt.with_timezone(&Utc).format("%Y").to_string());
 }
 
+#[test]
+fn test_datetime_js_millis() {
+    let dt = Utc.with_ymd_and_hms(2023, 6, 15, 12, 34, 56).unwrap() + TimeDelta::milliseconds(789);
+    assert_eq!(dt.to_js_millis(), 1_686_832_496_789.0);
+    assert_eq!(DateTime::<Utc>::from_js_millis(1_686_832_496_789.0), Some(dt));
+    assert_eq!(DateTime::<Utc>::from_js_millis(dt.to_js_millis()), Some(dt));
+
+    for ms in [0.0, -0.0, 1.0, -1.0, -62_135_596_800_000.0, 253_402_300_799_999.0] {
+        let dt = DateTime::<Utc>::from_js_millis(ms).unwrap();
+        assert_eq!(dt.to_js_millis(), ms);
+        assert_eq!(dt.timestamp_millis(), ms as i64);
+    }
+
+    // sub-millisecond precision is lost
+    let dt = Utc.timestamp_nanos(1_999_999);
+    assert_eq!(dt.to_js_millis(), 1.0);
+    let dt = Utc.timestamp_nanos(-1);
+    assert_eq!(dt.to_js_millis(), -1.0);
+    assert_eq!(DateTime::<Utc>::from_js_millis(1.9), DateTime::<Utc>::from_js_millis(1.0));
+    assert_eq!(DateTime::<Utc>::from_js_millis(-1.9), DateTime::<Utc>::from_js_millis(-1.0));
+
+    // leap seconds are not counted, a leap second maps to the following second
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_500)
+        .unwrap()
+        .and_utc();
+    assert_eq!(leap.to_js_millis(), 1_483_228_800_500.0);
+
+    // invalid JavaScript time values
+    assert_eq!(DateTime::<Utc>::from_js_millis(f64::NAN), None);
+    assert_eq!(DateTime::<Utc>::from_js_millis(f64::INFINITY), None);
+    assert_eq!(DateTime::<Utc>::from_js_millis(f64::NEG_INFINITY), None);
+    assert_eq!(DateTime::<Utc>::from_js_millis(8.640_000_000_000_001e15), None);
+    // valid in JavaScript but out of range for chrono
+    assert_eq!(DateTime::<Utc>::from_js_millis(8.64e15), None);
+    assert_eq!(DateTime::<Utc>::from_js_millis(-8.64e15), None);
+}
+
+#[test]
+fn test_datetime_from_timestamp_secs() {
+    let from_secs = DateTime::<Utc>::from_timestamp_secs;
+
+    assert_eq!(from_secs(0), Some(ymdhms_utc(1970, 1, 1, 0, 0, 0)));
+    assert_eq!(from_secs(-1), Some(ymdhms_utc(1969, 12, 31, 23, 59, 59)));
+    assert_eq!(from_secs(-86_400 * 365), Some(ymdhms_utc(1969, 1, 1, 0, 0, 0)));
+    assert_eq!(from_secs(1_700_000_000), Some(ymdhms_utc(2023, 11, 14, 22, 13, 20)));
+
+    let min = DateTime::<Utc>::MIN_UTC.timestamp();
+    let max = DateTime::<Utc>::MAX_UTC.timestamp();
+    assert_eq!(min, -8_334_632_851_200);
+    assert_eq!(max, 8_210_298_412_799);
+    assert_eq!(from_secs(min), Some(DateTime::<Utc>::MIN_UTC));
+    assert_eq!(from_secs(max).map(|dt| dt.naive_utc().date()), Some(NaiveDate::MAX));
+    assert_eq!(from_secs(min - 1), None);
+    assert_eq!(from_secs(max + 1), None);
+    assert_eq!(from_secs(i64::MIN), None);
+    assert_eq!(from_secs(i64::MAX), None);
+
+    for secs in [min, -1_000_000_007, -1, 0, 1, 1_000_000_007, max] {
+        assert_eq!(from_secs(secs).map(|dt| dt.timestamp()), Some(secs));
+    }
+}
+
+#[test]
+fn test_datetime_timestamp_nanos_i128() {
+    let dt = Utc.with_ymd_and_hms(2001, 9, 9, 1, 46, 40).unwrap() + TimeDelta::nanoseconds(555);
+    assert_eq!(dt.timestamp_nanos_i128(), i128::from(dt.timestamp_nanos()));
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(dt.timestamp_nanos_i128()), Some(dt));
+
+    // negative timestamps round towards negative infinity
+    let dt = DateTime::<Utc>::from_timestamp_nanos_i128(-1).unwrap();
+    assert_eq!(
+        dt,
+        Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap()
+            + TimeDelta::nanoseconds(999_999_999)
+    );
+    assert_eq!(dt.timestamp_nanos_i128(), -1);
+
+    // outside the range of `timestamp_nanos`
+    let dt = Utc.with_ymd_and_hms(1066, 10, 14, 9, 0, 0).unwrap();
+    assert_eq!(dt.timestamp_nanos_i128(), -28_502_722_800_000_000_000);
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(-28_502_722_800_000_000_000), Some(dt));
+
+    // the full range
+    let min = DateTime::<Utc>::MIN_UTC.timestamp_nanos_i128();
+    let max = DateTime::<Utc>::MAX_UTC.timestamp_nanos_i128();
+    assert_eq!(min, -8_334_632_851_200_000_000_000);
+    assert_eq!(max, 8_210_298_412_799_999_999_999);
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(min), Some(DateTime::<Utc>::MIN_UTC));
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(max), Some(DateTime::<Utc>::MAX_UTC));
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(min - 1), None);
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(max + 1), None);
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(i128::MIN), None);
+    assert_eq!(DateTime::<Utc>::from_timestamp_nanos_i128(i128::MAX), None);
+
+    // the offset does not matter
+    let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(1970, 1, 1, 1, 0, 1).unwrap();
+    assert_eq!(dt.timestamp_nanos_i128(), 1_000_000_000);
+}
+
+#[test]
+fn test_datetime_format_utc() {
+    let fmts = ["%Y-%m-%d %H:%M:%S%.f", "%a %b %e %T %Y", "%z %:z %Z", "%s", "%+"];
+    for secs in [-14 * 3600, -5 * 3600 - 1800, 0, 3600, 9 * 3600 + 1234, 14 * 3600] {
+        let tz = FixedOffset::east_opt(secs).unwrap();
+        let dt = tz.with_ymd_and_hms(2000, 1, 1, 0, 30, 59).unwrap();
+        for fmt in fmts {
+            assert_eq!(
+                dt.format_utc(fmt).to_string(),
+                dt.with_timezone(&Utc).format(fmt).to_string()
+            );
+        }
+    }
+
+    let tz = FixedOffset::east_opt(-7 * 3600).unwrap();
+    let dt = tz.with_ymd_and_hms(2023, 12, 31, 20, 15, 0).unwrap();
+    assert_eq!(dt.format_utc("%F %H:%M %z").to_string(), "2024-01-01 03:15 +0000");
+    assert_eq!(
+        dt.format_utc("%H:%M").to_string(),
+        dt.with_timezone(&Utc).format("%H:%M").to_string()
+    );
+
+    // leap seconds are preserved
+    let dt = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_500)
+        .unwrap()
+        .and_utc()
+        .with_timezone(&FixedOffset::east_opt(3600).unwrap());
+    assert_eq!(dt.format_utc("%H:%M:%S%.3f").to_string(), "23:59:60.500");
+}
+
 #[test]
 #[cfg(feature = "clock")]
 fn test_datetime_is_copy() {
@@ -1417,6 +2609,21 @@ fn test_datetime_from_local() {
     assert_eq!(datetime_west, datetime_utc.with_timezone(&timezone_west));
 }
 
+#[test]
+fn test_datetime_abs_diff() {
+    let utc = Utc.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+    let east =
+        FixedOffset::east_opt(5 * 3600).unwrap().with_ymd_and_hms(2023, 3, 1, 18, 30, 0).unwrap();
+    assert_eq!(utc.abs_diff(east), TimeDelta::minutes(90));
+    assert_eq!(east.abs_diff(utc), TimeDelta::minutes(90));
+    assert_eq!(utc.abs_diff(utc), TimeDelta::zero());
+
+    let min = DateTime::<Utc>::MIN_UTC;
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(min.abs_diff(max), max.signed_duration_since(min));
+    assert_eq!(max.abs_diff(min), max.signed_duration_since(min));
+}
+
 #[test]
 #[cfg(feature = "clock")]
 fn test_years_elapsed() {
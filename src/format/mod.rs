@@ -37,6 +37,8 @@ extern crate alloc;
 use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(any(feature = "alloc", feature = "std", test))]
 use core::borrow::Borrow;
 use core::fmt;
@@ -46,17 +48,23 @@ use core::str::FromStr;
 use std::error::Error;
 
 #[cfg(any(feature = "alloc", feature = "std", test))]
-use crate::naive::{NaiveDate, NaiveTime};
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(any(feature = "alloc", feature = "std", test))]
 use crate::offset::{FixedOffset, Offset};
 #[cfg(any(feature = "alloc", feature = "std", test))]
-use crate::{Datelike, Timelike};
-use crate::{Month, ParseMonthError, ParseWeekdayError, Weekday};
+use crate::{DateTime, Datelike, TimeZone, Timelike};
+use crate::{Days, Month, Months, ParseMonthError, ParseWeekdayError, Weekday};
 
 #[cfg(feature = "unstable-locales")]
 pub(crate) mod locales;
 
-pub use parse::{parse, parse_and_remainder};
+pub use parse::{
+    parse, parse_and_remainder, parse_best_effort, parse_with_am_pm, parse_with_timezone_names,
+};
+pub(crate) use parse::{
+    parse_http_date, parse_rfc2822_strict, parse_rfc3339_two_digit_year,
+    parse_rfc3339_with_precision,
+};
 pub use parsed::Parsed;
 /// L10n locales.
 #[cfg(feature = "unstable-locales")]
@@ -65,13 +73,9 @@ pub use strftime::StrftimeItems;
 
 #[cfg(not(feature = "unstable-locales"))]
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 struct Locale;
 
-/// An uninhabited type used for `InternalNumeric` and `InternalFixed` below.
-#[derive(Clone, PartialEq, Eq, Hash)]
-enum Void {}
-
 /// Padding characters for numeric items.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub enum Pad {
@@ -154,7 +158,7 @@ pub enum Numeric {
 /// An opaque type representing numeric item types for internal uses only.
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub struct InternalNumeric {
-    _dummy: Void,
+    val: InternalNumericKind,
 }
 
 impl fmt::Debug for InternalNumeric {
@@ -163,6 +167,17 @@ impl fmt::Debug for InternalNumeric {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum InternalNumericKind {
+    /// Quarter of the year, where January to March is 1 (FW=PW=1).
+    Quarter,
+    /// The number of non-leap milliseconds since the midnight UTC on January 1, 1970
+    /// (FW=1, PW=∞). Can be negative.
+    /// For formatting, it assumes UTC upon the absence of time zone offset.
+    /// For parsing, it sets both the timestamp and the nanosecond.
+    TimestampMillis,
+}
+
 /// Fixed-format item types.
 ///
 /// They have their own rules of formatting and parsing.
@@ -219,10 +234,12 @@ pub enum Fixed {
     /// The offset is limited from `-24:00:00` to `+24:00:00`,
     /// which is the same as [`FixedOffset`](../offset/struct.FixedOffset.html)'s range.
     TimezoneOffsetDoubleColon,
-    /// Offset from the local time to UTC without minutes (`+09` or `-04` or `+00`).
+    /// Offset from the local time to UTC with only the necessary precision (`+09` or `-04` or
+    /// `+00`, but `+05:30` or `+05:30:15` if the offset has minutes or seconds).
     ///
-    /// In the parser, the colon may be omitted,
-    /// The offset is limited from `-24` to `+24`,
+    /// In the parser, the minutes and the colon before them may be omitted (`+09`, `+0930` or
+    /// `+09:30`). Seconds are not parsed.
+    /// The offset is limited from `-24:00` to `+24:00`,
     /// which is the same as [`FixedOffset`](../offset/struct.FixedOffset.html)'s range.
     TimezoneOffsetTripleColon,
     /// Offset from the local time to UTC (`+09:00` or `-0400` or `Z`).
@@ -258,21 +275,26 @@ pub struct InternalFixed {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum InternalInternal {
-    /// Same as [`TimezoneOffsetColonZ`](#variant.TimezoneOffsetColonZ), but
-    /// allows missing minutes (per [ISO 8601][iso8601]).
-    ///
-    /// # Panics
+    /// Same as [`TimezoneOffsetColonZ`](#variant.TimezoneOffsetColonZ), but when parsing
+    /// allows missing minutes (per [ISO 8601][iso8601]) and one-digit hours and minutes, like
+    /// `+5`, `+530` or `+5:30`.
     ///
-    /// If you try to use this for printing.
+    /// Formats the same as [`TimezoneOffset`](#variant.TimezoneOffset).
     ///
     /// [iso8601]: https://en.wikipedia.org/wiki/ISO_8601#Time_offsets_from_UTC
     TimezoneOffsetPermissive,
+    /// Same as [`Nanosecond`](#variant.Nanosecond) but, when parsing, rounds half up to the
+    /// nearest nanosecond using the tenth fractional digit instead of truncating.
+    NanosecondRounded,
     /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 3 and there is no leading dot.
     Nanosecond3NoDot,
     /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 6 and there is no leading dot.
     Nanosecond6NoDot,
     /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 9 and there is no leading dot.
     Nanosecond9NoDot,
+    /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to the given number
+    /// of digits (1--9), truncating like [`Nanosecond3`](#variant.Nanosecond3).
+    NanosecondDigits(u8),
 }
 
 #[cfg(any(feature = "alloc", feature = "std", test))]
@@ -338,6 +360,14 @@ macro_rules! fix {
         Item::Fixed(Fixed::$x)
     };
 }
+macro_rules! internal_num {
+    ($x:ident) => {
+        Item::Numeric(
+            Numeric::Internal(InternalNumeric { val: InternalNumericKind::$x }),
+            Pad::None,
+        )
+    };
+}
 macro_rules! internal_fix {
     ($x:ident) => {
         Item::Fixed(Fixed::Internal(InternalFixed { val: InternalInternal::$x }))
@@ -423,6 +453,78 @@ const TOO_SHORT: ParseError = ParseError(ParseErrorKind::TooShort);
 const TOO_LONG: ParseError = ParseError(ParseErrorKind::TooLong);
 const BAD_FORMAT: ParseError = ParseError(ParseErrorKind::BadFormat);
 
+/// The strings for the [`Fixed::UpperAmPm`] (`%p`) and [`Fixed::LowerAmPm`] (`%P`) items,
+/// to format and parse localized meridiems without the `unstable-locales` feature.
+///
+/// Use it with [`DelayedFormat::with_am_pm`] for formatting and with [`parse_with_am_pm`] for
+/// parsing.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse_with_am_pm, AmPmNames, Parsed, StrftimeItems};
+/// use chrono::NaiveDate;
+///
+/// let names = AmPmNames::new("vorm.", "nachm.");
+/// let dt = NaiveDate::from_ymd_opt(2023, 5, 4).unwrap().and_hms_opt(15, 30, 0).unwrap();
+/// let formatted = dt.format("%d.%m.%Y %I:%M %p").with_am_pm(names).to_string();
+/// assert_eq!(formatted, "04.05.2023 03:30 nachm.");
+///
+/// let mut parsed = Parsed::new();
+/// parse_with_am_pm(&mut parsed, &formatted, StrftimeItems::new("%d.%m.%Y %I:%M %p"), names)?;
+/// assert_eq!(parsed.to_naive_datetime_with_offset(0)?, dt);
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AmPmNames<'a> {
+    /// The string for times before noon, i.e. hours 0 through 11.
+    pub am: &'a str,
+    /// The string for times from noon on, i.e. hours 12 through 23.
+    pub pm: &'a str,
+}
+
+impl<'a> AmPmNames<'a> {
+    /// The default strings, `AM` and `PM`.
+    pub const DEFAULT: AmPmNames<'static> = AmPmNames { am: "AM", pm: "PM" };
+
+    /// Makes new `AmPmNames` from the strings for times before and after noon.
+    ///
+    /// `%p` formats the strings as given and `%P` formats them in lowercase. When parsing, the
+    /// strings are matched case-insensitively for ASCII letters and the longest match wins.
+    /// Neither string should be empty or equal to the other, otherwise it can not be parsed.
+    #[must_use]
+    pub const fn new(am: &'a str, pm: &'a str) -> AmPmNames<'a> {
+        AmPmNames { am, pm }
+    }
+
+    /// Returns the AM/PM strings of the given locale, or `None` if the locale does not use them
+    /// (its strings are empty, as in most locales with a 24-hour clock).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::{parse_with_am_pm, AmPmNames, Parsed, StrftimeItems};
+    /// use chrono::{Locale, NaiveTime};
+    ///
+    /// let names = AmPmNames::from_locale(Locale::ko_KR).unwrap();
+    /// let mut parsed = Parsed::new();
+    /// parse_with_am_pm(&mut parsed, "오후 03:30", StrftimeItems::new("%p %I:%M"), names)?;
+    /// assert_eq!(parsed.to_naive_time()?, NaiveTime::from_hms_opt(15, 30, 0).unwrap());
+    ///
+    /// assert_eq!(AmPmNames::from_locale(Locale::fr_BE), None);
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    #[cfg(feature = "unstable-locales")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable-locales")))]
+    #[must_use]
+    pub fn from_locale(locale: Locale) -> Option<AmPmNames<'static>> {
+        match *locales::am_pm(locale) {
+            [am, pm] if !am.is_empty() && !pm.is_empty() => Some(AmPmNames { am, pm }),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(any(feature = "alloc", feature = "std", test))]
 struct Locales {
     short_months: &'static [&'static str],
@@ -491,7 +593,7 @@ pub fn format_item(
     item: &Item<'_>,
 ) -> fmt::Result {
     let mut result = String::new();
-    format_inner(&mut result, date, time, off, item, None)?;
+    format_inner(&mut result, date, time, off, item, None, None)?;
     w.pad(&result)
 }
 
@@ -503,8 +605,10 @@ fn format_inner(
     off: Option<&(String, FixedOffset)>,
     item: &Item<'_>,
     locale: Option<Locale>,
+    am_pm: Option<AmPmNames<'_>>,
 ) -> fmt::Result {
     let locale = Locales::new(locale);
+    let am_pm = am_pm.unwrap_or(AmPmNames { am: locale.am_pm[0], pm: locale.am_pm[1] });
 
     match *item {
         Item::Literal(s) | Item::Space(s) => result.push_str(s),
@@ -516,6 +620,10 @@ fn format_inner(
 
             let week_from_sun = |d: &NaiveDate| d.weeks_from(Weekday::Sun);
             let week_from_mon = |d: &NaiveDate| d.weeks_from(Weekday::Mon);
+            // like `%s` followed by `%3f`, a leap second does not carry into the seconds
+            let timestamp_millis = |dt: &NaiveDateTime| {
+                dt.timestamp() * 1000 + i64::from(dt.nanosecond() % 1_000_000_000 / 1_000_000)
+            };
 
             let (width, v) = match *spec {
                 Year => (4, date.map(|d| i64::from(d.year()))),
@@ -547,9 +655,20 @@ fn format_inner(
                         (_, _, _) => None,
                     },
                 ),
+                Internal(InternalNumeric { val: InternalNumericKind::TimestampMillis }) => (
+                    1,
+                    match (date, time, off) {
+                        (Some(d), Some(t), None) => Some(timestamp_millis(&d.and_time(*t))),
+                        (Some(d), Some(t), Some(&(_, off))) => {
+                            Some(timestamp_millis(&(d.and_time(*t) - off)))
+                        }
+                        (_, _, _) => None,
+                    },
+                ),
 
-                // for the future expansion
-                Internal(ref int) => match int._dummy {},
+                Internal(InternalNumeric { val: InternalNumericKind::Quarter }) => {
+                    (1, date.map(|d| i64::from(d.month0() / 3 + 1)))
+                }
             };
 
             if let Some(v) = v {
@@ -560,6 +679,9 @@ fn format_inner(
                         Pad::Zero => write!(result, "{:+01$}", v, width + 1),
                         Pad::Space => write!(result, "{:+1$}", v, width + 1),
                     }
+                } else if v >= 0 {
+                    write_padded(result, v as u64, width, *pad);
+                    Ok(())
                 } else {
                     match *pad {
                         Pad::None => write!(result, "{}", v),
@@ -598,32 +720,30 @@ fn format_inner(
                         Ok(())
                     }),
                     LowerAmPm => time.map(|t| {
-                        let ampm = if t.hour12().0 { locale.am_pm[1] } else { locale.am_pm[0] };
+                        let ampm = if t.hour12().0 { am_pm.pm } else { am_pm.am };
                         for char in ampm.chars() {
                             result.extend(char.to_lowercase())
                         }
                         Ok(())
                     }),
                     UpperAmPm => time.map(|t| {
-                        result.push_str(if t.hour12().0 {
-                            locale.am_pm[1]
-                        } else {
-                            locale.am_pm[0]
-                        });
+                        result.push_str(if t.hour12().0 { am_pm.pm } else { am_pm.am });
                         Ok(())
                     }),
-                    Nanosecond => time.map(|t| {
-                        let nano = t.nanosecond() % 1_000_000_000;
-                        if nano == 0 {
-                            Ok(())
-                        } else if nano % 1_000_000 == 0 {
-                            write!(result, ".{:03}", nano / 1_000_000)
-                        } else if nano % 1_000 == 0 {
-                            write!(result, ".{:06}", nano / 1_000)
-                        } else {
-                            write!(result, ".{:09}", nano)
-                        }
-                    }),
+                    Nanosecond
+                    | Internal(InternalFixed { val: InternalInternal::NanosecondRounded }) => time
+                        .map(|t| {
+                            let nano = t.nanosecond() % 1_000_000_000;
+                            if nano == 0 {
+                                Ok(())
+                            } else if nano % 1_000_000 == 0 {
+                                write!(result, ".{:03}", nano / 1_000_000)
+                            } else if nano % 1_000 == 0 {
+                                write!(result, ".{:06}", nano / 1_000)
+                            } else {
+                                write!(result, ".{:09}", nano)
+                            }
+                        }),
                     Nanosecond3 => time.map(|t| {
                         let nano = t.nanosecond() % 1_000_000_000;
                         write!(result, ".{:03}", nano / 1_000_000)
@@ -636,6 +756,13 @@ fn format_inner(
                         let nano = t.nanosecond() % 1_000_000_000;
                         write!(result, ".{:09}", nano)
                     }),
+                    Internal(InternalFixed { val: InternalInternal::NanosecondDigits(digits) }) => {
+                        time.map(|t| {
+                            let nano = t.nanosecond() % 1_000_000_000;
+                            let digits = u32::from(digits);
+                            write!(result, ".{:01$}", nano / 10u32.pow(9 - digits), digits as usize)
+                        })
+                    }
                     Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => time
                         .map(|t| {
                             let nano = t.nanosecond() % 1_000_000_000;
@@ -670,7 +797,7 @@ fn format_inner(
                         off.map(|&(_, off)| write_local_minus_utc(result, off, true, Colons::None))
                     }
                     Internal(InternalFixed { val: InternalInternal::TimezoneOffsetPermissive }) => {
-                        panic!("Do not try to write %#z it is undefined")
+                        off.map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::None))
                     }
                     RFC2822 =>
                     // same as `%a, %d %b %Y %H:%M:%S %z`
@@ -734,7 +861,18 @@ fn write_local_minus_utc(
             result.push(':');
             write_hundreds(result, (off % 60) as u8)
         }
-        Colons::Triple => Ok(()),
+        Colons::Triple => {
+            // only as precise as necessary, like GNU `date`
+            if off % 3600 != 0 {
+                result.push(':');
+                write_hundreds(result, (off / 60 % 60) as u8)?;
+            }
+            if off % 60 != 0 {
+                result.push(':');
+                write_hundreds(result, (off % 60) as u8)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -769,6 +907,27 @@ fn write_rfc2822_inner(
     t: &NaiveTime,
     off: FixedOffset,
     locale: Locales,
+) -> fmt::Result {
+    write_rfc2822_date_time(result, d, t, locale)?;
+    result.push(' ');
+    write_local_minus_utc(result, off, false, Colons::None)
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+/// write UTC datetimes like `Sun, 06 Nov 1994 08:49:37 GMT`, the IMF-fixdate format of RFC 7231
+pub(crate) fn write_http_date(result: &mut String, dt: crate::NaiveDateTime) -> fmt::Result {
+    write_rfc2822_date_time(result, &dt.date(), &dt.time(), Locales::new(None))?;
+    result.push_str(" GMT");
+    Ok(())
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+/// write the date and time of RFC 2822 without the zone, same as `%a, %d %b %Y %H:%M:%S`
+fn write_rfc2822_date_time(
+    result: &mut String,
+    d: &NaiveDate,
+    t: &NaiveTime,
+    locale: Locales,
 ) -> fmt::Result {
     let year = d.year();
     // RFC2822 is only defined on years 0 through 9999
@@ -790,9 +949,7 @@ fn write_rfc2822_inner(
     write_hundreds(result, t.minute() as u8)?;
     result.push(':');
     let sec = t.second() + t.nanosecond() / 1_000_000_000;
-    write_hundreds(result, sec as u8)?;
-    result.push(' ');
-    write_local_minus_utc(result, off, false, Colons::None)
+    write_hundreds(result, sec as u8)
 }
 
 /// Equivalent to `{:02}` formatting for n < 100.
@@ -807,6 +964,35 @@ pub(crate) fn write_hundreds(w: &mut impl Write, n: u8) -> fmt::Result {
     w.write_char(ones as char)
 }
 
+/// Equivalent to `{}`, `{:01$}` or `{:1$}` formatting of a non-negative integer, depending on
+/// `pad`, but writes the digits through a stack buffer instead of `fmt::Formatter`.
+#[cfg(any(feature = "alloc", feature = "std", test))]
+fn write_padded(result: &mut String, v: u64, width: usize, pad: Pad) {
+    let mut buf = [0u8; 20]; // u64::MAX has 20 digits
+    let mut pos = buf.len();
+    let mut n = v;
+    loop {
+        pos -= 1;
+        buf[pos] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+
+    let fill = match pad {
+        Pad::None => None,
+        Pad::Zero => Some('0'),
+        Pad::Space => Some(' '),
+    };
+    if let Some(fill) = fill {
+        for _ in (buf.len() - pos)..width {
+            result.push(fill);
+        }
+    }
+    result.extend(buf[pos..].iter().map(|&b| b as char));
+}
+
 /// Tries to format given arguments with given formatting items.
 /// Internally used by `DelayedFormat`.
 #[cfg(any(feature = "alloc", feature = "std", test))]
@@ -824,7 +1010,7 @@ where
 {
     let mut result = String::new();
     for item in items {
-        format_inner(&mut result, date, time, off, item.borrow(), None)?;
+        format_inner(&mut result, date, time, off, item.borrow(), None, None)?;
     }
     w.pad(&result)
 }
@@ -856,6 +1042,8 @@ pub struct DelayedFormat<I> {
     // only present when the feature is enabled.
     #[cfg(feature = "unstable-locales")]
     locale: Option<Locale>,
+    /// The strings for `%p` and `%P`, if they override those of the locale.
+    am_pm: Option<(String, String)>,
 }
 
 #[cfg(any(feature = "alloc", feature = "std", test))]
@@ -870,6 +1058,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
             items,
             #[cfg(feature = "unstable-locales")]
             locale: None,
+            am_pm: None,
         }
     }
 
@@ -892,6 +1081,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
             items,
             #[cfg(feature = "unstable-locales")]
             locale: None,
+            am_pm: None,
         }
     }
 
@@ -905,7 +1095,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
         items: I,
         locale: Locale,
     ) -> DelayedFormat<I> {
-        DelayedFormat { date, time, off: None, items, locale: Some(locale) }
+        DelayedFormat { date, time, off: None, items, locale: Some(locale), am_pm: None }
     }
 
     /// Makes a new `DelayedFormat` value out of local date and time, UTC offset and locale.
@@ -923,7 +1113,34 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
         Off: Offset + fmt::Display,
     {
         let name_and_diff = (offset.to_string(), offset.fix());
-        DelayedFormat { date, time, off: Some(name_and_diff), items, locale: Some(locale) }
+        DelayedFormat {
+            date,
+            time,
+            off: Some(name_and_diff),
+            items,
+            locale: Some(locale),
+            am_pm: None,
+        }
+    }
+
+    /// Uses the given strings for the `%p` and `%P` items, instead of `AM` and `PM` or those of
+    /// the locale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::AmPmNames;
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_opt(9, 15, 0).unwrap();
+    /// let names = AmPmNames::new("vorm.", "nachm.");
+    /// assert_eq!(t.format("%-I:%M %p").with_am_pm(names).to_string(), "9:15 vorm.");
+    /// assert_eq!(t.format("%-I:%M %p").to_string(), "9:15 AM");
+    /// ```
+    #[must_use]
+    pub fn with_am_pm(mut self, names: AmPmNames<'_>) -> DelayedFormat<I> {
+        self.am_pm = Some((names.am.to_string(), names.pm.to_string()));
+        self
     }
 }
 
@@ -931,26 +1148,96 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
 impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> fmt::Display for DelayedFormat<I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         #[cfg(feature = "unstable-locales")]
-        {
-            if let Some(locale) = self.locale {
-                return format_localized(
-                    f,
-                    self.date.as_ref(),
-                    self.time.as_ref(),
-                    self.off.as_ref(),
-                    self.items.clone(),
-                    locale,
-                );
-            }
+        let locale = self.locale;
+        #[cfg(not(feature = "unstable-locales"))]
+        let locale = None;
+        let am_pm = self.am_pm.as_ref().map(|(am, pm)| AmPmNames { am, pm });
+
+        let mut result = String::new();
+        for item in self.items.clone() {
+            format_inner(
+                &mut result,
+                self.date.as_ref(),
+                self.time.as_ref(),
+                self.off.as_ref(),
+                item.borrow(),
+                locale,
+                am_pm,
+            )?;
         }
+        f.pad(&result)
+    }
+}
+
+/// A `strftime`-like format string that is parsed once, so it can be applied to many values
+/// without parsing it again.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::Format;
+/// use chrono::{TimeZone, Utc};
+///
+/// let format = Format::parse("%Y-%m-%d %H:%M")?;
+/// let dt = Utc.with_ymd_and_hms(2023, 6, 1, 12, 30, 0).unwrap();
+/// assert_eq!(format.apply(&dt).to_string(), "2023-06-01 12:30");
+///
+/// // other types can use the parsed items with their `format_with_items` method
+/// let naive = dt.naive_utc();
+/// assert_eq!(naive.format_with_items(format.items()).to_string(), "2023-06-01 12:30");
+///
+/// assert!(Format::parse("%Y-%m-%d %J").is_err());
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[cfg(any(feature = "alloc", feature = "std", test))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Format<'a> {
+    items: Vec<Item<'a>>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<'a> Format<'a> {
+    /// Parses a `strftime`-like format string.
+    /// See the [`format::strftime` module](./strftime/index.html) on the supported escape
+    /// sequences.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseErrorKind::BadFormat`] if the format string contains an invalid or
+    /// unsupported specifier.
+    pub fn parse(fmt: &'a str) -> Result<Format<'a>, ParseError> {
+        let items = StrftimeItems::new(fmt).map(|item| match item {
+            Item::Error => Err(BAD_FORMAT),
+            item => Ok(item),
+        });
+        Ok(Format { items: items.collect::<Result<_, _>>()? })
+    }
 
-        format(f, self.date.as_ref(), self.time.as_ref(), self.off.as_ref(), self.items.clone())
+    /// Returns an iterator over the parsed formatting items, for use with the
+    /// `format_with_items` methods of the date and time types.
+    pub fn items(&self) -> core::slice::Iter<'_, Item<'a>> {
+        self.items.iter()
+    }
+
+    /// Formats the given `DateTime` with this format.
+    ///
+    /// This is the same as `dt.format(fmt)`, but without parsing `fmt` again.
+    #[must_use]
+    pub fn apply<Tz: TimeZone>(
+        &self,
+        dt: &DateTime<Tz>,
+    ) -> DelayedFormat<core::slice::Iter<'_, Item<'a>>>
+    where
+        Tz::Offset: fmt::Display,
+    {
+        dt.format_with_items(self.items())
     }
 }
 
 // this implementation is here only because we need some private code from `scan`
 
-/// Parsing a `str` into a `Weekday` uses the format [`%W`](./format/strftime/index.html).
+/// Parsing a `str` into a `Weekday` uses the format [`%A`](./format/strftime/index.html).
 ///
 /// # Example
 ///
@@ -968,11 +1255,14 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> fmt::Display for De
 /// assert_eq!("mON".parse::<Weekday>(), Ok(Weekday::Mon));
 /// ```
 ///
-/// Only the shortest form (e.g. `sun`) and the longest form (e.g. `sunday`) is accepted.
+/// Only the shortest form (e.g. `sun`) and the longest form (e.g. `sunday`) is accepted, as well
+/// as the common abbreviations `tues`, `thur` and `thurs`.
 ///
 /// ```
 /// # use chrono::Weekday;
-/// assert!("thurs".parse::<Weekday>().is_err());
+/// assert_eq!("thurs".parse::<Weekday>(), Ok(Weekday::Thu));
+/// assert!("satur".parse::<Weekday>().is_err());
+/// assert!("Sunday ".parse::<Weekday>().is_err());
 /// ```
 impl FromStr for Weekday {
     type Err = ParseWeekdayError;
@@ -998,7 +1288,7 @@ pub fn format_item_localized(
     locale: Locale,
 ) -> fmt::Result {
     let mut result = String::new();
-    format_inner(&mut result, date, time, off, item, Some(locale))?;
+    format_inner(&mut result, date, time, off, item, Some(locale), None)?;
     w.pad(&result)
 }
 
@@ -1020,12 +1310,12 @@ where
 {
     let mut result = String::new();
     for item in items {
-        format_inner(&mut result, date, time, off, item.borrow(), Some(locale))?;
+        format_inner(&mut result, date, time, off, item.borrow(), Some(locale), None)?;
     }
     w.pad(&result)
 }
 
-/// Parsing a `str` into a `Month` uses the format [`%W`](./format/strftime/index.html).
+/// Parsing a `str` into a `Month` uses the format [`%B`](./format/strftime/index.html).
 ///
 /// # Example
 ///
@@ -1075,3 +1365,236 @@ impl FromStr for Month {
         }
     }
 }
+
+/// Parses a non-negative number of calendar units, optionally followed by whitespace and the
+/// singular or plural unit name (compared case-insensitively).
+fn parse_calendar_units(s: &str, singular: &str, plural: &str) -> ParseResult<u64> {
+    let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return Err(if s.is_empty() { TOO_SHORT } else { INVALID });
+    }
+    let mut n = 0u64;
+    for b in s[..digits].bytes() {
+        n = n
+            .checked_mul(10)
+            .and_then(|n| n.checked_add(u64::from(b - b'0')))
+            .ok_or(OUT_OF_RANGE)?;
+    }
+
+    let rest = &s[digits..];
+    if rest.is_empty() {
+        return Ok(n);
+    }
+    let unit = rest.trim_start();
+    if unit.len() == rest.len() {
+        return Err(INVALID);
+    }
+    if unit.is_empty() {
+        return Err(TOO_SHORT);
+    }
+    if unit.eq_ignore_ascii_case(singular) || unit.eq_ignore_ascii_case(plural) {
+        Ok(n)
+    } else {
+        Err(INVALID)
+    }
+}
+
+/// Parsing a `str` into `Months` accepts the output of its `Display` implementation.
+///
+/// The accepted grammar is a decimal number, optionally followed by whitespace and the unit
+/// `month` or `months`. The unit is case-insensitive, and the singular and plural forms are
+/// accepted for any number. Signs and leading or trailing whitespace are not allowed.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Months;
+///
+/// assert_eq!("3 months".parse::<Months>(), Ok(Months::new(3)));
+/// assert_eq!("1 Month".parse::<Months>(), Ok(Months::new(1)));
+/// assert_eq!("12".parse::<Months>(), Ok(Months::new(12)));
+/// assert_eq!(Months::new(3).to_string().parse::<Months>(), Ok(Months::new(3)));
+///
+/// assert!("-1 months".parse::<Months>().is_err());
+/// assert!("3 days".parse::<Months>().is_err());
+/// assert!("3months".parse::<Months>().is_err());
+/// assert!("4294967296 months".parse::<Months>().is_err());
+/// ```
+impl FromStr for Months {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Months> {
+        let n = parse_calendar_units(s, "month", "months")?;
+        Ok(Months::new(u32::try_from(n).map_err(|_| OUT_OF_RANGE)?))
+    }
+}
+
+/// Parsing a `str` into `Days` accepts the output of its `Display` implementation.
+///
+/// The accepted grammar is a decimal number, optionally followed by whitespace and the unit
+/// `day` or `days`. The unit is case-insensitive, and the singular and plural forms are accepted
+/// for any number. Signs and leading or trailing whitespace are not allowed.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Days;
+///
+/// assert_eq!("10 days".parse::<Days>(), Ok(Days::new(10)));
+/// assert_eq!("1 day".parse::<Days>(), Ok(Days::new(1)));
+/// assert_eq!("7".parse::<Days>(), Ok(Days::new(7)));
+/// assert_eq!(Days::new(10).to_string().parse::<Days>(), Ok(Days::new(10)));
+///
+/// assert!("ten days".parse::<Days>().is_err());
+/// assert!("10 days ".parse::<Days>().is_err());
+/// ```
+impl FromStr for Days {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Days> {
+        parse_calendar_units(s, "day", "days").map(Days::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_padded, AmPmNames, Format, Pad, ParseErrorKind};
+    use crate::{Days, FixedOffset, Months, NaiveDate, TimeZone};
+
+    #[test]
+    fn test_write_padded_matches_fmt() {
+        let values = [0, 1, 7, 9, 10, 42, 99, 100, 999, 1_000, 9_999, 10_000, 123_456_789];
+        let values = values.iter().copied().chain([u64::from(u32::MAX), u64::MAX]);
+        for v in values {
+            for width in 0..12 {
+                let mut s = String::new();
+                write_padded(&mut s, v, width, Pad::None);
+                assert_eq!(s, format!("{}", v));
+
+                let mut s = String::new();
+                write_padded(&mut s, v, width, Pad::Zero);
+                assert_eq!(s, format!("{:01$}", v, width));
+
+                let mut s = String::new();
+                write_padded(&mut s, v, width, Pad::Space);
+                assert_eq!(s, format!("{:1$}", v, width));
+            }
+        }
+    }
+
+    #[test]
+    fn test_numeric_format_unchanged() {
+        let dt = NaiveDate::from_ymd_opt(2001, 2, 3)
+            .unwrap()
+            .and_hms_nano_opt(4, 5, 6, 7_008_009)
+            .unwrap();
+        assert_eq!(dt.format("%Y%m%d%H%M%S").to_string(), "20010203040506");
+        assert_eq!(dt.format("%-m/%-d %_H:%_M %9f").to_string(), "2/3  4: 5 007008009");
+        assert_eq!(
+            dt.format("%j %C %y %G %U %W %V %u %w %s").to_string(),
+            "034 20 01 2001 04 05 05 6 6 981173106"
+        );
+
+        let dt = NaiveDate::from_ymd_opt(12_345, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(dt.format("%Y %_Y %-Y").to_string(), "+12345 +12345 +12345");
+        let dt = NaiveDate::from_ymd_opt(-1, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(dt.format("%Y %C %y %s").to_string(), "-0001 -1 99 -62198755200");
+    }
+
+    #[test]
+    fn test_format_reuse() {
+        let fmts = ["%Y-%m-%d %H:%M:%S", "%+", "%a %b %e %T %Y %z", "%s%.3f", "%c %:z %%", ""];
+        let tz = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+        let dts = [
+            tz.with_ymd_and_hms(2001, 2, 3, 4, 5, 6).unwrap(),
+            tz.with_ymd_and_hms(-12, 12, 31, 23, 59, 59).unwrap(),
+            tz.timestamp_nanos(1_234_567_890_123),
+        ];
+        for fmt in fmts {
+            let format = Format::parse(fmt).unwrap();
+            for dt in &dts {
+                assert_eq!(format.apply(dt).to_string(), dt.format(fmt).to_string());
+                // the format can be used again
+                assert_eq!(format.apply(dt).to_string(), dt.format(fmt).to_string());
+
+                if fmt.contains('z') || fmt.contains('+') {
+                    continue; // the naive types can not format an offset
+                }
+                let naive = dt.naive_local();
+                assert_eq!(
+                    naive.format_with_items(format.items()).to_string(),
+                    naive.format(fmt).to_string()
+                );
+            }
+        }
+
+        assert_eq!(Format::parse("%Y-%m-%d %Q").map(|f| f.items().count()), Ok(7));
+        assert_eq!(Format::parse("%Y-%m-%d %J").unwrap_err().kind(), ParseErrorKind::BadFormat);
+        assert_eq!(Format::parse("%").unwrap_err().kind(), ParseErrorKind::BadFormat);
+    }
+
+    #[test]
+    fn test_format_with_am_pm() {
+        let names = AmPmNames::new("vorm.", "nachm.");
+        let dt = NaiveDate::from_ymd_opt(2023, 5, 4).unwrap().and_hms_opt(11, 59, 0).unwrap();
+        assert_eq!(dt.format("%I:%M %p").with_am_pm(names).to_string(), "11:59 vorm.");
+        assert_eq!(dt.format("%I:%M %P").with_am_pm(names).to_string(), "11:59 vorm.");
+        let dt = dt + crate::TimeDelta::minutes(1);
+        assert_eq!(dt.format("%I:%M %p").with_am_pm(names).to_string(), "12:00 nachm.");
+        assert_eq!(dt.format("%r").with_am_pm(names).to_string(), "12:00:00 nachm.");
+
+        // `%P` is lowercase
+        let names = AmPmNames::new("Vorm.", "NACHM.");
+        assert_eq!(dt.format("%P").with_am_pm(names).to_string(), "nachm.");
+        assert_eq!(dt.format("%p").with_am_pm(names).to_string(), "NACHM.");
+
+        // the default names, and padding still applies
+        assert_eq!(dt.format("%p").with_am_pm(AmPmNames::DEFAULT).to_string(), "PM");
+        assert_eq!(format!("{:>8}", dt.format("%p").with_am_pm(names)), "  NACHM.");
+
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let dt = tz.with_ymd_and_hms(2023, 5, 4, 23, 0, 0).unwrap();
+        assert_eq!(dt.format("%H %p %z").with_am_pm(names).to_string(), "23 NACHM. +0100");
+    }
+
+    #[cfg(feature = "unstable-locales")]
+    #[test]
+    fn test_am_pm_names_from_locale() {
+        use super::{parse_with_am_pm, Locale, Parsed, StrftimeItems};
+        use crate::Utc;
+
+        assert_eq!(AmPmNames::from_locale(Locale::POSIX), Some(AmPmNames::DEFAULT));
+        assert_eq!(AmPmNames::from_locale(Locale::fr_BE), None);
+
+        // what `format_localized` writes can be parsed back
+        let date = NaiveDate::from_ymd_opt(2023, 5, 4).unwrap();
+        for locale in [Locale::POSIX, Locale::en_US, Locale::ko_KR, Locale::ja_JP] {
+            let names = AmPmNames::from_locale(locale).unwrap();
+            for hour in [0, 1, 11, 12, 13, 23] {
+                let dt = Utc.from_utc_datetime(&date.and_hms_opt(hour, 30, 0).unwrap());
+                for fmt in ["%I:%M %p", "%p %I:%M", "%I:%M%P"] {
+                    let s = dt.format_localized(fmt, locale).to_string();
+                    let mut parsed = Parsed::new();
+                    parse_with_am_pm(&mut parsed, &s, StrftimeItems::new(fmt), names).unwrap();
+                    assert_eq!(parsed.to_naive_time(), Ok(dt.time()), "{:?} {}", locale, s);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_months_and_days_from_str_error_kinds() {
+        let kind = |s: &str| s.parse::<Months>().unwrap_err().kind();
+        assert_eq!(kind(""), ParseErrorKind::TooShort);
+        assert_eq!(kind("1 "), ParseErrorKind::TooShort);
+        assert_eq!(kind("x"), ParseErrorKind::Invalid);
+        assert_eq!(kind("1x"), ParseErrorKind::Invalid);
+        assert_eq!(kind("1 days"), ParseErrorKind::Invalid);
+        assert_eq!(kind("4294967296 months"), ParseErrorKind::OutOfRange);
+
+        let kind = |s: &str| s.parse::<Days>().unwrap_err().kind();
+        assert_eq!(kind("-1"), ParseErrorKind::Invalid);
+        assert_eq!(kind("1 months"), ParseErrorKind::Invalid);
+        assert_eq!(kind("99999999999999999999"), ParseErrorKind::OutOfRange);
+    }
+}
@@ -7,14 +7,20 @@
 #![allow(deprecated)]
 
 use core::borrow::Borrow;
-use core::str;
 use core::usize;
+use core::{iter, str};
 
 use super::scan;
-use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad, Parsed};
+use super::{
+    AmPmNames, Fixed, InternalFixed, InternalInternal, InternalNumeric, InternalNumericKind, Item,
+    Numeric, Pad, Parsed, StrftimeItems,
+};
 use super::{ParseError, ParseErrorKind, ParseResult};
 use super::{BAD_FORMAT, INVALID, NOT_ENOUGH, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
-use crate::{DateTime, FixedOffset, Weekday};
+use crate::{DateTime, FixedOffset, LocalResult, TimeDelta, Weekday};
+
+/// The lookup of time zone names for `%Z`, see [`parse_with_timezone_names`].
+type TimezoneNames<'a> = &'a dyn Fn(&str) -> LocalResult<FixedOffset>;
 
 fn set_weekday_with_num_days_from_sunday(p: &mut Parsed, v: i64) -> ParseResult<()> {
     p.set_weekday(match v {
@@ -161,7 +167,179 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     Ok((s, ()))
 }
 
-fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, ())> {
+/// Parse an RFC 2822 format datetime without the obsolete syntax accepted by `parse_rfc2822`,
+/// e.g. `Fri, 21 Nov 1997 09:55:06 -0600`
+///
+/// All of the input must be consumed.
+pub(crate) fn parse_rfc2822_strict(parsed: &mut Parsed, mut s: &str) -> ParseResult<()> {
+    macro_rules! try_consume {
+        ($e:expr) => {{
+            let (s_, v) = $e?;
+            s = s_;
+            v
+        }};
+    }
+
+    // the RFC 2822 syntax from Section 3.3, without the obsolete forms of Section 4.3:
+    //
+    // date-time   = [ day-of-week "," SP ] date SP time
+    // day-of-week = "Mon" / "Tue" / "Wed" / "Thu" / "Fri" / "Sat" / "Sun"
+    // date        = 1*2DIGIT SP month SP 4*DIGIT
+    // month       = "Jan" / "Feb" / "Mar" / "Apr" / "May" / "Jun" /
+    //               "Jul" / "Aug" / "Sep" / "Oct" / "Nov" / "Dec"
+    // time        = 2DIGIT ":" 2DIGIT [ ":" 2DIGIT ] SP zone
+    // zone        = ( "+" / "-" ) 4DIGIT
+    //
+    // some notes:
+    //
+    // - quoted characters can be in any mixture of lower and upper cases.
+    //
+    // - folding white space is only accepted in its canonical form of a single SP (`%x20`),
+    //   and comments (CFWS) are not accepted at all.
+    //
+    // - two- and three-digit years, the named zones and the single-letter military zones are
+    //   obsolete syntax and rejected.
+
+    if let Ok((s_, weekday)) = scan::short_weekday(s) {
+        s = scan::char(scan::char(s_, b',')?, b' ')?;
+        parsed.set_weekday(weekday)?;
+    }
+
+    parsed.set_day(try_consume!(scan::number(s, 1, 2)))?;
+    s = scan::char(s, b' ')?;
+    parsed.set_month(1 + i64::from(try_consume!(scan::short_month0(s))))?;
+    s = scan::char(s, b' ')?;
+    parsed.set_year(try_consume!(scan::number(s, 4, usize::MAX)))?;
+
+    s = scan::char(s, b' ')?;
+    parsed.set_hour(try_consume!(scan::number(s, 2, 2)))?;
+    s = scan::char(s, b':')?;
+    parsed.set_minute(try_consume!(scan::number(s, 2, 2)))?;
+    if let Ok(s_) = scan::char(s, b':') {
+        parsed.set_second(try_consume!(scan::number(s_, 2, 2)))?;
+    }
+
+    s = scan::char(s, b' ')?;
+    parsed.set_offset(i64::from(try_consume!(scan::timezone_offset(s, |s| Ok(s)))))?;
+
+    if !s.is_empty() {
+        return Err(TOO_LONG);
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_http_date(parsed: &mut Parsed, mut s: &str) -> ParseResult<()> {
+    macro_rules! try_consume {
+        ($e:expr) => {{
+            let (s_, v) = $e?;
+            s = s_;
+            v
+        }};
+    }
+
+    // the HTTP-date syntax from RFC 7231 Section 7.1.1.1:
+    //
+    // HTTP-date    = IMF-fixdate / rfc850-date / asctime-date
+    // IMF-fixdate  = day-name "," SP day SP month SP 4DIGIT SP time-of-day SP "GMT"
+    // rfc850-date  = day-name-l "," SP day "-" month "-" 2DIGIT SP time-of-day SP "GMT"
+    // asctime-date = day-name SP month SP ( 2DIGIT / ( SP 1DIGIT ) ) SP time-of-day SP 4DIGIT
+    // day-name     = "Mon" / "Tue" / "Wed" / "Thu" / "Fri" / "Sat" / "Sun"
+    // day-name-l   = "Monday" / "Tuesday" / "Wednesday" / "Thursday" / "Friday" /
+    //                "Saturday" / "Sunday"
+    // day          = 2DIGIT
+    // month        = "Jan" / "Feb" / "Mar" / "Apr" / "May" / "Jun" /
+    //                "Jul" / "Aug" / "Sep" / "Oct" / "Nov" / "Dec"
+    // time-of-day  = 2DIGIT ":" 2DIGIT ":" 2DIGIT
+    //
+    // some notes:
+    //
+    // - day and month names can be in any mixture of lower and upper cases, "GMT" can not.
+    //
+    // - all three forms are in UTC; the asctime form has no zone at all.
+    //
+    // - a two-digit year of the RFC 850 form < 50 is interpreted by adding 2000, otherwise by
+    //   adding 1900, like for RFC 2822. RFC 7231 instead asks to pick the most recent year in the
+    //   past with the same last two digits if the year appears to be more than 50 years in the
+    //   future, which depends on the current date.
+
+    fn time_of_day<'a>(parsed: &mut Parsed, s: &'a str) -> ParseResult<&'a str> {
+        let (s, hour) = scan::number(s, 2, 2)?;
+        parsed.set_hour(hour)?;
+        let (s, minute) = scan::number(scan::char(s, b':')?, 2, 2)?;
+        parsed.set_minute(minute)?;
+        let (s, second) = scan::number(scan::char(s, b':')?, 2, 2)?;
+        parsed.set_second(second)?;
+        Ok(s)
+    }
+
+    let (s_, weekday) = scan::short_weekday(s)?;
+    parsed.set_weekday(weekday)?;
+
+    if let Ok(s_) = scan::char(s_, b' ') {
+        // asctime-date
+        s = s_;
+        parsed.set_month(1 + i64::from(try_consume!(scan::short_month0(s))))?;
+        s = scan::char(s, b' ')?;
+        if let Ok(s_) = scan::char(s, b' ') {
+            parsed.set_day(try_consume!(scan::number(s_, 1, 1)))?;
+        } else {
+            parsed.set_day(try_consume!(scan::number(s, 2, 2)))?;
+        }
+        s = time_of_day(parsed, scan::char(s, b' ')?)?;
+        s = scan::char(s, b' ')?;
+        parsed.set_year(try_consume!(scan::number(s, 4, 4)))?;
+    } else {
+        let rfc850 = !s_.starts_with(',');
+        if rfc850 {
+            // the long weekday name must be complete, `Tues` and `Thurs` are not accepted
+            let long_len = [6, 7, 9, 8, 6, 8, 6][weekday.num_days_from_monday() as usize];
+            let (s_, _) = scan::short_or_long_weekday(s)?;
+            if s.len() - s_.len() != long_len {
+                return Err(INVALID);
+            }
+            s = s_;
+        } else {
+            s = s_;
+        }
+        s = scan::char(scan::char(s, b',')?, b' ')?;
+        parsed.set_day(try_consume!(scan::number(s, 2, 2)))?;
+        let separator = if rfc850 { b'-' } else { b' ' };
+        s = scan::char(s, separator)?;
+        parsed.set_month(1 + i64::from(try_consume!(scan::short_month0(s))))?;
+        s = scan::char(s, separator)?;
+        if rfc850 {
+            let year = try_consume!(scan::number(s, 2, 2));
+            parsed.set_year(if year < 50 { year + 2000 } else { year + 1900 })?;
+        } else {
+            parsed.set_year(try_consume!(scan::number(s, 4, 4)))?;
+        }
+        s = time_of_day(parsed, scan::char(s, b' ')?)?;
+        s = scan::char(s, b' ')?;
+        s = s.strip_prefix("GMT").ok_or(if s.len() < 3 { TOO_SHORT } else { INVALID })?;
+    }
+    parsed.set_offset(0)?;
+
+    if !s.is_empty() {
+        return Err(TOO_LONG);
+    }
+    Ok(())
+}
+
+/// Parse an RFC 3339 datetime like `parse_rfc3339`, and return the number of fractional digits
+/// of the seconds in the input (0 if there is no fraction, and at most 9).
+///
+/// All of the input must be consumed.
+pub(crate) fn parse_rfc3339_with_precision(parsed: &mut Parsed, s: &str) -> ParseResult<u8> {
+    let (s, precision) = parse_rfc3339(parsed, s)?;
+    if !s.is_empty() {
+        return Err(TOO_LONG);
+    }
+    Ok(precision)
+}
+
+/// Parses an RFC 3339 datetime, and returns the remaining input and the number of fractional
+/// digits of the seconds.
+fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, u8)> {
     macro_rules! try_consume {
         ($e:expr) => {{
             let (s_, v) = $e?;
@@ -193,11 +371,47 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     // - it may accept any number of fractional digits for seconds.
     //   for Chrono, this means that we should skip digits past first 9 digits.
     //
+    // - ISO 8601 also allows a comma as the decimal separator of `time-secfrac`.
+    //   we accept it as well, but always print a period.
+    //
     // - unlike RFC 2822, the valid offset ranges from -23:59 to +23:59.
     //   note that this restriction is unique to RFC 3339 and not ISO 8601.
     //   since this is not a typical Chrono behavior, we check it earlier.
 
     parsed.set_year(try_consume!(scan::number(s, 4, 4)))?;
+    parse_rfc3339_after_year(parsed, s)
+}
+
+/// Parse an RFC 3339 datetime like `parse_rfc3339`, but also accept a two-digit year such as
+/// `23-01-18T00:00:00Z`. A two-digit year sets the `year_mod_100` field, so it is in 1970--2069.
+///
+/// All of the input must be consumed.
+pub(crate) fn parse_rfc3339_two_digit_year(parsed: &mut Parsed, s: &str) -> ParseResult<()> {
+    let bytes = s.as_bytes();
+    let two_digit_year =
+        bytes.len() > 2 && bytes[..2].iter().all(u8::is_ascii_digit) && bytes[2] == b'-';
+    let (s, _) = if two_digit_year {
+        parsed.set_year_mod_100(i64::from((bytes[0] - b'0') * 10 + (bytes[1] - b'0')))?;
+        parse_rfc3339_after_year(parsed, &s[2..])?
+    } else {
+        parse_rfc3339(parsed, s)?
+    };
+    if !s.is_empty() {
+        return Err(TOO_LONG);
+    }
+    Ok(())
+}
+
+/// Parses the rest of an RFC 3339 datetime after the year, see `parse_rfc3339`.
+fn parse_rfc3339_after_year<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, u8)> {
+    macro_rules! try_consume {
+        ($e:expr) => {{
+            let (s_, v) = $e?;
+            s = s_;
+            v
+        }};
+    }
+
     s = scan::char(s, b'-')?;
     parsed.set_month(try_consume!(scan::number(s, 2, 2)))?;
     s = scan::char(s, b'-')?;
@@ -214,9 +428,11 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     parsed.set_minute(try_consume!(scan::number(s, 2, 2)))?;
     s = scan::char(s, b':')?;
     parsed.set_second(try_consume!(scan::number(s, 2, 2)))?;
-    if s.starts_with('.') {
-        let nanosecond = try_consume!(scan::nanosecond(&s[1..]));
+    let mut precision = 0;
+    if s.starts_with('.') || s.starts_with(',') {
+        let (nanosecond, digits) = try_consume!(scan::nanosecond_with_digits(&s[1..]));
         parsed.set_nanosecond(nanosecond)?;
+        precision = digits;
     }
 
     let offset = try_consume!(scan::timezone_offset_zulu(s, |s| scan::char(s, b':')));
@@ -225,7 +441,7 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     }
     parsed.set_offset(i64::from(offset))?;
 
-    Ok((s, ()))
+    Ok((s, precision))
 }
 
 /// Tries to parse given string into `parsed` with given formatting items.
@@ -249,7 +465,83 @@ where
     I: Iterator<Item = B>,
     B: Borrow<Item<'a>>,
 {
-    parse_internal(parsed, s, items).map(|_| ()).map_err(|(_s, e)| e)
+    parse_internal(parsed, s, items, None, None).map(|_| ()).map_err(|(_s, e)| e)
+}
+
+/// Same as [`parse`], but the `%p` and `%P` items match the given strings instead of `AM` and
+/// `PM`.
+///
+/// See [`AmPmNames`] for an example.
+pub fn parse_with_am_pm<'a, I, B>(
+    parsed: &mut Parsed,
+    s: &str,
+    items: I,
+    am_pm: AmPmNames<'_>,
+) -> ParseResult<()>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+{
+    parse_internal(parsed, s, items, Some(am_pm), None).map(|_| ()).map_err(|(_s, e)| e)
+}
+
+/// Same as [`parse`], but the time zone name of `%Z` is looked up with the given function instead
+/// of being skipped.
+///
+/// The lookup returns:
+///
+/// - `LocalResult::Single(offset)` for a name with a known offset, which sets the
+///   [`offset`](Parsed#structfield.offset) field.
+/// - `LocalResult::Ambiguous(..)` for a known name that can refer to different offsets, like `CST`
+///   (Central Standard Time in North America, or China Standard Time). The offset is left unset.
+/// - `LocalResult::None` for a name that is not accepted, which fails the parsing with `INVALID`.
+///
+/// In the first two cases the name is recorded, and can be read back with
+/// [`Parsed::timezone_name`]. Names are at most 16 bytes long.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::{parse_with_timezone_names, Parsed, StrftimeItems};
+/// use chrono::{FixedOffset, LocalResult};
+///
+/// let lookup = |name: &str| match name {
+///     "UTC" | "GMT" => LocalResult::Single(FixedOffset::east_opt(0).unwrap()),
+///     "CST" => LocalResult::Ambiguous(
+///         FixedOffset::west_opt(6 * 3600).unwrap(),
+///         FixedOffset::east_opt(8 * 3600).unwrap(),
+///     ),
+///     _ => LocalResult::None,
+/// };
+/// let items = || StrftimeItems::new("%Y-%m-%d %H:%M %Z");
+///
+/// let mut parsed = Parsed::new();
+/// parse_with_timezone_names(&mut parsed, "2023-07-01 12:00 GMT", items(), lookup)?;
+/// assert_eq!(parsed.offset, Some(0));
+/// assert_eq!(parsed.timezone_name(), Some("GMT"));
+/// assert_eq!(parsed.to_datetime()?.to_string(), "2023-07-01 12:00:00 +00:00");
+///
+/// let mut parsed = Parsed::new();
+/// parse_with_timezone_names(&mut parsed, "2023-07-01 12:00 CST", items(), lookup)?;
+/// assert_eq!(parsed.offset, None);
+/// assert_eq!(parsed.timezone_name(), Some("CST"));
+///
+/// let mut parsed = Parsed::new();
+/// assert!(parse_with_timezone_names(&mut parsed, "2023-07-01 12:00 XYZ", items(), lookup).is_err());
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+pub fn parse_with_timezone_names<'a, I, B, F>(
+    parsed: &mut Parsed,
+    s: &str,
+    items: I,
+    lookup: F,
+) -> ParseResult<()>
+where
+    I: Iterator<Item = B>,
+    B: Borrow<Item<'a>>,
+    F: Fn(&str) -> LocalResult<FixedOffset>,
+{
+    parse_internal(parsed, s, items, None, Some(&lookup)).map(|_| ()).map_err(|(_s, e)| e)
 }
 
 /// Tries to parse given string into `parsed` with given formatting items.
@@ -275,17 +567,55 @@ where
     I: Iterator<Item = B>,
     B: Borrow<Item<'a>>,
 {
-    match parse_internal(parsed, s, items) {
+    match parse_internal(parsed, s, items, None, None) {
         Ok(s) => Ok(s),
         Err((s, ParseError(ParseErrorKind::TooLong))) => Ok(s),
         Err((_s, e)) => Err(e),
     }
 }
 
+/// Parses as much of `s` as possible with the strftime-like format string `fmt`.
+///
+/// Unlike [`parse`] this never fails. The items of `fmt` are parsed one by one until the end of
+/// the format string, or until an item does not match the input. The fields of every item that
+/// matched are set on the returned [`Parsed`], along with the remainder of `s` starting at the
+/// first item that did not match (or the trailing input after the last item).
+///
+/// The returned `Parsed` may therefore be incomplete, or even empty: converting it with for
+/// example [`Parsed::to_naive_date`] can still fail. An invalid format string stops the parsing
+/// at the offending specifier.
+///
+/// This is useful to extract whatever date information is present in unstructured input, such as
+/// log lines.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::parse_best_effort;
+///
+/// let (parsed, remainder) = parse_best_effort("2023-07 (no day)", "%Y-%m-%d");
+/// assert_eq!((parsed.year, parsed.month, parsed.day), (Some(2023), Some(7), None));
+/// assert_eq!(remainder, " (no day)");
+/// assert!(parsed.to_naive_date().is_err());
+/// ```
+#[must_use]
+pub fn parse_best_effort<'a>(mut s: &'a str, fmt: &str) -> (Parsed, &'a str) {
+    let mut parsed = Parsed::new();
+    for item in StrftimeItems::new(fmt) {
+        match parse_and_remainder(&mut parsed, s, iter::once(item)) {
+            Ok(remainder) => s = remainder,
+            Err(_) => break,
+        }
+    }
+    (parsed, s)
+}
+
 fn parse_internal<'a, 'b, I, B>(
     parsed: &mut Parsed,
     mut s: &'b str,
     items: I,
+    am_pm: Option<AmPmNames<'_>>,
+    timezone_names: Option<TimezoneNames<'_>>,
 ) -> Result<&'b str, (&'b str, ParseError)>
 where
     I: Iterator<Item = B>,
@@ -359,7 +689,7 @@ where
                 }
             }
 
-            Item::Numeric(ref spec, ref _pad) => {
+            Item::Numeric(ref spec, ref pad) => {
                 use super::Numeric::*;
                 type Setter = fn(&mut Parsed, i64) -> ParseResult<()>;
 
@@ -385,10 +715,21 @@ where
                     Nanosecond => (9, false, Parsed::set_nanosecond),
                     Timestamp => (usize::MAX, false, Parsed::set_timestamp),
 
-                    // for the future expansion
-                    Internal(ref int) => match int._dummy {},
+                    Internal(InternalNumeric { val: InternalNumericKind::Quarter }) => {
+                        (1, false, Parsed::set_quarter)
+                    }
+                    Internal(InternalNumeric { val: InternalNumericKind::TimestampMillis }) => {
+                        (usize::MAX, true, Parsed::set_timestamp_millis)
+                    }
                 };
 
+                // space padding is written before the number to fill its width,
+                // so accept it for items like `%e`, `%k` and `%l`.
+                if *pad == Pad::Space && width != usize::MAX {
+                    let spaces = s.bytes().take(width - 1).take_while(|&b| b == b' ').count();
+                    s = &s[spaces..];
+                }
+
                 let v = if signed {
                     if s.starts_with('-') {
                         let v = try_consume!(scan::number(&s[1..], 1, usize::MAX));
@@ -430,6 +771,11 @@ where
                     }
 
                     &LowerAmPm | &UpperAmPm => {
+                        if let Some(names) = am_pm {
+                            let ampm = try_consume!(scan::am_pm(s, names.am, names.pm));
+                            parsed.set_ampm(ampm).map_err(|e| (s, e))?;
+                            continue;
+                        }
                         if s.len() < 2 {
                             return Err((s, TOO_SHORT));
                         }
@@ -442,13 +788,24 @@ where
                         s = &s[2..];
                     }
 
-                    &Nanosecond | &Nanosecond3 | &Nanosecond6 | &Nanosecond9 => {
+                    &Nanosecond
+                    | &Nanosecond3
+                    | &Nanosecond6
+                    | &Nanosecond9
+                    | &Internal(InternalFixed { val: InternalInternal::NanosecondDigits(_) }) => {
                         if s.starts_with('.') {
                             let nano = try_consume!(scan::nanosecond(&s[1..]));
                             parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
                         }
                     }
 
+                    &Internal(InternalFixed { val: InternalInternal::NanosecondRounded }) => {
+                        if s.starts_with('.') {
+                            let nano = try_consume!(scan::nanosecond_rounded(&s[1..]));
+                            parsed.set_nanosecond_with_carry(nano).map_err(|e| (s, e))?;
+                        }
+                    }
+
                     &Internal(InternalFixed { val: InternalInternal::Nanosecond3NoDot }) => {
                         if s.len() < 3 {
                             return Err((s, TOO_SHORT));
@@ -473,20 +830,39 @@ where
                         parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
                     }
 
-                    &TimezoneName => {
-                        try_consume!(scan::timezone_name_skip(s));
-                    }
+                    &TimezoneName => match timezone_names {
+                        None => try_consume!(scan::timezone_name_skip(s)),
+                        Some(lookup) => {
+                            let name = try_consume!(scan::timezone_name(s));
+                            let offset = match lookup(name) {
+                                LocalResult::Single(offset) => Some(offset),
+                                LocalResult::Ambiguous(..) => None,
+                                LocalResult::None => return Err((s, INVALID)),
+                            };
+                            parsed.set_timezone_name(name).map_err(|e| (s, e))?;
+                            if let Some(offset) = offset {
+                                let offset = i64::from(offset.local_minus_utc());
+                                parsed.set_offset(offset).map_err(|e| (s, e))?;
+                            }
+                        }
+                    },
 
-                    &TimezoneOffsetColon
-                    | &TimezoneOffsetDoubleColon
-                    | &TimezoneOffsetTripleColon
-                    | &TimezoneOffset => {
+                    &TimezoneOffsetColon | &TimezoneOffsetDoubleColon | &TimezoneOffset => {
                         s = scan::trim1(s);
                         let offset =
                             try_consume!(scan::timezone_offset(s, scan::consume_colon_maybe));
                         parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
                     }
 
+                    &TimezoneOffsetTripleColon => {
+                        s = scan::trim1(s);
+                        let offset = try_consume!(scan::timezone_offset_minutes_optional(
+                            s,
+                            scan::consume_colon_maybe
+                        ));
+                        parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
+                    }
+
                     &TimezoneOffsetColonZ | &TimezoneOffsetZ => {
                         s = scan::trim1(s);
                         let offset =
@@ -498,15 +874,14 @@ where
                         val: InternalInternal::TimezoneOffsetPermissive,
                     }) => {
                         s = scan::trim1(s);
-                        let offset = try_consume!(scan::timezone_offset_permissive(
-                            s,
-                            scan::consume_colon_maybe
-                        ));
+                        let offset = try_consume!(scan::timezone_offset_flexible(s));
                         parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
                     }
 
                     &RFC2822 => try_consume!(parse_rfc2822(parsed, s)),
-                    &RFC3339 => try_consume!(parse_rfc3339(parsed, s)),
+                    &RFC3339 => {
+                        try_consume!(parse_rfc3339(parsed, s));
+                    }
                 }
             }
 
@@ -556,7 +931,7 @@ impl str::FromStr for DateTime<FixedOffset> {
         ];
 
         let mut parsed = Parsed::new();
-        match parse_internal(&mut parsed, s, DATE_ITEMS.iter()) {
+        match parse_internal(&mut parsed, s, DATE_ITEMS.iter(), None, None) {
             Err((remainder, e)) if e.0 == ParseErrorKind::TooLong => {
                 if remainder.starts_with('T') || remainder.starts_with(' ') {
                     parse(&mut parsed, &remainder[1..], TIME_ITEMS.iter())?;
@@ -571,6 +946,127 @@ impl str::FromStr for DateTime<FixedOffset> {
     }
 }
 
+impl FixedOffset {
+    /// Parses a string holding only a UTC offset into a `FixedOffset`.
+    ///
+    /// Accepts `+HH:MM`, `+HHMM`, `+HH` (and the same with `-`), as well as `Z` or `z` for UTC.
+    /// The whole input must be consumed.
+    ///
+    /// Returns `ParseError` of kind [`OutOfRange`](crate::format::ParseErrorKind::OutOfRange)
+    /// if the offset exceeds ±86,399 seconds (±23:59:59).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// assert_eq!(FixedOffset::from_str_relaxed("-0730")?, FixedOffset::west_opt(27_000).unwrap());
+    /// assert_eq!(FixedOffset::from_str_relaxed("+05:30")?, FixedOffset::east_opt(19_800).unwrap());
+    /// assert_eq!(FixedOffset::from_str_relaxed("+09")?, FixedOffset::east_opt(32_400).unwrap());
+    /// assert_eq!(FixedOffset::from_str_relaxed("Z")?, FixedOffset::east_opt(0).unwrap());
+    /// assert!(FixedOffset::from_str_relaxed("+24:00").is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn from_str_relaxed(s: &str) -> ParseResult<FixedOffset> {
+        let (s, offset) = scan::timezone_offset_permissive(s, scan::consume_colon_maybe)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        FixedOffset::east_opt(offset).ok_or(OUT_OF_RANGE)
+    }
+
+    /// Parses a UTC offset written as a signed number of minutes, such as `+330` for +05:30 or
+    /// `-480` for -08:00.
+    ///
+    /// The sign is optional for positive offsets. This is the inverse of formatting
+    /// [`FixedOffset::total_minutes`] with `{:+}`.
+    ///
+    /// Returns `ParseError` of kind [`OutOfRange`](crate::format::ParseErrorKind::OutOfRange)
+    /// if the offset is not within ±1439 minutes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let offset = FixedOffset::from_str_total_minutes("+330")?;
+    /// assert_eq!(offset, FixedOffset::east_opt(19_800).unwrap());
+    /// assert_eq!(format!("{:+}", offset.total_minutes()), "+330");
+    /// assert_eq!(FixedOffset::from_str_total_minutes("-480")?, FixedOffset::west_opt(28_800).unwrap());
+    /// assert!(FixedOffset::from_str_total_minutes("+1440").is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn from_str_total_minutes(s: &str) -> ParseResult<FixedOffset> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(&b'+') => (false, &s[1..]),
+            Some(&b'-') => (true, &s[1..]),
+            _ => (false, s),
+        };
+        let (s, minutes) = scan::number(s, 1, 4)?;
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        let secs = minutes as i32 * 60;
+        FixedOffset::east_opt(if negative { -secs } else { secs }).ok_or(OUT_OF_RANGE)
+    }
+}
+
+impl TimeDelta {
+    /// Parses a duration written like a clock, as `HH:MM` or `HH:MM:SS`.
+    ///
+    /// The hours can have any number of digits and are not limited to 23. The minutes and
+    /// seconds must be two digits below 60. The seconds can have a fraction of up to nine digits,
+    /// such as `01:02:03.25`; any further digits are ignored. A leading `-` makes the duration
+    /// negative. The whole input must be consumed.
+    ///
+    /// This is the inverse of [`TimeDelta::to_clock_string`].
+    ///
+    /// Returns `ParseError` of kind [`OutOfRange`](crate::format::ParseErrorKind::OutOfRange)
+    /// if the minutes or seconds are 60 or more, or if the duration does not fit in a
+    /// `TimeDelta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::parse_clock("01:30")?, TimeDelta::minutes(90));
+    /// assert_eq!(TimeDelta::parse_clock("100:00:00")?, TimeDelta::hours(100));
+    /// assert_eq!(TimeDelta::parse_clock("-00:00:01.5")?, TimeDelta::milliseconds(-1500));
+    /// assert!(TimeDelta::parse_clock("01:60").is_err());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn parse_clock(s: &str) -> ParseResult<TimeDelta> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let (s, hours) = scan::number(s, 1, usize::MAX)?;
+        let (s, minutes) = scan::number(scan::char(s, b':')?, 2, 2)?;
+        let (s, seconds, nanos) = match s.strip_prefix(':') {
+            Some(s) => {
+                let (s, seconds) = scan::number(s, 2, 2)?;
+                match s.strip_prefix('.') {
+                    Some(s) => {
+                        let (s, nanos) = scan::nanosecond(s)?;
+                        (s, seconds, nanos)
+                    }
+                    None => (s, seconds, 0),
+                }
+            }
+            None => (s, 0, 0),
+        };
+        if !s.is_empty() {
+            return Err(TOO_LONG);
+        }
+        if minutes >= 60 || seconds >= 60 {
+            return Err(OUT_OF_RANGE);
+        }
+        let delta = TimeDelta::from_hms_nano(hours, minutes, seconds, nanos).ok_or(OUT_OF_RANGE)?;
+        Ok(if negative { -delta } else { delta })
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_parse() {
@@ -763,6 +1259,22 @@ fn test_parse() {
     check!("-",           [num!(Year)]; TOO_SHORT);
     check!("+",           [num!(Year)]; TOO_SHORT);
 
+    // space padded numeric
+    check!(" 9",    [nums!(Hour)]; hour_div_12: 0, hour_mod_12: 9);
+    check!("09",    [nums!(Hour)]; hour_div_12: 0, hour_mod_12: 9);
+    check!("9",     [nums!(Hour)]; hour_div_12: 0, hour_mod_12: 9);
+    check!(" 9",    [num!(Hour)]; INVALID);
+    check!(" 9",    [num0!(Hour)]; INVALID);
+    check!("  9",   [nums!(Hour)]; INVALID);
+    check!(" ",     [nums!(Hour)]; TOO_SHORT);
+    check!(" 9",    [nums!(Hour12)]; hour_mod_12: 9);
+    check!(" 9 ",   [nums!(Hour12)]; TOO_LONG);
+    check!("  5",   [nums!(Ordinal)]; ordinal: 5);
+    check!("   5",  [nums!(Ordinal)]; INVALID);
+    check!(" 987",  [nums!(Year)]; year: 987);
+    check!("  -42", [nums!(Year)]; year: -42);
+    check!(" \t9",  [nums!(Hour)]; INVALID);
+
     // unsigned numeric
     check!("345",   [num!(Ordinal)]; ordinal: 345);
     check!("+345",  [num!(Ordinal)]; INVALID);
@@ -799,11 +1311,23 @@ fn test_parse() {
     check!("7 89 01",
            [num!(WeekdayFromMon), sp!(" "), num!(Ordinal), sp!(" "), num!(Hour12)];
            weekday: Weekday::Sun, ordinal: 89, hour_mod_12: 1);
+    check!("Q3",    [lit!("Q"), internal_num!(Quarter)]; quarter: 3);
+    check!("Q34",   [lit!("Q"), internal_num!(Quarter)]; TOO_LONG);
+    check!("Q5",    [lit!("Q"), internal_num!(Quarter)]; OUT_OF_RANGE);
+    check!("Q0",    [lit!("Q"), internal_num!(Quarter)]; OUT_OF_RANGE);
     check!("23 45 6 78901234 567890123",
            [num!(Hour), sp!(" "), num!(Minute), sp!(" "), num!(Second), sp!(" "),
            num!(Nanosecond), sp!(" "), num!(Timestamp)];
            hour_div_12: 1, hour_mod_12: 11, minute: 45, second: 6, nanosecond: 78_901_234,
            timestamp: 567_890_123);
+    check!("1234567",   [internal_num!(TimestampMillis)]; timestamp: 1_234, nanosecond: 567_000_000);
+    check!("-1",        [internal_num!(TimestampMillis)]; timestamp: -1, nanosecond: 999_000_000);
+    check!("-1000",     [internal_num!(TimestampMillis)]; timestamp: -1, nanosecond: 0);
+    check!("+42",       [internal_num!(TimestampMillis)]; timestamp: 0, nanosecond: 42_000_000);
+    check!("1234567 567000000", [internal_num!(TimestampMillis), sp!(" "), num!(Nanosecond)];
+           timestamp: 1_234, nanosecond: 567_000_000);
+    check!("1234567 567000001", [internal_num!(TimestampMillis), sp!(" "), num!(Nanosecond)]; IMPOSSIBLE);
+    check!("-",         [internal_num!(TimestampMillis)]; TOO_SHORT);
 
     // fixed: month and weekday names
     check!("apr",       [fix!(ShortMonthName)]; month: 4);
@@ -830,8 +1354,12 @@ fn test_parse() {
     check!("The",       [fix!(ShortWeekdayName)]; INVALID);
     check!("Nop",       [fix!(ShortWeekdayName)]; INVALID);
     check!("Thu",       [fix!(LongWeekdayName)]; weekday: Weekday::Thu);
-    check!("Thur",      [fix!(LongWeekdayName)]; TOO_LONG); // `Thu` is parsed
-    check!("Thurs",     [fix!(LongWeekdayName)]; TOO_LONG); // ditto
+    check!("Thur",      [fix!(LongWeekdayName)]; weekday: Weekday::Thu);
+    check!("Thurs",     [fix!(LongWeekdayName)]; weekday: Weekday::Thu);
+    check!("Thurz",     [fix!(LongWeekdayName)]; TOO_LONG); // `Thur` is parsed
+    check!("Tues",      [fix!(LongWeekdayName)]; weekday: Weekday::Tue);
+    check!("Tues",      [fix!(ShortWeekdayName)]; TOO_LONG); // `Tue` is parsed
+    check!("Wednesday", [fix!(LongWeekdayName)]; weekday: Weekday::Wed);
     check!("Thursday",  [fix!(LongWeekdayName)]; weekday: Weekday::Thu);
     check!("Thursdays", [fix!(LongWeekdayName)]; TOO_LONG);
     check!("Thursdays", [fix!(LongWeekdayName), lit!("s")]; weekday: Weekday::Thu);
@@ -1110,9 +1638,28 @@ fn test_parse() {
     check!(" Z",           [fix!(TimezoneOffsetColon)]; INVALID);
     check!(" z",           [fix!(TimezoneOffsetColon)]; INVALID);
     // testing `TimezoneOffsetColon` also tests same path as `TimezoneOffsetDoubleColon`
-    // and `TimezoneOffsetTripleColon` for function `parse_internal`.
-    // No need for separate tests for `TimezoneOffsetDoubleColon` and
-    // `TimezoneOffsetTripleColon`.
+    // for function `parse_internal`.
+    // No need for separate tests for `TimezoneOffsetDoubleColon`.
+
+    // TimezoneOffsetTripleColon
+    check!("+12",          [fix!(TimezoneOffsetTripleColon)]; offset: 43_200);
+    check!("-05",          [fix!(TimezoneOffsetTripleColon)]; offset: -18_000);
+    check!("+1234",        [fix!(TimezoneOffsetTripleColon)]; offset: 45_240);
+    check!("+12:34",       [fix!(TimezoneOffsetTripleColon)]; offset: 45_240);
+    check!(" +12",         [fix!(TimezoneOffsetTripleColon)]; offset: 43_200);
+    check!("+12 ",         [fix!(TimezoneOffsetTripleColon), sp!(" ")]; offset: 43_200);
+    check!("+12 2001",     [fix!(TimezoneOffsetTripleColon), sp!(" "), num!(Year)];
+           offset: 43_200, year: 2001);
+    check!("+12x",         [fix!(TimezoneOffsetTripleColon), lit!("x")]; offset: 43_200);
+    check!("+12:",         [fix!(TimezoneOffsetTripleColon)]; TOO_LONG);
+    check!("+123",         [fix!(TimezoneOffsetTripleColon)]; TOO_SHORT);
+    check!("+12:3",        [fix!(TimezoneOffsetTripleColon)]; TOO_SHORT);
+    check!("+12:60",       [fix!(TimezoneOffsetTripleColon)]; OUT_OF_RANGE);
+    check!("+12:34:56",    [fix!(TimezoneOffsetTripleColon)]; TOO_LONG);
+    check!("+1",           [fix!(TimezoneOffsetTripleColon)]; TOO_SHORT);
+    check!("12",           [fix!(TimezoneOffsetTripleColon)]; INVALID);
+    check!("Z",            [fix!(TimezoneOffsetTripleColon)]; INVALID);
+    check!("",             [fix!(TimezoneOffsetTripleColon)]; TOO_SHORT);
 
     // TimezoneOffsetZ
     check!("1",            [fix!(TimezoneOffsetZ)]; INVALID);
@@ -1209,9 +1756,9 @@ fn test_parse() {
     check!("123456",       [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("1234567",      [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("12345678",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+1",           [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!("+1",           [internal_fix!(TimezoneOffsetPermissive)]; offset: 3_600);
     check!("+12",          [internal_fix!(TimezoneOffsetPermissive)]; offset: 43_200);
-    check!("+123",         [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!("+123",         [internal_fix!(TimezoneOffsetPermissive)]; offset: 4_980);
     check!("+1234",        [internal_fix!(TimezoneOffsetPermissive)]; offset: 45_240);
     check!("-1234",        [internal_fix!(TimezoneOffsetPermissive)]; offset: -45_240);
     check!("+12345",       [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
@@ -1225,9 +1772,9 @@ fn test_parse() {
     check!("12:34:",       [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("12:34:5",      [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("12:34:56",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+1:",          [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+1:",          [internal_fix!(TimezoneOffsetPermissive)]; offset: 3_600);
     check!("+12:",         [internal_fix!(TimezoneOffsetPermissive)]; offset: 43_200);
-    check!("+12:3",        [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!("+12:3",        [internal_fix!(TimezoneOffsetPermissive)]; offset: 43_380);
     check!("+12:34",       [internal_fix!(TimezoneOffsetPermissive)]; offset: 45_240);
     check!("-12:34",       [internal_fix!(TimezoneOffsetPermissive)]; offset: -45_240);
     check!("+12:34:",      [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
@@ -1236,19 +1783,19 @@ fn test_parse() {
     check!("+12:34:56:",   [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
     check!("+12:34:56:7",  [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
     check!("+12:34:56:78", [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
-    check!("+12 34",       [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+12  34",      [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+12 :34",      [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+12 34",       [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
+    check!("+12  34",      [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
+    check!("+12 :34",      [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
     check!("+12: 34",      [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+12 : 34",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+12  :34",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+12 : 34",     [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
+    check!("+12  :34",     [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
     check!("+12:  34",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+12  :  34",   [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+12  :  34",   [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
     check!("+12::34",      [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+12 ::34",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+12 ::34",     [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
     check!("+12: :34",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("+12:: 34",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+12  ::34",    [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+12  ::34",    [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
     check!("+12:  :34",    [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("+12::  34",    [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("+12:::34",     [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
@@ -1275,17 +1822,37 @@ fn test_parse() {
     check!("#Z",           [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!(":Z",           [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!(":z",           [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!("+Z",           [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
-    check!("-Z",           [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
-    check!("+A",           [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!("+Z",           [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("-Z",           [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+A",           [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("+PST",         [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("+🙃",          [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("+Z:",          [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!(" :Z",          [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
-    check!(" +Z",          [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
-    check!(" -Z",          [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!(" +Z",          [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!(" -Z",          [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("+:Z",          [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
     check!("Y",            [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+",            [internal_fix!(TimezoneOffsetPermissive)]; TOO_SHORT);
+    check!("+5",           [internal_fix!(TimezoneOffsetPermissive)]; offset: 18_000);
+    check!("-5",           [internal_fix!(TimezoneOffsetPermissive)]; offset: -18_000);
+    check!("+530",         [internal_fix!(TimezoneOffsetPermissive)]; offset: 19_800);
+    check!("+0530",        [internal_fix!(TimezoneOffsetPermissive)]; offset: 19_800);
+    check!("+5:30",        [internal_fix!(TimezoneOffsetPermissive)]; offset: 19_800);
+    check!("+5:3",         [internal_fix!(TimezoneOffsetPermissive)]; offset: 18_180);
+    check!("+05:3",        [internal_fix!(TimezoneOffsetPermissive)]; offset: 18_180);
+    check!("-9:05",        [internal_fix!(TimezoneOffsetPermissive)]; offset: -32_700);
+    check!("+5:",          [internal_fix!(TimezoneOffsetPermissive)]; offset: 18_000);
+    check!("+5:345",       [internal_fix!(TimezoneOffsetPermissive)]; TOO_LONG);
+    check!("+5:30:",       [internal_fix!(TimezoneOffsetPermissive), lit!(":")]; offset: 19_800);
+    check!("+5 ",          [internal_fix!(TimezoneOffsetPermissive), sp!(" ")]; offset: 18_000);
+    check!("+560",         [internal_fix!(TimezoneOffsetPermissive)]; OUT_OF_RANGE);
+    check!("+5:60",        [internal_fix!(TimezoneOffsetPermissive)]; OUT_OF_RANGE);
+    check!("+99:99",       [internal_fix!(TimezoneOffsetPermissive)]; OUT_OF_RANGE);
+    check!("+9999",        [internal_fix!(TimezoneOffsetPermissive)]; OUT_OF_RANGE);
+    check!("+99:59",       [internal_fix!(TimezoneOffsetPermissive)]; offset: 359_940);
+    check!("+5:x",         [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
+    check!("+5::30",       [internal_fix!(TimezoneOffsetPermissive)]; INVALID);
 
     // TimezoneName
     check!("CEST",         [fix!(TimezoneName)]; );
@@ -1464,6 +2031,214 @@ fn test_rfc2822() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_parse_space_padded_hour() {
+    use crate::NaiveTime;
+
+    for (s, fmt, hour) in [
+        (" 9:05", "%k:%M", 9),
+        ("09:05", "%k:%M", 9),
+        ("9:05", "%k:%M", 9),
+        ("21:05", "%k:%M", 21),
+        (" 9:05 AM", "%l:%M %p", 9),
+        ("09:05 PM", "%l:%M %p", 21),
+        ("12:05 AM", "%l:%M %p", 0),
+        ("x 9:05", "x%k:%M", 9),
+    ] {
+        let expected = NaiveTime::from_hms_opt(hour, 5, 0).unwrap();
+        assert_eq!(NaiveTime::parse_from_str(s, fmt), Ok(expected), "{:?} {:?}", s, fmt);
+    }
+    assert!(NaiveTime::parse_from_str("  9:05", "%k:%M").is_err());
+
+    // what is formatted can be parsed back
+    let date = crate::NaiveDate::from_ymd_opt(2023, 3, 7).unwrap();
+    for hour in 0..24 {
+        let dt = date.and_hms_opt(hour, 5, 0).unwrap();
+        for fmt in ["%k:%M %e.%m.%Y", "%l:%M %p %e.%m.%Y", "%_H:%_M %_d.%_m.%Y"] {
+            let s = dt.format(fmt).to_string();
+            assert_eq!(crate::NaiveDateTime::parse_from_str(&s, fmt), Ok(dt), "{:?}", s);
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_with_timezone_names() {
+    use super::IMPOSSIBLE;
+    use crate::TimeZone;
+
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let lookup = |name: &str| match name {
+        "UTC" | "GMT" => LocalResult::Single(utc),
+        "CEST" => LocalResult::Single(FixedOffset::east_opt(2 * 3600).unwrap()),
+        "CST" => LocalResult::Ambiguous(
+            FixedOffset::west_opt(6 * 3600).unwrap(),
+            FixedOffset::east_opt(8 * 3600).unwrap(),
+        ),
+        _ => LocalResult::None,
+    };
+    let parse_tz = |s: &str, fmt: &str| {
+        let mut parsed = Parsed::new();
+        parse_with_timezone_names(&mut parsed, s, StrftimeItems::new(fmt), lookup).map(|_| parsed)
+    };
+
+    let parsed = parse_tz("2023-07-01 12:00 CEST", "%Y-%m-%d %H:%M %Z").unwrap();
+    assert_eq!((parsed.offset, parsed.timezone_name()), (Some(7200), Some("CEST")));
+    assert_eq!(
+        parsed.to_datetime(),
+        Ok(FixedOffset::east_opt(7200).unwrap().with_ymd_and_hms(2023, 7, 1, 12, 0, 0).unwrap())
+    );
+
+    // an ambiguous name only records the name
+    let parsed = parse_tz("2023-07-01 12:00 CST", "%Y-%m-%d %H:%M %Z").unwrap();
+    assert_eq!((parsed.offset, parsed.timezone_name()), (None, Some("CST")));
+    assert!(parsed.to_datetime().is_err());
+    let parsed = parse_tz("2023-07-01 12:00 CST -0600", "%Y-%m-%d %H:%M %Z %z").unwrap();
+    assert_eq!((parsed.offset, parsed.timezone_name()), (Some(-21600), Some("CST")));
+
+    // the offset of the name must agree with the numeric offset
+    let parsed = parse_tz("12:00 GMT +0000", "%H:%M %Z %z").unwrap();
+    assert_eq!((parsed.offset, parsed.timezone_name()), (Some(0), Some("GMT")));
+    assert_eq!(parse_tz("12:00 GMT +0100", "%H:%M %Z %z").unwrap_err(), IMPOSSIBLE);
+    assert_eq!(parse_tz("12:00 UTC GMT", "%H:%M %Z %Z").unwrap_err(), IMPOSSIBLE);
+
+    // unknown, lowercase, missing or too long names
+    assert_eq!(parse_tz("12:00 XYZ", "%H:%M %Z").unwrap_err(), INVALID);
+    assert_eq!(parse_tz("12:00 utc", "%H:%M %Z").unwrap_err(), INVALID);
+    assert_eq!(parse_tz("12:00 ", "%H:%M %Z").unwrap_err(), TOO_SHORT);
+    assert_eq!(parse_tz("12:00", "%H:%M%Z").unwrap_err(), TOO_SHORT);
+    let lookup_any = |_: &str| LocalResult::Single(utc);
+    let mut parsed = Parsed::new();
+    let result = parse_with_timezone_names(
+        &mut parsed,
+        "ABCDEFGHIJKLMNOPQ",
+        StrftimeItems::new("%Z"),
+        lookup_any,
+    );
+    assert_eq!(result, Err(OUT_OF_RANGE));
+
+    // without a lookup `%Z` is still skipped
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, "12:00 XYZ", StrftimeItems::new("%H:%M %Z")).unwrap();
+    assert_eq!((parsed.offset, parsed.timezone_name()), (None, None));
+}
+
+#[cfg(test)]
+#[test]
+fn test_http_date() {
+    use super::*;
+
+    let testdates = [
+        // IMF-fixdate
+        ("Sun, 06 Nov 1994 08:49:37 GMT", Ok("1994-11-06T08:49:37+00:00")),
+        ("sun, 06 NOV 1994 08:49:37 GMT", Ok("1994-11-06T08:49:37+00:00")),
+        ("Mon, 31 Dec 2035 23:59:60 GMT", Ok("2035-12-31T23:59:60+00:00")),
+        ("Sun, 6 Nov 1994 08:49:37 GMT", Err(INVALID)),
+        ("Sun, 06 Nov 94 08:49:37 GMT", Err(INVALID)),
+        ("Sun, 06 Nov 1994 08:49 GMT", Err(INVALID)),
+        ("Sun, 06 Nov 1994 08:49:37 gmt", Err(INVALID)),
+        ("Sun, 06 Nov 1994 08:49:37 +0000", Err(INVALID)),
+        ("Sun, 06 Nov 1994 08:49:37 UT", Err(TOO_SHORT)),
+        ("Sun, 06 Nov 1994 08:49:37", Err(TOO_SHORT)),
+        ("Sun, 06 Nov 1994 08:49:37 GMT ", Err(TOO_LONG)),
+        ("Sun,  06 Nov 1994 08:49:37 GMT", Err(INVALID)),
+        ("Mon, 06 Nov 1994 08:49:37 GMT", Err(IMPOSSIBLE)),
+        ("Sun, 31 Nov 1994 08:49:37 GMT", Err(OUT_OF_RANGE)),
+        // RFC 850
+        ("Sunday, 06-Nov-94 08:49:37 GMT", Ok("1994-11-06T08:49:37+00:00")),
+        ("SUNDAY, 06-nov-94 08:49:37 GMT", Ok("1994-11-06T08:49:37+00:00")),
+        ("Saturday, 06-Nov-49 08:49:37 GMT", Ok("2049-11-06T08:49:37+00:00")),
+        ("Monday, 06-Nov-50 08:49:37 GMT", Ok("1950-11-06T08:49:37+00:00")),
+        ("Sun, 06-Nov-94 08:49:37 GMT", Err(INVALID)),
+        ("Sunday, 06 Nov 1994 08:49:37 GMT", Err(INVALID)),
+        ("Sunday, 06-Nov-1994 08:49:37 GMT", Err(INVALID)),
+        ("Thurs, 06-Nov-49 08:49:37 GMT", Err(INVALID)),
+        ("Sunday, 06-Nov-94 08:49:37", Err(TOO_SHORT)),
+        ("Monday, 06-Nov-94 08:49:37 GMT", Err(IMPOSSIBLE)),
+        // asctime
+        ("Sun Nov  6 08:49:37 1994", Ok("1994-11-06T08:49:37+00:00")),
+        ("Sun Nov 06 08:49:37 1994", Ok("1994-11-06T08:49:37+00:00")),
+        ("Wed Nov 16 08:49:37 1994", Ok("1994-11-16T08:49:37+00:00")),
+        ("Sun Nov 6 08:49:37 1994", Err(INVALID)),
+        ("Wed Nov  16 08:49:37 1994", Err(INVALID)),
+        ("Sun Nov  6 08:49:37 94", Err(TOO_SHORT)),
+        ("Sun Nov  6 08:49:37 1994 GMT", Err(TOO_LONG)),
+        ("Sun Nov  6 1994 08:49:37", Err(INVALID)),
+        // other
+        ("", Err(TOO_SHORT)),
+        ("Sunday", Err(TOO_SHORT)),
+        ("1994-11-06T08:49:37Z", Err(INVALID)),
+        (" Sun, 06 Nov 1994 08:49:37 GMT", Err(INVALID)),
+    ];
+
+    for &(date, checkdate) in testdates.iter() {
+        let dt = DateTime::<FixedOffset>::parse_from_http_date(date).map(|dt| dt.to_rfc3339());
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!(
+                "Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                date, dt, checkdate
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rfc2822_strict() {
+    use super::*;
+
+    let testdates = [
+        ("Tue, 20 Jan 2015 17:35:20 -0800", Ok("Tue, 20 Jan 2015 17:35:20 -0800")), // normal case
+        ("Fri, 2 Jan 2015 17:35:20 +0000", Ok("Fri, 02 Jan 2015 17:35:20 +0000")),  // one-digit day
+        ("Fri, 02 Jan 2015 17:35:20 -0000", Ok("Fri, 02 Jan 2015 17:35:20 +0000")), // leading zero
+        ("20 Jan 2015 17:35:20 +0930", Ok("Tue, 20 Jan 2015 17:35:20 +0930")), // no day of week
+        ("tue, 20 JAN 2015 17:35 -0800", Ok("Tue, 20 Jan 2015 17:35:00 -0800")), // no second
+        ("Tue, 20 Jan 02015 17:35:20 -0800", Ok("Tue, 20 Jan 2015 17:35:20 -0800")), // long year
+        ("Wed, 20 Jan 2015 17:35:20 -0800", Err(IMPOSSIBLE)), // mismatching day of week
+        ("30 Feb 2015 17:35:20 -0800", Err(OUT_OF_RANGE)),    // bad day of month
+        ("Tue, 20 Jan 2015 17:35:20 -0860", Err(OUT_OF_RANGE)), // bad offset minutes
+        ("Tue, 20 Jan 2015", Err(TOO_SHORT)),                 // omitted fields
+        // folding whitespace other than a single space
+        ("Fri,  2 Jan 2015 17:35:20 -0800", Err(INVALID)),
+        ("Tue,20 Jan 2015 17:35:20 -0800", Err(INVALID)),
+        ("Tue, 20  Jan 2015 17:35:20 -0800", Err(INVALID)),
+        ("Tue, 20 Jan 2015\t17:35:20 -0800", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17 : 35 : 20 -0800", Err(INVALID)),
+        (" Tue, 20 Jan 2015 17:35:20 -0800", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17:35:20 -0800 ", Err(TOO_LONG)),
+        // comments
+        ("Tue, 20 Jan 2015 17:35:20 -0800 (UTC)", Err(TOO_LONG)),
+        ("Tue, 20 Jan 2015 (Tuesday) 17:35:20 -0800", Err(INVALID)),
+        // obsolete years
+        ("Tue, 20 Jan 15 17:35:20 -0800", Err(INVALID)),
+        ("Tue, 20 Jan 115 17:35:20 -0800", Err(INVALID)),
+        // obsolete zones
+        ("Tue, 20 Jan 2015 17:35:20 GMT", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17:35:20 UT", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17:35:20 PST", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17:35:20 Z", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17:35:20 a", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17:35:20 +08:00", Err(INVALID)),
+        ("Tue, 20 Jan 2015 17:35:20 +08", Err(TOO_SHORT)),
+    ];
+
+    for &(date, checkdate) in testdates.iter() {
+        let dt = DateTime::<FixedOffset>::parse_from_rfc2822_strict(date)
+            .map(|dt| dt.format_with_items([Item::Fixed(Fixed::RFC2822)].iter()).to_string());
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!(
+                "Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                date, dt, checkdate
+            );
+        }
+        // everything that is accepted by the strict parser is also accepted by the lenient one
+        if let Ok(dt) = DateTime::<FixedOffset>::parse_from_rfc2822_strict(date) {
+            assert_eq!(DateTime::<FixedOffset>::parse_from_rfc2822(date), Ok(dt));
+        }
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn parse_rfc850() {
@@ -1521,26 +2296,36 @@ fn test_rfc3339() {
         ("2015-01-20T17:35:20.000031-08:00", Ok("2015-01-20T17:35:20.000031-08:00")),
         ("2015-01-20T17:35:20.000000004-08:00", Ok("2015-01-20T17:35:20.000000004-08:00")),
         ("2015-01-20T17:35:20.000000000452-08:00", Ok("2015-01-20T17:35:20-08:00")), // too small
+        ("2015-01-20T17:35:20,001-08:00", Ok("2015-01-20T17:35:20.001-08:00")), // comma separator
+        ("2024-01-01T00:00:00,5Z", Ok("2024-01-01T00:00:00.500+00:00")),        // comma separator
+        ("2015-01-20T17:35:20,-08:00", Err(INVALID)), // comma without digits
+        ("2015-01-20T17:35:20,", Err(TOO_SHORT)),     // trailing comma without digits
+        ("2015-01-20T17:35:20.", Err(TOO_SHORT)),     // trailing period without digits
+        ("2015-01-20T17:35:20,,5-08:00", Err(INVALID)), // double comma
+        ("2016-12-31T23:59:60Z", Ok("2016-12-31T23:59:60+00:00")), // leap second
+        ("2016-12-31T23:59:60.5Z", Ok("2016-12-31T23:59:60.500+00:00")), // leap second
+        ("2017-01-01T08:59:60+09:00", Ok("2017-01-01T08:59:60+09:00")), // leap second
+        ("2016-12-31T23:59:61Z", Err(OUT_OF_RANGE)),  // bad second value
         ("2015-01-20 17:35:20.001-08:00", Err(INVALID)), // missing separator 'T'
         ("2015/01/20T17:35:20.001-08:00", Err(INVALID)), // wrong separator char YMD
         ("2015-01-20T17-35-20.001-08:00", Err(INVALID)), // wrong separator char HMS
-        ("99999-01-20T17:35:20-08:00", Err(INVALID)),    // bad year value
-        ("-2000-01-20T17:35:20-08:00", Err(INVALID)),    // bad year value
+        ("99999-01-20T17:35:20-08:00", Err(INVALID)), // bad year value
+        ("-2000-01-20T17:35:20-08:00", Err(INVALID)), // bad year value
         ("2015-02-30T17:35:20-08:00", Err(OUT_OF_RANGE)), // bad day of month value
         ("2015-01-20T25:35:20-08:00", Err(OUT_OF_RANGE)), // bad hour value
         ("2015-01-20T17:65:20-08:00", Err(OUT_OF_RANGE)), // bad minute value
         ("2015-01-20T17:35:90-08:00", Err(OUT_OF_RANGE)), // bad second value
         ("2015-01-20T17:35:20-24:00", Err(OUT_OF_RANGE)), // bad offset value
-        ("15-01-20T17:35:20-08:00", Err(INVALID)),       // bad year format
-        ("15-01-20T17:35:20-08:00:00", Err(INVALID)),    // bad year format, bad offset format
-        ("2015-01-20T17:35:20-0800", Err(INVALID)),      // bad offset format
+        ("15-01-20T17:35:20-08:00", Err(INVALID)),    // bad year format
+        ("15-01-20T17:35:20-08:00:00", Err(INVALID)), // bad year format, bad offset format
+        ("2015-01-20T17:35:20-0800", Err(INVALID)),   // bad offset format
         ("2015-01-20T17:35:20.001-08 : 00", Err(INVALID)), // bad offset format
         ("2015-01-20T17:35:20-08:00:00", Err(TOO_LONG)), // bad offset format
-        ("2015-01-20T17:35:20-08:", Err(TOO_SHORT)),     // bad offset format
-        ("2015-01-20T17:35:20-08", Err(TOO_SHORT)),      // bad offset format
-        ("2015-01-20T", Err(TOO_SHORT)),                 // missing HMS
-        ("2015-01-20T00:00:1", Err(TOO_SHORT)),          // missing complete S
-        ("2015-01-20T00:00:1-08:00", Err(INVALID)),      // missing complete S
+        ("2015-01-20T17:35:20-08:", Err(TOO_SHORT)),  // bad offset format
+        ("2015-01-20T17:35:20-08", Err(TOO_SHORT)),   // bad offset format
+        ("2015-01-20T", Err(TOO_SHORT)),              // missing HMS
+        ("2015-01-20T00:00:1", Err(TOO_SHORT)),       // missing complete S
+        ("2015-01-20T00:00:1-08:00", Err(INVALID)),   // missing complete S
     ];
 
     fn rfc3339_to_datetime(date: &str) -> ParseResult<DateTime<FixedOffset>> {
@@ -1579,3 +2364,190 @@ fn test_issue_1010() {
     "\u{c}\u{c}%A\u{c}\u{b}\0SUN\u{c}\u{c}\u{c}SUNN\u{c}\u{c}\u{c}SUN\u{c}\u{c}!\u{c}\u{b}\u{c}\u{c}\u{c}\u{c}%A\u{c}\u{b}%a");
     assert_eq!(dt, Err(ParseError(ParseErrorKind::Invalid)));
 }
+
+#[cfg(test)]
+#[test]
+fn test_fixed_offset_from_str_relaxed() {
+    let offset = |secs| Ok(FixedOffset::east_opt(secs).unwrap());
+    assert_eq!(FixedOffset::from_str_relaxed("+00:00"), offset(0));
+    assert_eq!(FixedOffset::from_str_relaxed("Z"), offset(0));
+    assert_eq!(FixedOffset::from_str_relaxed("z"), offset(0));
+    assert_eq!(FixedOffset::from_str_relaxed("-0730"), offset(-27_000));
+    assert_eq!(FixedOffset::from_str_relaxed("-07:30"), offset(-27_000));
+    assert_eq!(FixedOffset::from_str_relaxed("+09"), offset(32_400));
+    assert_eq!(FixedOffset::from_str_relaxed("+23:59"), offset(86_340));
+    assert_eq!(FixedOffset::from_str_relaxed("+24:00"), Err(OUT_OF_RANGE));
+    assert_eq!(FixedOffset::from_str_relaxed("-99"), Err(OUT_OF_RANGE));
+    assert_eq!(FixedOffset::from_str_relaxed("+01:60"), Err(OUT_OF_RANGE));
+    assert_eq!(FixedOffset::from_str_relaxed("+09:00 "), Err(TOO_LONG));
+    assert_eq!(FixedOffset::from_str_relaxed("0900"), Err(INVALID));
+    assert_eq!(FixedOffset::from_str_relaxed(""), Err(TOO_SHORT));
+}
+
+#[cfg(test)]
+#[test]
+fn test_fixed_offset_from_str_total_minutes() {
+    let offset = |secs| Ok(FixedOffset::east_opt(secs).unwrap());
+    assert_eq!(FixedOffset::from_str_total_minutes("+330"), offset(19_800));
+    assert_eq!(FixedOffset::from_str_total_minutes("330"), offset(19_800));
+    assert_eq!(FixedOffset::from_str_total_minutes("-480"), offset(-28_800));
+    assert_eq!(FixedOffset::from_str_total_minutes("+0"), offset(0));
+    assert_eq!(FixedOffset::from_str_total_minutes("-0"), offset(0));
+    assert_eq!(FixedOffset::from_str_total_minutes("+1439"), offset(86_340));
+    assert_eq!(FixedOffset::from_str_total_minutes("-1439"), offset(-86_340));
+    assert_eq!(FixedOffset::from_str_total_minutes("+1440"), Err(OUT_OF_RANGE));
+    assert_eq!(FixedOffset::from_str_total_minutes("-9999"), Err(OUT_OF_RANGE));
+    assert_eq!(FixedOffset::from_str_total_minutes("+10000"), Err(TOO_LONG));
+    assert_eq!(FixedOffset::from_str_total_minutes("+330 "), Err(TOO_LONG));
+    assert_eq!(FixedOffset::from_str_total_minutes("+05:30"), Err(TOO_LONG));
+    assert_eq!(FixedOffset::from_str_total_minutes("+"), Err(TOO_SHORT));
+    assert_eq!(FixedOffset::from_str_total_minutes("Z"), Err(INVALID));
+
+    // round-trip through `total_minutes`
+    for minutes in [330, -480, 0, 1439, -1439] {
+        let offset = FixedOffset::east_opt(minutes * 60).unwrap();
+        let formatted = format!("{:+}", offset.total_minutes());
+        assert_eq!(FixedOffset::from_str_total_minutes(&formatted), Ok(offset));
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_with_am_pm() {
+    use super::*;
+
+    let names = AmPmNames::new("vorm.", "nachm.");
+    let parse_hour = |s: &str, fmt: &str| {
+        let mut parsed = Parsed::new();
+        parse_with_am_pm(&mut parsed, s, StrftimeItems::new(fmt), names)
+            .and_then(|_| parsed.to_naive_time())
+            .map(|t| t.format("%H:%M").to_string())
+    };
+    assert_eq!(parse_hour("9:15 vorm.", "%I:%M %p"), Ok("09:15".to_string()));
+    assert_eq!(parse_hour("9:15 nachm.", "%I:%M %p"), Ok("21:15".to_string()));
+    assert_eq!(parse_hour("12:00 Vorm.", "%I:%M %P"), Ok("00:00".to_string()));
+    assert_eq!(parse_hour("12:00 NACHM.", "%I:%M %P"), Ok("12:00".to_string()));
+    assert_eq!(parse_hour("9:15 AM", "%I:%M %p"), Err(INVALID));
+    assert_eq!(parse_hour("9:15 vorm", "%I:%M %p"), Err(INVALID));
+    assert_eq!(parse_hour("9:15 vorm.x", "%I:%M %p"), Err(TOO_LONG));
+    assert_eq!(parse_hour("9:15 ", "%I:%M %p"), Err(TOO_SHORT));
+
+    // the default names with `parse_with_am_pm` match `parse`
+    for s in ["9:15 am", "9:15 PM", "9:15 pM"] {
+        let mut parsed = Parsed::new();
+        let mut expected = Parsed::new();
+        let items = StrftimeItems::new("%I:%M %p");
+        assert_eq!(
+            parse_with_am_pm(&mut parsed, s, items.clone(), AmPmNames::DEFAULT),
+            parse(&mut expected, s, items)
+        );
+        assert_eq!(parsed, expected);
+    }
+
+    // round trip
+    let dt = NaiveDate::from_ymd_opt(2023, 5, 4).unwrap().and_hms_opt(0, 30, 0).unwrap();
+    for fmt in ["%Y-%m-%d %I:%M %p", "%Y-%m-%d %-I:%M%P"] {
+        for hours in 0..24 {
+            let dt = dt + TimeDelta::hours(hours);
+            let s = dt.format(fmt).with_am_pm(names).to_string();
+            let mut parsed = Parsed::new();
+            parse_with_am_pm(&mut parsed, &s, StrftimeItems::new(fmt), names).unwrap();
+            assert_eq!(parsed.to_naive_datetime_with_offset(0), Ok(dt));
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_best_effort() {
+    // everything matches
+    let (parsed, remainder) = parse_best_effort("2023-07-14", "%Y-%m-%d");
+    assert_eq!(parsed.to_naive_date().unwrap().to_string(), "2023-07-14");
+    assert_eq!(remainder, "");
+    let (parsed, remainder) = parse_best_effort("2023-07-14 and more", "%Y-%m-%d");
+    assert_eq!((parsed.year, parsed.month, parsed.day), (Some(2023), Some(7), Some(14)));
+    assert_eq!(remainder, " and more");
+
+    // only the year and month are present
+    let (parsed, remainder) = parse_best_effort("2023-07", "%Y-%m-%d %H:%M");
+    assert_eq!((parsed.year, parsed.month, parsed.day), (Some(2023), Some(7), None));
+    assert_eq!(parsed.hour_div_12, None);
+    assert_eq!(remainder, "");
+    assert_eq!(parsed.to_naive_date(), Err(NOT_ENOUGH));
+    let (parsed, remainder) = parse_best_effort("Jul 2023, somewhere", "%b %Y %d");
+    assert_eq!((parsed.year, parsed.month, parsed.day), (Some(2023), Some(7), None));
+    assert_eq!(remainder, ", somewhere");
+    let (parsed, remainder) = parse_best_effort("2023/07/14", "%Y-%m-%d");
+    assert_eq!((parsed.year, parsed.month), (Some(2023), None));
+    assert_eq!(remainder, "/07/14");
+
+    // a value that conflicts with an earlier field stops the parsing before it
+    let (parsed, remainder) = parse_best_effort("2023-07-14 Aug", "%Y-%m-%d %b");
+    assert_eq!((parsed.year, parsed.month, parsed.day), (Some(2023), Some(7), Some(14)));
+    assert_eq!(remainder, "Aug");
+
+    // nothing matches
+    let (parsed, remainder) = parse_best_effort("no date here", "%Y-%m-%d");
+    assert_eq!(parsed, Parsed::new());
+    assert_eq!(remainder, "no date here");
+
+    // an invalid format string stops at the bad specifier
+    let (parsed, remainder) = parse_best_effort("2023-07", "%Y-%!");
+    assert_eq!(parsed.year, Some(2023));
+    assert_eq!(remainder, "07");
+}
+
+#[cfg(test)]
+#[test]
+fn test_time_delta_parse_clock() {
+    let parse = TimeDelta::parse_clock;
+    let hms = |h, m, s| TimeDelta::from_hms(h, m, s).unwrap();
+
+    assert_eq!(parse("01:30"), Ok(hms(1, 30, 0)));
+    assert_eq!(parse("1:30"), Ok(hms(1, 30, 0)));
+    assert_eq!(parse("00:00"), Ok(TimeDelta::zero()));
+    assert_eq!(parse("01:30:45"), Ok(hms(1, 30, 45)));
+    assert_eq!(parse("23:59:59"), Ok(hms(23, 59, 59)));
+    assert_eq!(parse("01:02:03.25"), Ok(hms(1, 2, 3) + TimeDelta::milliseconds(250)));
+    assert_eq!(parse("00:00:00.123456789"), Ok(TimeDelta::nanoseconds(123_456_789)));
+    assert_eq!(parse("00:00:00.1234567891"), Ok(TimeDelta::nanoseconds(123_456_789)));
+
+    // more than 24 hours
+    assert_eq!(parse("24:00"), Ok(TimeDelta::days(1)));
+    assert_eq!(parse("100:00:00"), Ok(hms(100, 0, 0)));
+    assert_eq!(parse("0100:00:00"), Ok(hms(100, 0, 0)));
+    assert_eq!(
+        parse("2562047788015:12:55"),
+        Ok(TimeDelta::seconds(TimeDelta::max_value().num_seconds()))
+    );
+    assert_eq!(parse("2562047788016:00:00"), Err(OUT_OF_RANGE));
+    assert_eq!(parse("99999999999999999999:00"), Err(OUT_OF_RANGE));
+
+    // negative durations
+    assert_eq!(parse("-01:30"), Ok(-hms(1, 30, 0)));
+    assert_eq!(parse("-100:00:01"), Ok(-hms(100, 0, 1)));
+    assert_eq!(parse("-00:00:00.5"), Ok(TimeDelta::milliseconds(-500)));
+    assert_eq!(parse("-00:00"), Ok(TimeDelta::zero()));
+
+    // round-trip through `to_clock_string`
+    for secs in [0, 59, 3600, 86_399, 360_000, -1, -5445, -360_001] {
+        let delta = TimeDelta::seconds(secs);
+        assert_eq!(parse(&delta.to_clock_string()), Ok(delta));
+    }
+
+    assert_eq!(parse("01:60"), Err(OUT_OF_RANGE));
+    assert_eq!(parse("01:00:60"), Err(OUT_OF_RANGE));
+    assert_eq!(parse("01:5"), Err(TOO_SHORT));
+    assert_eq!(parse("01:5x"), Err(INVALID));
+    assert_eq!(parse("01:300"), Err(TOO_LONG));
+    assert_eq!(parse("01:30:"), Err(TOO_SHORT));
+    assert_eq!(parse("01:30.5"), Err(TOO_LONG));
+    assert_eq!(parse("01:30:00."), Err(TOO_SHORT));
+    assert_eq!(parse("01:30 "), Err(TOO_LONG));
+    assert_eq!(parse(" 01:30"), Err(INVALID));
+    assert_eq!(parse("+01:30"), Err(INVALID));
+    assert_eq!(parse("--01:30"), Err(INVALID));
+    assert_eq!(parse("01"), Err(TOO_SHORT));
+    assert_eq!(parse("-"), Err(TOO_SHORT));
+    assert_eq!(parse(""), Err(TOO_SHORT));
+}
@@ -4,6 +4,8 @@
 //! A collection of parsed date and time items.
 //! They can be constructed incrementally while being checked for consistency.
 
+use core::fmt;
+
 use super::{ParseResult, IMPOSSIBLE, NOT_ENOUGH, OUT_OF_RANGE};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 use crate::offset::{FixedOffset, LocalResult, Offset, TimeZone};
@@ -30,6 +32,7 @@ pub struct Parsed {
     /// Due to the common usage, if this field is missing but
     /// [`year_mod_100`](#structfield.year_mod_100) is present,
     /// it is inferred to 19 when `year_mod_100 >= 70` and 20 otherwise.
+    /// Use [`Parsed::set_year_from_two_digit`] to choose a different 100-year window.
     pub year_div_100: Option<i32>,
 
     /// Year modulo 100. Implies that the year is >= 1 BCE when set.
@@ -56,6 +59,11 @@ pub struct Parsed {
     /// Month (1--12).
     pub month: Option<u32>,
 
+    /// Quarter of the year (1--4).
+    ///
+    /// This is never used to construct a date, only checked against the resulting month.
+    pub quarter: Option<u32>,
+
     /// Week number, where the week 1 starts at the first Sunday of January
     /// (0--53, 1--53 or 1--52 depending on the year).
     pub week_from_sun: Option<u32>,
@@ -99,6 +107,56 @@ pub struct Parsed {
 
     /// Offset from the local time to UTC, in seconds.
     pub offset: Option<i32>,
+
+    /// Whether the rounded fractional second has carried into the next whole second.
+    ///
+    /// This is only set when parsing the `%#.f` specifier, which rounds instead of truncating,
+    /// in which case the [`nanosecond`](#structfield.nanosecond) field is zero.
+    pub(super) nanosecond_carry: bool,
+
+    /// The time zone name, as set by [`Parsed::set_timezone_name`].
+    pub(super) timezone_name: TimezoneName,
+}
+
+/// The maximum length in bytes of a time zone name stored in [`Parsed`].
+const TIMEZONE_NAME_MAX: usize = 16;
+
+/// A time zone name stored inline, so that `Parsed` does not borrow the input.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub(super) struct TimezoneName {
+    len: u8,
+    bytes: [u8; TIMEZONE_NAME_MAX],
+}
+
+impl TimezoneName {
+    fn as_str(&self) -> Option<&str> {
+        match self.len {
+            0 => None,
+            len => core::str::from_utf8(&self.bytes[..usize::from(len)]).ok(),
+        }
+    }
+}
+
+impl fmt::Debug for TimezoneName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The first year of the 100-year window a lone two-digit year is interpreted in by default.
+const DEFAULT_TWO_DIGIT_YEAR_PIVOT: i32 = 1970;
+
+/// Returns the year divided by 100 for the two-digit year `value` (0--99), when the full year is
+/// in the 100-year window starting at `pivot`.
+fn two_digit_year_div_100(value: i64, pivot: i32) -> ParseResult<i64> {
+    if !(0..=99).contains(&value) || pivot < 0 {
+        return Err(OUT_OF_RANGE);
+    }
+    let pivot = i64::from(pivot);
+    match value < pivot % 100 {
+        true => Ok(pivot / 100 + 1),
+        false => Ok(pivot / 100),
+    }
 }
 
 /// Checks if `old` is either empty or has the same value as `new` (i.e. "consistent"),
@@ -148,6 +206,48 @@ impl Parsed {
         set_if_consistent(&mut self.year_mod_100, i32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
     }
 
+    /// Tries to set the [`year_div_100`](#structfield.year_div_100) and
+    /// [`year_mod_100`](#structfield.year_mod_100) fields from a two-digit year.
+    ///
+    /// The two-digit year `value` (0--99) is interpreted as the year within the 100 years starting
+    /// at `pivot`. A missing `year_div_100` is normally inferred as if `pivot` is 1970, so that
+    /// two-digit years map to 1970--2069.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OUT_OF_RANGE` if `value` is not in the range 0--99 or `pivot` is negative, and
+    /// `IMPOSSIBLE` if the year is inconsistent with the fields already set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::format::Parsed;
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_year_from_two_digit(70, 2000).unwrap();
+    /// assert_eq!((parsed.year_div_100, parsed.year_mod_100), (Some(20), Some(70)));
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_year_from_two_digit(70, 1950).unwrap();
+    /// assert_eq!((parsed.year_div_100, parsed.year_mod_100), (Some(19), Some(70)));
+    /// ```
+    pub fn set_year_from_two_digit(&mut self, value: i64, pivot: i32) -> ParseResult<()> {
+        self.set_year_div_100(two_digit_year_div_100(value, pivot)?)?;
+        self.set_year_mod_100(value)
+    }
+
+    /// Infers the missing [`year_div_100`](#structfield.year_div_100) and
+    /// [`isoyear_div_100`](#structfield.isoyear_div_100) fields of a lone two-digit year from
+    /// the 100-year window starting at `pivot`.
+    pub(crate) fn set_two_digit_year_pivot(&mut self, pivot: i32) -> ParseResult<()> {
+        if let (None, None, Some(r)) = (self.year, self.year_div_100, self.year_mod_100) {
+            self.year_div_100 = Some(two_digit_year_div_100(i64::from(r), pivot)? as i32);
+        }
+        if let (None, None, Some(r)) = (self.isoyear, self.isoyear_div_100, self.isoyear_mod_100) {
+            self.isoyear_div_100 = Some(two_digit_year_div_100(i64::from(r), pivot)? as i32);
+        }
+        Ok(())
+    }
+
     /// Tries to set the [`isoyear`](#structfield.isoyear) field from given value.
     #[inline]
     pub fn set_isoyear(&mut self, value: i64) -> ParseResult<()> {
@@ -184,6 +284,15 @@ impl Parsed {
         set_if_consistent(&mut self.month, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
     }
 
+    /// Tries to set the [`quarter`](#structfield.quarter) field from given value.
+    #[inline]
+    pub fn set_quarter(&mut self, value: i64) -> ParseResult<()> {
+        if !(1..=4).contains(&value) {
+            return Err(OUT_OF_RANGE);
+        }
+        set_if_consistent(&mut self.quarter, value as u32)
+    }
+
     /// Tries to set the [`week_from_sun`](#structfield.week_from_sun) field from given value.
     #[inline]
     pub fn set_week_from_sun(&mut self, value: i64) -> ParseResult<()> {
@@ -265,18 +374,75 @@ impl Parsed {
         set_if_consistent(&mut self.nanosecond, u32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
     }
 
+    /// Same as `set_nanosecond`, but a value of exactly 1,000,000,000 (from rounding)
+    /// is recorded as a carry into the next whole second.
+    #[inline]
+    pub(super) fn set_nanosecond_with_carry(&mut self, value: i64) -> ParseResult<()> {
+        if value == 1_000_000_000 {
+            self.nanosecond_carry = true;
+            self.set_nanosecond(0)
+        } else {
+            self.set_nanosecond(value)
+        }
+    }
+
     /// Tries to set the [`timestamp`](#structfield.timestamp) field from given value.
     #[inline]
     pub fn set_timestamp(&mut self, value: i64) -> ParseResult<()> {
         set_if_consistent(&mut self.timestamp, value)
     }
 
+    /// Tries to set the [`timestamp`](#structfield.timestamp) and
+    /// [`nanosecond`](#structfield.nanosecond) fields from given number of milliseconds.
+    ///
+    /// A negative value is split with floor division, so `-1` is one millisecond before
+    /// the epoch: a timestamp of `-1` with a nanosecond of 999,000,000.
+    #[inline]
+    pub fn set_timestamp_millis(&mut self, value: i64) -> ParseResult<()> {
+        self.set_timestamp(value.div_euclid(1000))?;
+        self.set_nanosecond(value.rem_euclid(1000) * 1_000_000)
+    }
+
     /// Tries to set the [`offset`](#structfield.offset) field from given value.
     #[inline]
     pub fn set_offset(&mut self, value: i64) -> ParseResult<()> {
         set_if_consistent(&mut self.offset, i32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
     }
 
+    /// Tries to set the time zone name, as returned by [`timezone_name`](#method.timezone_name).
+    ///
+    /// The name is only recorded for the caller; it does not set the
+    /// [`offset`](#structfield.offset) field or take part in any `to_*` method.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OUT_OF_RANGE` if the name is empty or longer than 16 bytes, and `IMPOSSIBLE` if a
+    /// different name is already set.
+    pub fn set_timezone_name(&mut self, name: &str) -> ParseResult<()> {
+        if name.is_empty() || name.len() > TIMEZONE_NAME_MAX {
+            return Err(OUT_OF_RANGE);
+        }
+        match self.timezone_name.as_str() {
+            Some(old) if old == name => Ok(()),
+            Some(_) => Err(IMPOSSIBLE),
+            None => {
+                self.timezone_name.len = name.len() as u8;
+                self.timezone_name.bytes[..name.len()].copy_from_slice(name.as_bytes());
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the time zone name, if it has been set with
+    /// [`set_timezone_name`](#method.set_timezone_name).
+    ///
+    /// When parsing with [`parse_with_timezone_names`](super::parse_with_timezone_names) this is
+    /// the name matched by `%Z`.
+    #[must_use]
+    pub fn timezone_name(&self) -> Option<&str> {
+        self.timezone_name.as_str()
+    }
+
     /// Returns a parsed naive date out of given fields.
     ///
     /// This method is able to determine the date from given subset of fields:
@@ -328,7 +494,10 @@ impl Parsed {
 
                 // we only have modulo. try to interpret a modulo as a conventional two-digit year.
                 // note: we are affected by Rust issue #18060. avoid multiple range patterns.
-                (None, None, Some(r @ 0..=99)) => Ok(Some(r + if r < 70 { 2000 } else { 1900 })),
+                (None, None, Some(r @ 0..=99)) => {
+                    let q = two_digit_year_div_100(i64::from(r), DEFAULT_TWO_DIGIT_YEAR_PIVOT)?;
+                    Ok(Some(q as i32 * 100 + r))
+                }
 
                 // otherwise it is an out-of-bound or insufficient condition.
                 (None, Some(_), None) => Err(NOT_ENOUGH),
@@ -478,6 +647,10 @@ impl Parsed {
             (_, _, _) => return Err(NOT_ENOUGH),
         };
 
+        // verify the quarter, which is never used to determine the date itself.
+        let quarter = parsed_date.month0() / 3 + 1;
+        let verified = verified && self.quarter.unwrap_or(quarter) == quarter;
+
         if verified {
             Ok(parsed_date)
         } else {
@@ -495,6 +668,16 @@ impl Parsed {
     ///
     /// It is able to handle leap seconds when given second is 60.
     pub fn to_naive_time(&self) -> ParseResult<NaiveTime> {
+        let time = self.to_naive_time_without_carry()?;
+        if self.nanosecond_carry {
+            // there is no date to carry into, so this wraps around at midnight.
+            Ok(time.overflowing_add_signed(TimeDelta::seconds(1)).0)
+        } else {
+            Ok(time)
+        }
+    }
+
+    fn to_naive_time_without_carry(&self) -> ParseResult<NaiveTime> {
         let hour_div_12 = match self.hour_div_12 {
             Some(v @ 0..=1) => v,
             Some(_) => return Err(OUT_OF_RANGE),
@@ -538,7 +721,7 @@ impl Parsed {
     /// Either way those fields have to be consistent to each other.
     pub fn to_naive_datetime_with_offset(&self, offset: i32) -> ParseResult<NaiveDateTime> {
         let date = self.to_naive_date();
-        let time = self.to_naive_time();
+        let time = self.to_naive_time_without_carry();
         if let (Ok(date), Ok(time)) = (date, time) {
             let datetime = date.and_time(time);
 
@@ -554,7 +737,7 @@ impl Parsed {
                 }
             }
 
-            Ok(datetime)
+            self.carry_into(datetime)
         } else if let Some(timestamp) = self.timestamp {
             use super::ParseError as PE;
             use super::ParseErrorKind::{Impossible, OutOfRange};
@@ -598,8 +781,8 @@ impl Parsed {
 
             // validate other fields (e.g. week) and return
             let date = parsed.to_naive_date()?;
-            let time = parsed.to_naive_time()?;
-            Ok(date.and_time(time))
+            let time = parsed.to_naive_time_without_carry()?;
+            self.carry_into(date.and_time(time))
         } else {
             // reproduce the previous error(s)
             date?;
@@ -608,6 +791,15 @@ impl Parsed {
         }
     }
 
+    /// Applies the carry from a rounded fractional second, if any.
+    fn carry_into(&self, datetime: NaiveDateTime) -> ParseResult<NaiveDateTime> {
+        if self.nanosecond_carry {
+            datetime.checked_add_signed(TimeDelta::seconds(1)).ok_or(OUT_OF_RANGE)
+        } else {
+            Ok(datetime)
+        }
+    }
+
     /// Returns a parsed fixed time zone offset out of given fields.
     pub fn to_fixed_offset(&self) -> ParseResult<FixedOffset> {
         self.offset.and_then(FixedOffset::east_opt).ok_or(OUT_OF_RANGE)
@@ -705,6 +897,29 @@ mod tests {
     use crate::Datelike;
     use crate::Weekday::*;
 
+    #[test]
+    fn test_parsed_set_timezone_name() {
+        let mut p = Parsed::new();
+        assert_eq!(p.timezone_name(), None);
+        assert_eq!(p.set_timezone_name(""), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_timezone_name("ABCDEFGHIJKLMNOPQ"), Err(OUT_OF_RANGE));
+        assert_eq!(p.timezone_name(), None);
+        assert_eq!(p.set_timezone_name("CEST"), Ok(()));
+        assert_eq!(p.set_timezone_name("CEST"), Ok(()));
+        assert_eq!(p.set_timezone_name("CET"), Err(IMPOSSIBLE));
+        assert_eq!(p.timezone_name(), Some("CEST"));
+        assert_eq!(p.offset, None);
+        assert_eq!(format!("{:?}", p).matches("timezone_name: Some(\"CEST\")").count(), 1);
+
+        let mut p = Parsed::new();
+        assert_eq!(p.set_timezone_name("ABCDEFGHIJKLMNOP"), Ok(()));
+        assert_eq!(p.timezone_name(), Some("ABCDEFGHIJKLMNOP"));
+        let mut p = Parsed::new();
+        assert_eq!(p.set_timezone_name("東京"), Ok(()));
+        assert_eq!(p.timezone_name(), Some("東京"));
+        assert_ne!(p, Parsed::new());
+    }
+
     #[test]
     fn test_parsed_set_fields() {
         // year*, isoyear*
@@ -749,6 +964,14 @@ mod tests {
         assert_eq!(p.set_month(8), Ok(()));
         assert_eq!(p.set_month(0x1_0000_0008), Err(OUT_OF_RANGE));
 
+        // quarter
+        let mut p = Parsed::new();
+        assert_eq!(p.set_quarter(0), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_quarter(5), Err(OUT_OF_RANGE));
+        assert_eq!(p.set_quarter(3), Ok(()));
+        assert_eq!(p.set_quarter(3), Ok(()));
+        assert_eq!(p.set_quarter(2), Err(IMPOSSIBLE));
+
         // hour
         let mut p = Parsed::new();
         assert_eq!(p.set_hour(12), Ok(()));
@@ -776,6 +999,67 @@ mod tests {
         assert_eq!(p.set_timestamp(1_234_567_891), Err(IMPOSSIBLE));
     }
 
+    #[test]
+    fn test_parsed_set_year_from_two_digit() {
+        let year_from = |value, pivot| {
+            let mut p = Parsed::new();
+            p.set_year_from_two_digit(value, pivot)?;
+            p.set_ordinal(1)?;
+            p.to_naive_date().map(|d| d.year())
+        };
+
+        // the default window
+        for pivot in [1970, 1970 - 100, 1970 + 100] {
+            let offset = pivot - 1970;
+            assert_eq!(year_from(70, pivot), Ok(1970 + offset));
+            assert_eq!(year_from(99, pivot), Ok(1999 + offset));
+            assert_eq!(year_from(0, pivot), Ok(2000 + offset));
+            assert_eq!(year_from(69, pivot), Ok(2069 + offset));
+        }
+
+        // a window aligned with a century
+        assert_eq!(year_from(0, 2000), Ok(2000));
+        assert_eq!(year_from(70, 2000), Ok(2070));
+        assert_eq!(year_from(99, 2000), Ok(2099));
+
+        assert_eq!(year_from(49, 1950), Ok(2049));
+        assert_eq!(year_from(50, 1950), Ok(1950));
+        assert_eq!(year_from(5, 0), Ok(5));
+
+        assert_eq!(year_from(100, 1970), Err(OUT_OF_RANGE));
+        assert_eq!(year_from(-1, 1970), Err(OUT_OF_RANGE));
+        assert_eq!(year_from(10, -1), Err(OUT_OF_RANGE));
+
+        // consistency with already set fields
+        let mut p = Parsed::new();
+        assert_eq!(p.set_year_div_100(19), Ok(()));
+        assert_eq!(p.set_year_from_two_digit(70, 2000), Err(IMPOSSIBLE));
+        assert_eq!(p.set_year_from_two_digit(70, 1950), Ok(()));
+        assert_eq!(p.set_year_from_two_digit(71, 1950), Err(IMPOSSIBLE));
+    }
+
+    #[test]
+    fn test_parsed_set_two_digit_year_pivot() {
+        let mut p = Parsed::new();
+        p.year_mod_100 = Some(70);
+        p.isoyear_mod_100 = Some(69);
+        assert_eq!(p.set_two_digit_year_pivot(2000), Ok(()));
+        assert_eq!((p.year_div_100, p.isoyear_div_100), (Some(20), Some(20)));
+
+        // only applies to a lone two-digit year
+        let mut p = Parsed::new();
+        p.year_div_100 = Some(19);
+        p.year_mod_100 = Some(70);
+        p.isoyear = Some(1970);
+        p.isoyear_mod_100 = Some(70);
+        assert_eq!(p.set_two_digit_year_pivot(2000), Ok(()));
+        assert_eq!((p.year_div_100, p.isoyear_div_100), (Some(19), None));
+
+        let mut p = Parsed::new();
+        assert_eq!(p.set_two_digit_year_pivot(2000), Ok(()));
+        assert_eq!(p, Parsed::new());
+    }
+
     #[test]
     fn test_parsed_to_naive_date() {
         macro_rules! parse {
@@ -842,6 +1126,15 @@ mod tests {
             Err(OUT_OF_RANGE)
         );
 
+        // ymd: quarter
+        assert_eq!(parse!(year: 1984, quarter: 1, month: 3, day: 31), ymd(1984, 3, 31));
+        assert_eq!(parse!(year: 1984, quarter: 2, month: 4, day: 1), ymd(1984, 4, 1));
+        assert_eq!(parse!(year: 1984, quarter: 4, month: 12, day: 31), ymd(1984, 12, 31));
+        assert_eq!(parse!(year: 1984, quarter: 1, month: 4, day: 1), Err(IMPOSSIBLE));
+        assert_eq!(parse!(year: 1984, quarter: 3, ordinal: 183), ymd(1984, 7, 1));
+        assert_eq!(parse!(year: 1984, quarter: 2, ordinal: 183), Err(IMPOSSIBLE));
+        assert_eq!(parse!(year: 1984, quarter: 1), Err(NOT_ENOUGH));
+
         // ymd: conflicting inputs
         assert_eq!(parse!(year: 1984, year_div_100: 19, month: 1, day: 1), ymd(1984, 1, 1));
         assert_eq!(parse!(year: 1984, year_div_100: 20, month: 1, day: 1), Err(IMPOSSIBLE));
@@ -920,6 +1213,16 @@ mod tests {
         assert_eq!(parse!(isoyear: 2005, isoweek: 0, weekday: Thu), Err(OUT_OF_RANGE));
         assert_eq!(parse!(isoyear: 2005, isoweek: 5, weekday: Thu), ymd(2005, 2, 3));
         assert_eq!(parse!(isoyear: 2005, weekday: Thu), Err(NOT_ENOUGH));
+        assert_eq!(parse!(year: 2019, isoyear: 2020, isoweek: 1, weekday: Mon), ymd(2019, 12, 30));
+        assert_eq!(parse!(year: 2020, isoyear: 2020, isoweek: 1, weekday: Mon), Err(IMPOSSIBLE));
+        assert_eq!(
+            parse!(year: 2021, month: 1, isoyear: 2020, isoweek: 53, weekday: Fri),
+            ymd(2021, 1, 1)
+        );
+        assert_eq!(
+            parse!(year: 2020, month: 12, isoyear: 2020, isoweek: 53, weekday: Fri),
+            Err(IMPOSSIBLE)
+        );
 
         // year and ordinal
         assert_eq!(parse!(ordinal: 123), Err(NOT_ENOUGH));
@@ -68,6 +68,12 @@ pub(super) fn number(s: &str, min: usize, max: usize) -> ParseResult<(&str, i64)
 /// Tries to consume at least one digits as a fractional second.
 /// Returns the number of whole nanoseconds (0--999,999,999).
 pub(super) fn nanosecond(s: &str) -> ParseResult<(&str, i64)> {
+    let (s, (v, _)) = nanosecond_with_digits(s)?;
+    Ok((s, v))
+}
+
+/// Same as `nanosecond` but also returns the number of digits in the input, capped at 9.
+pub(super) fn nanosecond_with_digits(s: &str) -> ParseResult<(&str, (i64, u8))> {
     // record the number of digits consumed for later scaling.
     let origlen = s.len();
     let (s, v) = number(s, 1, 9)?;
@@ -81,7 +87,18 @@ pub(super) fn nanosecond(s: &str) -> ParseResult<(&str, i64)> {
     // if there are more than 9 digits, skip next digits.
     let s = s.trim_left_matches(|c: char| c.is_ascii_digit());
 
-    Ok((s, v))
+    Ok((s, (v, consumed as u8)))
+}
+
+/// Same as `nanosecond` but rounds half up to the nearest nanosecond using the tenth digit.
+/// Returns the number of nanoseconds (0--1,000,000,000),
+/// where 1,000,000,000 means that the fraction carries into the next whole second.
+pub(super) fn nanosecond_rounded(s: &str) -> ParseResult<(&str, i64)> {
+    let (rest, v) = nanosecond(s)?;
+
+    // `nanosecond` has skipped any digits past the ninth, so look back for the tenth one.
+    let round_up = s.len() - rest.len() > 9 && s.as_bytes()[9] >= b'5';
+    Ok((rest, v + i64::from(round_up)))
 }
 
 /// Tries to consume a fixed number of digits as a fractional second.
@@ -141,6 +158,24 @@ pub(super) fn short_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
     Ok((&s[3..], weekday))
 }
 
+/// Tries to parse a meridiem with the given strings, case-insensitively for ASCII letters.
+/// Returns `true` for `pm`. If both strings match the longest one is used.
+pub(super) fn am_pm<'a>(s: &'a str, am: &str, pm: &str) -> ParseResult<(&'a str, bool)> {
+    let matches = |name: &str| {
+        !name.is_empty()
+            && s.len() >= name.len()
+            && s.as_bytes()[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+    };
+    let ampm = match (matches(am), matches(pm)) {
+        (true, true) => pm.len() > am.len(),
+        (true, false) => false,
+        (false, true) => true,
+        (false, false) => return Err(if s.is_empty() { TOO_SHORT } else { INVALID }),
+    };
+    let len = if ampm { pm.len() } else { am.len() };
+    Ok((&s[len..], ampm))
+}
+
 /// Tries to parse the month index (0 through 11) with short or long month names.
 /// It prefers long month names to short month names when both are possible.
 pub(super) fn short_or_long_month0(s: &str) -> ParseResult<(&str, u8)> {
@@ -161,17 +196,27 @@ pub(super) fn short_or_long_month0(s: &str) -> ParseResult<(&str, u8)> {
 
 /// Tries to parse the weekday with short or long weekday names.
 /// It prefers long weekday names to short weekday names when both are possible.
+///
+/// The common English four- and five-letter abbreviations `Tues`, `Thur` and `Thurs` are also
+/// accepted.
 pub(super) fn short_or_long_weekday(s: &str) -> ParseResult<(&str, Weekday)> {
     // lowercased weekday names, minus first three chars
     static LONG_WEEKDAY_SUFFIXES: [&str; 7] =
         ["day", "sday", "nesday", "rsday", "day", "urday", "day"];
+    // lowercased alternative abbreviations, minus first three chars, longest first
+    static ABBR_WEEKDAY_SUFFIXES: [&[&str]; 7] = [&[], &["s"], &[], &["rs", "r"], &[], &[], &[]];
 
     let (mut s, weekday) = short_weekday(s)?;
 
     // tries to consume the suffix if possible
-    let suffix = LONG_WEEKDAY_SUFFIXES[weekday.num_days_from_monday() as usize];
-    if s.len() >= suffix.len() && equals(&s.as_bytes()[..suffix.len()], suffix) {
-        s = &s[suffix.len()..];
+    let index = weekday.num_days_from_monday() as usize;
+    let suffixes = core::iter::once(LONG_WEEKDAY_SUFFIXES[index])
+        .chain(ABBR_WEEKDAY_SUFFIXES[index].iter().copied());
+    for suffix in suffixes {
+        if s.len() >= suffix.len() && equals(&s.as_bytes()[..suffix.len()], suffix) {
+            s = &s[suffix.len()..];
+            break;
+        }
     }
 
     Ok((s, weekday))
@@ -326,6 +371,25 @@ where
     }
 }
 
+/// Same as `timezone_offset` but allows the minutes to be missing, in which case the input may
+/// continue with anything but a digit.
+pub(super) fn timezone_offset_minutes_optional<F>(
+    s: &str,
+    consume_colon: F,
+) -> ParseResult<(&str, i32)>
+where
+    F: FnMut(&str) -> ParseResult<&str>,
+{
+    // look past the sign, the hours and an optional colon for the start of the minutes
+    let after_hours = s.get(3..).unwrap_or("");
+    let minutes = after_hours.strip_prefix(':').unwrap_or(after_hours);
+    if minutes.starts_with(|c: char| c.is_ascii_digit()) {
+        return timezone_offset(s, consume_colon);
+    }
+    let (_, offset) = timezone_offset_internal(s.get(..3).unwrap_or(s), consume_colon, true)?;
+    Ok((after_hours, offset))
+}
+
 /// Same as `timezone_offset` but also allows for `z`/`Z` which is the same as
 /// `+00:00`, and allows missing minutes entirely.
 pub(super) fn timezone_offset_permissive<F>(s: &str, colon: F) -> ParseResult<(&str, i32)>
@@ -338,6 +402,48 @@ where
     }
 }
 
+/// Same as `timezone_offset_permissive` but allows one or two digits for the hours, and one or
+/// two digits for the minutes after a colon.
+///
+/// Without a colon the number of digits decides how they are split: `H`, `HH`, `HMM` or `HHMM`.
+/// A colon must be followed by the minutes, unless it is at the end of `s`.
+pub(super) fn timezone_offset_flexible(s: &str) -> ParseResult<(&str, i32)> {
+    let negative = match s.as_bytes().first() {
+        Some(&b'z') | Some(&b'Z') => return Ok((&s[1..], 0)),
+        Some(&b'+') => false,
+        Some(&b'-') => true,
+        Some(_) => return Err(INVALID),
+        None => return Err(TOO_SHORT),
+    };
+    let s = &s[1..];
+
+    let digits = |s: &str, max| s.bytes().take(max).take_while(u8::is_ascii_digit).count();
+    let value = |s: &str| s.bytes().fold(0, |v, c| v * 10 + i32::from(c - b'0'));
+    let (hours, minutes, s) = match digits(s, 4) {
+        0 if s.is_empty() => return Err(TOO_SHORT),
+        0 => return Err(INVALID),
+        3 => (value(&s[..1]), value(&s[1..3]), &s[3..]),
+        4 => (value(&s[..2]), value(&s[2..4]), &s[4..]),
+        n => {
+            let (hours, s) = (value(&s[..n]), &s[n..]);
+            match s.strip_prefix(':') {
+                Some("") => (hours, 0, ""),
+                Some(s) => match digits(s, 2) {
+                    0 => return Err(INVALID),
+                    m => (hours, value(&s[..m]), &s[m..]),
+                },
+                None => (hours, 0, s),
+            }
+        }
+    };
+    if minutes >= 60 {
+        return Err(OUT_OF_RANGE);
+    }
+
+    let seconds = hours * 3600 + minutes * 60;
+    Ok((s, if negative { -seconds } else { seconds }))
+}
+
 /// Same as `timezone_offset` but also allows for RFC 2822 legacy timezones.
 /// May return `None` which indicates an insufficient offset data (i.e. `-0000`).
 /// See [RFC 2822 Section 4.3].
@@ -383,6 +489,16 @@ pub(super) fn timezone_name_skip(s: &str) -> ParseResult<(&str, ())> {
     Ok((s.trim_left_matches(|c: char| !c.is_whitespace()), ()))
 }
 
+/// Consumes a time zone name, any non-empty run of non-whitespace characters, and returns it.
+pub(super) fn timezone_name(s: &str) -> ParseResult<(&str, &str)> {
+    let rest = s.trim_left_matches(|c: char| !c.is_whitespace());
+    match s.len() - rest.len() {
+        0 if s.is_empty() => Err(TOO_SHORT),
+        0 => Err(INVALID),
+        len => Ok((rest, &s[..len])),
+    }
+}
+
 /// Tries to consume an RFC2822 comment including preceding ` `.
 ///
 /// Returns the remaining string after the closing parenthesis.
@@ -416,9 +532,9 @@ enum CommentState {
 #[cfg(test)]
 mod tests {
     use super::{
-        comment_2822, consume_colon_maybe, equals, nanosecond, nanosecond_fixed, s_next,
-        short_or_long_month0, short_or_long_weekday, space, timezone_name_skip,
-        timezone_offset_2822, trim1,
+        am_pm, comment_2822, consume_colon_maybe, equals, nanosecond, nanosecond_fixed,
+        nanosecond_rounded, s_next, short_or_long_month0, short_or_long_weekday, space,
+        timezone_name_skip, timezone_offset_2822, trim1,
     };
     use crate::format::{INVALID, TOO_SHORT};
     use crate::Weekday;
@@ -497,6 +613,16 @@ mod tests {
     fn test_short_or_long_weekday() {
         assert_eq!(short_or_long_weekday("sAtu").unwrap(), ("u", Weekday::Sat));
         assert_eq!(short_or_long_weekday("thu").unwrap(), ("", Weekday::Thu));
+        assert_eq!(short_or_long_weekday("Tues").unwrap(), ("", Weekday::Tue));
+        assert_eq!(short_or_long_weekday("Tuesday").unwrap(), ("", Weekday::Tue));
+        assert_eq!(short_or_long_weekday("Tuess").unwrap(), ("s", Weekday::Tue));
+        assert_eq!(short_or_long_weekday("Thur").unwrap(), ("", Weekday::Thu));
+        assert_eq!(short_or_long_weekday("THURS").unwrap(), ("", Weekday::Thu));
+        assert_eq!(short_or_long_weekday("Thursday").unwrap(), ("", Weekday::Thu));
+        assert_eq!(short_or_long_weekday("Thurday").unwrap(), ("day", Weekday::Thu));
+        assert_eq!(short_or_long_weekday("Wednesday").unwrap(), ("", Weekday::Wed));
+        assert_eq!(short_or_long_weekday("Weds").unwrap(), ("s", Weekday::Wed));
+        assert_eq!(short_or_long_weekday("Mons").unwrap(), ("s", Weekday::Mon));
     }
 
     #[test]
@@ -511,6 +637,17 @@ mod tests {
         assert_eq!(nanosecond("8").unwrap(), ("", 800000000));
     }
 
+    #[test]
+    fn test_nanosecond_rounded() {
+        assert_eq!(nanosecond_rounded("8").unwrap(), ("", 800_000_000));
+        assert_eq!(nanosecond_rounded("123456789").unwrap(), ("", 123_456_789));
+        assert_eq!(nanosecond_rounded("1234567894").unwrap(), ("", 123_456_789));
+        assert_eq!(nanosecond_rounded("1234567895").unwrap(), ("", 123_456_790));
+        assert_eq!(nanosecond_rounded("12345678949999Z").unwrap(), ("Z", 123_456_789));
+        assert_eq!(nanosecond_rounded("9999999995").unwrap(), ("", 1_000_000_000));
+        assert!(nanosecond_rounded("Z").is_err());
+    }
+
     #[test]
     fn test_equals() {
         assert!(equals(b"\x5b", "["));
@@ -568,4 +705,20 @@ mod tests {
         assert_eq!(consume_colon_maybe(":  😸"), Ok("  😸"));
         assert_eq!(consume_colon_maybe(": :😸"), Ok(" :😸"));
     }
+
+    #[test]
+    fn test_am_pm() {
+        assert_eq!(am_pm("vorm.", "vorm.", "nachm."), Ok(("", false)));
+        assert_eq!(am_pm("NACHM. x", "vorm.", "nachm."), Ok((" x", true)));
+        assert_eq!(am_pm("午前", "午前", "午後"), Ok(("", false)));
+        assert_eq!(am_pm("午後😸", "午前", "午後"), Ok(("😸", true)));
+        // the longest match wins
+        assert_eq!(am_pm("a.m.", "a", "a.m."), Ok(("", true)));
+        assert_eq!(am_pm("a.x", "a", "a.m."), Ok((".x", false)));
+        assert_eq!(am_pm("vorm", "vorm.", "nachm."), Err(INVALID));
+        assert_eq!(am_pm("x", "vorm.", "nachm."), Err(INVALID));
+        assert_eq!(am_pm("", "vorm.", "nachm."), Err(TOO_SHORT));
+        // empty names never match
+        assert_eq!(am_pm("x", "", "pm"), Err(INVALID));
+    }
 }
@@ -19,6 +19,7 @@ The following specifiers are available both to formatting and parsing.
 | `%b`  | `Jul`    | Abbreviated month name. Always 3 letters.                                  |
 | `%B`  | `July`   | Full month name. Also accepts corresponding abbreviation in parsing.       |
 | `%h`  | `Jul`    | Same as `%b`.                                                              |
+| `%q`  | `3`      | Quarter of the year (1--4), derived from the month.                        |
 |       |          |                                                                            |
 | `%d`  | `08`     | Day number (01--31), zero-padded to 2 digits.                              |
 | `%e`  | ` 8`     | Same as `%d` but space-padded. Same as `%_d`.                              |
@@ -55,9 +56,11 @@ The following specifiers are available both to formatting and parsing.
 | `%S`  | `60`     | Second number (00--60), zero-padded to 2 digits. [^4]                      |
 | `%f`  | `026490000`   | The fractional seconds (in nanoseconds) since last whole second. [^7] |
 | `%.f` | `.026490`| Similar to `.%f` but left-aligned. These all consume the leading dot. [^7] |
+| `%#.f`| `.026490`| Same as `%.f`, but rounds to the nearest nanosecond when parsing. [^7]     |
 | `%.3f`| `.026`        | Similar to `.%f` but left-aligned but fixed to a length of 3. [^7]    |
 | `%.6f`| `.026490`     | Similar to `.%f` but left-aligned but fixed to a length of 6. [^7]    |
 | `%.9f`| `.026490000`  | Similar to `.%f` but left-aligned but fixed to a length of 9. [^7]    |
+| `%.4f`| `.0264`       | Similar to `.%f` but left-aligned but fixed to any other length from 1 to 8, truncated (not rounded). [^7] |
 | `%3f` | `026`         | Similar to `%.3f` but without the leading dot. [^7]                   |
 | `%6f` | `026490`      | Similar to `%.6f` but without the leading dot. [^7]                   |
 | `%9f` | `026490000`   | Similar to `%.9f` but without the leading dot. [^7]                   |
@@ -72,14 +75,16 @@ The following specifiers are available both to formatting and parsing.
 | `%z`  | `+0930`  | Offset from the local time to UTC (with UTC being `+0000`).                |
 | `%:z` | `+09:30` | Same as `%z` but with a colon.                                             |
 |`%::z`|`+09:30:00`| Offset from the local time to UTC with seconds.                            |
-|`%:::z`| `+09`    | Offset from the local time to UTC without minutes.                         |
-| `%#z` | `+09`    | *Parsing only:* Same as `%z` but allows minutes to be missing or present.  |
+|`%:::z`| `+09:30` | Offset from the local time to UTC without minutes, unless they are nonzero (`+05:30`). [^9] |
+| `%#z` | `+0930`  | Same as `%z` when formatting. When parsing, allows one or two digits for the hours and the minutes, and minutes to be missing (`+5`, `+530`, `+5:30`). |
+|`%#:z`| `+09:30` | Same as `%:z`, but prints `Z` for a zero offset. When parsing, accepts `Z` (or `UTC`) as well as `+09:30` and `+0930`. |
 |       |          |                                                                            |
 |       |          | **DATE & TIME SPECIFIERS:**                                                |
 |`%c`|`Sun Jul  8 00:34:60 2001`|Locale's date and time (e.g., Thu Mar  3 23:05:25 2005).       |
 | `%+`  | `2001-07-08T00:34:60.026490+09:30` | ISO 8601 / RFC 3339 date & time format. [^5]     |
 |       |               |                                                                       |
 | `%s`  | `994518299`   | UNIX timestamp, the number of seconds since 1970-01-01 00:00 UTC. [^6]|
+| `%Q`  | `994518299026`| The number of milliseconds since 1970-01-01 00:00 UTC. [^6]           |
 |       |          |                                                                            |
 |       |          | **SPECIAL SPECIFIERS:**                                                    |
 | `%t`  |          | Literal tab (`\t`).                                                        |
@@ -95,10 +100,15 @@ Modifier | Description
 `%_?`    | Uses spaces as a padding. (e.g. `%j` = `012`, `%_j` = ` 12`)
 `%0?`    | Uses zeroes as a padding. (e.g. `%e` = ` 9`, `%0e` = `09`)
 
+When parsing, space-padded specifiers (such as `%e`, `%k`, `%l` and `%_?`) accept the leading
+spaces they are formatted with, so both ` 9` and `09` can be parsed with `%k`.
+
 Notes:
 
 [^1]: `%C`, `%y`:
    This is floor division, so 100 BCE (year number -99) will print `-1` and `99` respectively.
+   When parsing `%y` (or `%g`) without a century, the year is assumed to be in 1970--2069.
+   Use `parse_from_str_with_pivot` on the parsed type to choose a different 100-year window.
 
 [^2]: `%U`:
    Week 1 starts with the first Sunday in that year.
@@ -127,12 +137,16 @@ Notes:
    stable, it is best to avoid this specifier if you want to control the exact
    output.
 
-[^6]: `%s`:
+[^6]: `%s`, `%Q`:
    This is not padded and can be negative.
    For the purpose of Chrono, it only accounts for non-leap seconds
    so it slightly differs from ISO C `strftime` behavior.
+   <br>
+   <br>
+   `%Q` also carries the milliseconds, so parsing it sets the nanosecond as well.
+   Any other sub-second specifier in the same format string must agree with it.
 
-[^7]: `%f`, `%.f`, `%.3f`, `%.6f`, `%.9f`, `%3f`, `%6f`, `%9f`:
+[^7]: `%f`, `%.f`, `%#.f`, `%.3f`, `%.6f`, `%.9f`, `%.Nf`, `%3f`, `%6f`, `%9f`:
    <br>
    The default `%f` is right-aligned and always zero-padded to 9 digits
    for the compatibility with glibc and others,
@@ -149,6 +163,13 @@ Notes:
    the next character is not `.`.
    <br>
    <br>
+   The variant `%#.f` prints and reads the same as `%.f`, but instead of skipping
+   the digits past the ninth it rounds half up on the tenth digit.
+   E.g. parsing `.0264900005` will yield 26,490,001 nanoseconds.
+   The rounding can carry into the whole seconds:
+   `59.9999999995` is read as the start of the next minute, not as a leap second.
+   <br>
+   <br>
    The variant `%.3f`, `%.6f` and `%.9f` are left-aligned and print 3, 6 or 9 fractional digits
    according to the number preceding `f`.
    E.g. 70ms after the last second under `%.3f` will print `.070` (note: not `.07`),
@@ -157,6 +178,15 @@ Notes:
    the next character is not `.` however will print with the specified length.
    <br>
    <br>
+   The variant `%.1f`, `%.2f`, `%.4f`, `%.5f`, `%.7f` and `%.8f` print exactly that many
+   fractional digits, and truncate the rest like `%.3f`; they do not round.
+   E.g. 26,490,000 nanoseconds under `%.4f` will print `.0264`, and under `%.2f` will print `.02`,
+   so a value never rounds up into the next whole second.
+   They read the same as `%.3f`.
+   Unlike `%.3f` they have no public [`Fixed`] variant;
+   use [`StrftimeItems`] to get the corresponding formatting item.
+   <br>
+   <br>
    The variant `%3f`, `%6f` and `%9f` are left-aligned and print 3, 6 or 9 fractional digits
    according to the number preceding `f`, but without the leading dot.
    E.g. 70ms after the last second under `%3f` will print `070` (note: not `07`),
@@ -178,6 +208,20 @@ Notes:
    It is not possible to reliably convert from an abbreviation to an offset,
    for example CDT can mean either Central Daylight Time (North America) or
    China Daylight Time.
+   <br>
+   <br>
+   To look up the names you know, parse with
+   [`parse_with_timezone_names`](crate::format::parse_with_timezone_names) instead:
+   it validates the name and sets the offset if the name is unambiguous.
+
+[^9]: `%:::z`:
+   Like the GNU `date` command, this prints the offset only as precisely as needed:
+   `+09` for a whole number of hours, `+05:30` if the minutes are nonzero, and
+   `+05:30:15` if the seconds are nonzero. So it never loses information.
+   <br>
+   <br>
+   When parsing, the hours can be followed by optional minutes, with or without a colon
+   (`+09`, `+0930` or `+09:30`). Offsets with seconds cannot be parsed.
 */
 
 #[cfg(feature = "unstable-locales")]
@@ -188,7 +232,10 @@ use alloc::vec::Vec;
 
 #[cfg(feature = "unstable-locales")]
 use super::{locales, Locale};
-use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad};
+use super::{
+    Fixed, InternalFixed, InternalInternal, InternalNumeric, InternalNumericKind, Item, Numeric,
+    Pad,
+};
 
 #[cfg(feature = "unstable-locales")]
 type Fmt<'a> = Vec<Item<'a>>;
@@ -275,7 +322,7 @@ impl<'a> StrftimeItems<'a> {
     }
 }
 
-const HAVE_ALTERNATES: &str = "z";
+const HAVE_ALTERNATES: &str = "z.:";
 
 impl<'a> Iterator for StrftimeItems<'a> {
     type Item = Item<'a>;
@@ -373,6 +420,7 @@ impl<'a> Iterator for StrftimeItems<'a> {
                     'I' => num0!(Hour12),
                     'M' => num0!(Minute),
                     'P' => fix!(LowerAmPm),
+                    'Q' => internal_num!(TimestampMillis),
                     'R' => recons![num0!(Hour), lit!(":"), num0!(Minute)],
                     'S' => num0!(Second),
                     'T' => recons![num0!(Hour), lit!(":"), num0!(Minute), lit!(":"), num0!(Second)],
@@ -395,6 +443,7 @@ impl<'a> Iterator for StrftimeItems<'a> {
                     'm' => num0!(Month),
                     'n' => sp!("\n"),
                     'p' => fix!(UpperAmPm),
+                    'q' => internal_num!(Quarter),
                     'r' => recons![
                         num0!(Hour12),
                         lit!(":"),
@@ -421,6 +470,14 @@ impl<'a> Iterator for StrftimeItems<'a> {
                         }
                     }
                     '+' => fix!(RFC3339),
+                    ':' if is_alternate => {
+                        if self.remainder.starts_with('z') {
+                            self.remainder = &self.remainder[1..];
+                            fix!(TimezoneOffsetColonZ)
+                        } else {
+                            Item::Error
+                        }
+                    }
                     ':' => {
                         if self.remainder.starts_with("::z") {
                             self.remainder = &self.remainder[3..];
@@ -435,6 +492,10 @@ impl<'a> Iterator for StrftimeItems<'a> {
                             Item::Error
                         }
                     }
+                    '.' if is_alternate => match next!() {
+                        'f' => internal_fix!(NanosecondRounded),
+                        _ => Item::Error,
+                    },
                     '.' => match next!() {
                         '3' => match next!() {
                             'f' => fix!(Nanosecond3),
@@ -448,6 +509,12 @@ impl<'a> Iterator for StrftimeItems<'a> {
                             'f' => fix!(Nanosecond9),
                             _ => Item::Error,
                         },
+                        c @ ('1' | '2' | '4' | '5' | '7' | '8') => match next!() {
+                            'f' => Item::Fixed(Fixed::Internal(InternalFixed {
+                                val: InternalInternal::NanosecondDigits(c as u8 - b'0'),
+                            })),
+                            _ => Item::Error,
+                        },
                         'f' => fix!(Nanosecond),
                         _ => Item::Error,
                     },
@@ -512,8 +579,13 @@ impl<'a> Iterator for StrftimeItems<'a> {
 mod tests {
     #[cfg(feature = "unstable-locales")]
     use super::Locale;
-    use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad, StrftimeItems};
-    use crate::{DateTime, FixedOffset, NaiveDate, TimeZone, Timelike, Utc};
+    use super::{
+        Fixed, InternalFixed, InternalInternal, InternalNumeric, InternalNumericKind, Item,
+        Numeric, Pad, StrftimeItems,
+    };
+    use crate::{
+        DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+    };
 
     #[test]
     fn test_strftime_items() {
@@ -629,6 +701,22 @@ mod tests {
         assert_eq!(parse_and_collect("%.j"), [Item::Error]);
         assert_eq!(parse_and_collect("%:j"), [Item::Error]);
         assert_eq!(parse_and_collect("%-j"), [num!(Ordinal)]);
+        assert_eq!(
+            parse_and_collect("%.4f"),
+            [Item::Fixed(Fixed::Internal(InternalFixed {
+                val: InternalInternal::NanosecondDigits(4)
+            }))]
+        );
+        assert_eq!(
+            parse_and_collect("%.1f"),
+            [Item::Fixed(Fixed::Internal(InternalFixed {
+                val: InternalInternal::NanosecondDigits(1)
+            }))]
+        );
+        assert_eq!(parse_and_collect("%.0f"), [Item::Error]);
+        assert_eq!(parse_and_collect("%.10f"), [Item::Error]);
+        assert_eq!(parse_and_collect("Q%q"), [lit!("Q"), internal_num!(Quarter)]);
+        assert_eq!(parse_and_collect("%Q"), [internal_num!(TimestampMillis)]);
         assert_eq!(parse_and_collect("%0j"), [num0!(Ordinal)]);
         assert_eq!(parse_and_collect("%_j"), [nums!(Ordinal)]);
         assert_eq!(parse_and_collect("%.e"), [Item::Error]);
@@ -642,6 +730,12 @@ mod tests {
         assert_eq!(parse_and_collect("%ZZZZ"), [fix!(TimezoneName), lit!("ZZZ")]);
         assert_eq!(parse_and_collect("%Z😽"), [fix!(TimezoneName), lit!("😽")]);
         assert_eq!(parse_and_collect("%#z"), [internal_fix!(TimezoneOffsetPermissive)]);
+        assert_eq!(parse_and_collect("%#:z"), [fix!(TimezoneOffsetColonZ)]);
+        assert_eq!(parse_and_collect("%#::z"), [Item::Error]);
+        assert_eq!(parse_and_collect("%#:"), [Item::Error]);
+        assert_eq!(parse_and_collect("%#.f"), [internal_fix!(NanosecondRounded)]);
+        assert_eq!(parse_and_collect("%#.3f"), [Item::Error]);
+        assert_eq!(parse_and_collect("%#f"), [Item::Error]);
         assert_eq!(parse_and_collect("%#m"), [Item::Error]);
     }
 
@@ -662,6 +756,7 @@ mod tests {
         assert_eq!(dt.format("%C").to_string(), "20");
         assert_eq!(dt.format("%y").to_string(), "01");
         assert_eq!(dt.format("%m").to_string(), "07");
+        assert_eq!(dt.format("%q").to_string(), "3");
         assert_eq!(dt.format("%b").to_string(), "Jul");
         assert_eq!(dt.format("%B").to_string(), "July");
         assert_eq!(dt.format("%h").to_string(), "Jul");
@@ -700,6 +795,7 @@ mod tests {
         assert_eq!(dt.format("%.3f").to_string(), ".026");
         assert_eq!(dt.format("%.6f").to_string(), ".026490");
         assert_eq!(dt.format("%.9f").to_string(), ".026490708");
+        assert_eq!(dt.format("%.4f").to_string(), ".0264");
         assert_eq!(dt.format("%3f").to_string(), "026");
         assert_eq!(dt.format("%6f").to_string(), "026490");
         assert_eq!(dt.format("%9f").to_string(), "026490708");
@@ -713,7 +809,8 @@ mod tests {
         assert_eq!(dt.format("%z").to_string(), "+0930");
         assert_eq!(dt.format("%:z").to_string(), "+09:30");
         assert_eq!(dt.format("%::z").to_string(), "+09:30:00");
-        assert_eq!(dt.format("%:::z").to_string(), "+09");
+        assert_eq!(dt.format("%:::z").to_string(), "+09:30");
+        assert_eq!(dt.format("%#:z").to_string(), "+09:30");
 
         // date & time specifiers
         assert_eq!(dt.format("%c").to_string(), "Sun Jul  8 00:34:60 2001");
@@ -744,6 +841,7 @@ mod tests {
             "2001-07-08T00:34:60.026490+09:30"
         );
         assert_eq!(dt.format("%s").to_string(), "994518299");
+        assert_eq!(dt.format("%Q").to_string(), "994518299026");
 
         // special specifiers
         assert_eq!(dt.format("%t").to_string(), "\t");
@@ -754,8 +852,155 @@ mod tests {
         assert_eq!(dt.format("  %Y%d%m%%%%%t%H%M%S\t").to_string(), "  20010807%%\t003460\t");
         assert_eq!(
             dt.format("  %Y%d%m%%%%%t%H:%P:%M%S%:::z\t").to_string(),
-            "  20010807%%\t00:am:3460+09\t"
+            "  20010807%%\t00:am:3460+09:30\t"
+        );
+    }
+
+    #[test]
+    fn test_strftime_fixed_digits() {
+        let t = |nano| NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_nano_opt(0, 0, 0, nano);
+        let fmt = |nano, spec| t(nano).unwrap().format(spec).to_string();
+
+        // truncation, like `%.3f`
+        assert_eq!(fmt(26_450_000, "%.4f"), ".0264");
+        assert_eq!(fmt(26_550_000, "%.4f"), ".0265");
+        assert_eq!(fmt(26_999_999, "%.2f"), ".02");
+        assert_eq!(fmt(26_999_999, "%.3f"), ".026");
+        assert_eq!(fmt(26_999_999, "%.4f"), ".0269");
+        assert_eq!(fmt(250_000_000, "%.1f"), ".2");
+        assert_eq!(fmt(123_456_789, "%.8f"), ".12345678");
+        assert_eq!(fmt(123_456_789, "%.7f"), ".1234567");
+        assert_eq!(fmt(123_456_789, "%.5f"), ".12345");
+
+        // boundary values
+        for spec in ["%.1f", "%.2f", "%.4f", "%.5f", "%.7f", "%.8f"] {
+            let digits = usize::from(spec.as_bytes()[2] - b'0');
+            assert_eq!(fmt(0, spec), format!(".{}", "0".repeat(digits)));
+            assert_eq!(fmt(1, spec), format!(".{}", "0".repeat(digits)));
+            assert_eq!(fmt(999_999_999, spec), format!(".{}", "9".repeat(digits)));
+            assert_eq!(fmt(500_000_000, spec), format!(".5{}", "0".repeat(digits - 1)));
+        }
+        // the smallest value that shows up in the last digit
+        assert_eq!(fmt(100_000_000, "%.1f"), ".1");
+        assert_eq!(fmt(99_999_999, "%.1f"), ".0");
+        assert_eq!(fmt(10, "%.8f"), ".00000001");
+        assert_eq!(fmt(9, "%.8f"), ".00000000");
+
+        // leap seconds only use the fractional part
+        let leap = NaiveDate::from_ymd_opt(2001, 7, 8).unwrap().and_hms_nano_opt(
+            23,
+            59,
+            59,
+            1_026_490_708,
         );
+        assert_eq!(leap.unwrap().format("%S%.5f").to_string(), "60.02649");
+
+        // parsing reads any number of digits
+        let parse = |s| NaiveTime::parse_from_str(s, "%H:%M:%S%.4f");
+        assert_eq!(
+            parse("00:00:00.0265"),
+            Ok(NaiveTime::from_hms_nano_opt(0, 0, 0, 26_500_000).unwrap())
+        );
+        assert_eq!(
+            parse("00:00:00.02649"),
+            Ok(NaiveTime::from_hms_nano_opt(0, 0, 0, 26_490_000).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_strftime_offset_necessary_precision() {
+        for (secs, expected) in [
+            (0, "+00"),
+            (5 * 3600, "+05"),
+            (-4 * 3600, "-04"),
+            (5 * 3600 + 30 * 60, "+05:30"),
+            (-(3 * 3600 + 30 * 60), "-03:30"),
+            (5 * 3600 + 30 * 60 + 15, "+05:30:15"),
+            (3600 + 15, "+01:00:15"),
+            (-86_399, "-23:59:59"),
+        ] {
+            let offset = FixedOffset::east_opt(secs).unwrap();
+            let dt = offset.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+            assert_eq!(dt.format("%:::z").to_string(), expected);
+            if secs % 60 == 0 {
+                let formatted = dt.format("%F %T %:::z").to_string();
+                assert_eq!(
+                    DateTime::<FixedOffset>::parse_from_str(&formatted, "%F %T %:::z"),
+                    Ok(dt)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_strftime_timestamp_millis() {
+        let ymd_hms_milli = |y, m, d, h, n, s, ms| {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_milli_opt(h, n, s, ms).unwrap()
+        };
+        for (dt, millis) in [
+            (ymd_hms_milli(1970, 1, 1, 0, 0, 0, 0), "0"),
+            (ymd_hms_milli(2001, 9, 9, 1, 46, 40, 555), "1000000000555"),
+            (ymd_hms_milli(1969, 12, 31, 23, 59, 59, 999), "-1"),
+            (ymd_hms_milli(1969, 12, 31, 23, 59, 58, 1), "-1999"),
+        ] {
+            assert_eq!(dt.format("%Q").to_string(), millis);
+            assert_eq!(dt.and_utc().format("%Q").to_string(), millis);
+            assert_eq!(NaiveDateTime::parse_from_str(millis, "%Q"), Ok(dt));
+        }
+
+        // the offset is taken into account
+        let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(1970, 1, 1, 1, 0, 0);
+        assert_eq!(dt.unwrap().format("%Q").to_string(), "0");
+
+        // sub-millisecond precision is truncated
+        let dt = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_nano_opt(0, 0, 1, 2_999_999);
+        assert_eq!(dt.unwrap().format("%Q").to_string(), "1002");
+    }
+
+    #[test]
+    fn test_strftime_quarter() {
+        for month in 1..=12 {
+            let date = NaiveDate::from_ymd_opt(2023, month, 15).unwrap();
+            let formatted = date.format("%Y Q%q %m-%d").to_string();
+            assert_eq!(formatted, format!("2023 Q{} {:02}-15", (month - 1) / 3 + 1, month));
+            assert_eq!(NaiveDate::parse_from_str(&formatted, "%Y Q%q %m-%d"), Ok(date));
+        }
+
+        // the quarter must agree with the month
+        assert!(NaiveDate::parse_from_str("2023 Q2 07-15", "%Y Q%q %m-%d").is_err());
+        assert!(NaiveDate::parse_from_str("2023 Q5 07-15", "%Y Q%q %m-%d").is_err());
+        assert!(NaiveDate::parse_from_str("2023 Q0 01-15", "%Y Q%q %m-%d").is_err());
+        assert_eq!(
+            NaiveDate::parse_from_str("2023 Q3 189", "%Y Q%q %j"),
+            Ok(NaiveDate::from_ymd_opt(2023, 7, 8).unwrap())
+        );
+        assert!(NaiveDate::parse_from_str("2023 Q1 189", "%Y Q%q %j").is_err());
+    }
+
+    #[cfg(feature = "unstable-locales")]
+    #[test]
+    fn test_strftime_localized_iso_week() {
+        // the ISO week date specifiers are numeric and must not depend on the locale,
+        // in particular around the year boundaries where the ISO year differs.
+        for year in 1999..=2030 {
+            let start = NaiveDate::from_ymd_opt(year, 12, 25).unwrap();
+            for date in start.iter_days().take(14) {
+                let expected = date.format("%G-W%V-%u %g").to_string();
+                for locale in [Locale::POSIX, Locale::fr_BE, Locale::de_DE] {
+                    let localized = date.format_localized("%G-W%V-%u %g", locale).to_string();
+                    assert_eq!(localized, expected, "{}", date);
+
+                    let dt = Utc.from_utc_datetime(&date.and_hms_opt(12, 0, 0).unwrap());
+                    let localized = dt.format_localized("%G-W%V-%u %g", locale).to_string();
+                    assert_eq!(localized, expected, "{}", dt);
+                }
+            }
+        }
+
+        let date = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap();
+        assert_eq!(date.format_localized("%G-W%V-%u", Locale::de_DE).to_string(), "2020-W53-7");
+        let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        assert_eq!(date.format_localized("%G-W%V-%u", Locale::fr_BE).to_string(), "2025-W01-1");
     }
 
     #[cfg(feature = "unstable-locales")]
@@ -381,7 +381,7 @@
 use core::fmt;
 
 mod time_delta;
-pub use time_delta::TimeDelta;
+pub use time_delta::{TimeDelta, TimeUnit};
 
 #[cfg(feature = "__doctest")]
 #[cfg_attr(feature = "__doctest", cfg(doctest))]
@@ -407,7 +407,7 @@ pub mod prelude {
     #[doc(no_inline)]
     pub use crate::SubsecRound;
     #[doc(no_inline)]
-    pub use crate::{DateTime, SecondsFormat};
+    pub use crate::{DateTime, SecondsFormat, ZuluPolicy};
     #[doc(no_inline)]
     pub use crate::{Datelike, Month, Timelike, Weekday};
     #[doc(no_inline)]
@@ -424,7 +424,7 @@ pub use date::{Date, MAX_DATE, MIN_DATE};
 
 mod datetime;
 #[allow(deprecated)]
-pub use datetime::{DateTime, SecondsFormat, MAX_DATETIME, MIN_DATETIME};
+pub use datetime::{DateTime, SecondsFormat, ZuluPolicy, MAX_DATETIME, MIN_DATETIME};
 
 pub mod format;
 /// L10n locales.
@@ -435,7 +435,9 @@ pub use format::{ParseError, ParseResult};
 
 pub mod naive;
 #[doc(no_inline)]
-pub use naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, NaiveWeek};
+pub use naive::{
+    is_leap_year, ClampedError, Days, Era, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, NaiveWeek,
+};
 
 pub mod offset;
 #[cfg(feature = "clock")]
@@ -443,19 +445,19 @@ pub mod offset;
 #[doc(no_inline)]
 pub use offset::Local;
 #[doc(no_inline)]
-pub use offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+pub use offset::{AmbiguityStrategy, FixedOffset, LocalResult, Offset, TimeZone, Utc};
 
 mod round;
-pub use round::{DurationRound, RoundingError, SubsecRound};
+pub use round::{DurationRound, RoundMode, RoundingError, SubsecRound};
 
 mod weekday;
 pub use weekday::{ParseWeekdayError, Weekday};
 
 mod month;
-pub use month::{Month, Months, ParseMonthError};
+pub use month::{Month, Months, OverflowPolicy, ParseMonthError};
 
 mod traits;
-pub use traits::{Datelike, Timelike};
+pub use traits::{DateError, Datelike, Timelike};
 
 #[cfg(feature = "__internal_bench")]
 #[doc(hidden)]
@@ -3,6 +3,7 @@ use core::fmt;
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
 
+use crate::naive::is_leap_year;
 use crate::OutOfRange;
 
 /// The month of the year.
@@ -59,6 +60,32 @@ pub enum Month {
 }
 
 impl Month {
+    /// All months of the year, starting with January.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Month;
+    ///
+    /// assert_eq!(Month::ALL.len(), 12);
+    /// assert_eq!(Month::ALL[0], Month::January);
+    /// assert_eq!(Month::ALL[11], Month::December);
+    /// ```
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
     /// The next month.
     ///
     /// `m`:        | `January`  | `February` | `...` | `December`
@@ -155,6 +182,43 @@ impl Month {
             Month::December => "December",
         }
     }
+
+    /// Get the number of days in the month in the given `year`.
+    ///
+    /// February has 29 days in leap years of the proleptic Gregorian calendar and 28 otherwise.
+    ///
+    /// ```
+    /// use chrono::Month;
+    ///
+    /// assert_eq!(Month::January.length(2023), 31);
+    /// assert_eq!(Month::February.length(2023), 28);
+    /// assert_eq!(Month::February.length(2024), 29);
+    /// assert_eq!(Month::February.length(1900), 28);
+    /// assert_eq!(Month::February.length(2000), 29);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn length(&self, year: i32) -> u8 {
+        match *self {
+            Month::February if is_leap_year(year) => 29,
+            _ => self.length_in_common_year(),
+        }
+    }
+
+    /// Get the number of days in the month in a common (non-leap) year.
+    ///
+    /// `m`:                          | `January` | `February` | `March` | `...` | `December`
+    /// ----------------------------- | --------- | ---------- | ------- | ----- | ----------
+    /// `m.length_in_common_year()`:  | 31        | 28         | 31      | `...` | 31
+    #[inline]
+    #[must_use]
+    pub const fn length_in_common_year(&self) -> u8 {
+        match *self {
+            Month::February => 28,
+            Month::April | Month::June | Month::September | Month::November => 30,
+            _ => 31,
+        }
+    }
 }
 
 impl TryFrom<u8> for Month {
@@ -191,6 +255,33 @@ impl Months {
     }
 }
 
+/// Formats the number of months followed by the unit, e.g. `1 month` or `3 months`.
+///
+/// The output can be parsed back with [`str::parse`].
+impl fmt::Display for Months {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit = if self.0 == 1 { "month" } else { "months" };
+        write!(f, "{} {}", self.0, unit)
+    }
+}
+
+/// How to handle a day of month that does not exist in the month resulting from adding [`Months`].
+///
+/// Used by [`NaiveDate::add_months_with_policy`](crate::NaiveDate::add_months_with_policy).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OverflowPolicy {
+    /// Use the last day of the resulting month, so January 31 plus one month is the last day of
+    /// February.
+    ///
+    /// This is the behavior of [`NaiveDate::checked_add_months`](crate::NaiveDate::checked_add_months).
+    Clamp,
+    /// Roll the excess days over into the next month, so January 31 plus one month is March 3 in
+    /// a common year and March 2 in a leap year.
+    Overflow,
+    /// Fail if the day does not exist in the resulting month.
+    Error,
+}
+
 /// An error resulting from reading `<Month>` value with `FromStr`.
 #[derive(Clone, PartialEq, Eq)]
 pub struct ParseMonthError {
@@ -249,7 +340,7 @@ mod month_serde {
 
 #[cfg(test)]
 mod tests {
-    use super::Month;
+    use super::{Month, Months};
     use crate::{Datelike, OutOfRange, TimeZone, Utc};
 
     #[test]
@@ -267,6 +358,78 @@ mod tests {
         assert_eq!((dt.year(), dt.month(), dt.day()), (2019, 1, 28));
     }
 
+    #[test]
+    fn test_month_all() {
+        assert_eq!(Month::ALL.len(), 12);
+        assert_eq!(Month::ALL[0], Month::January);
+        for (i, month) in Month::ALL.iter().enumerate() {
+            assert_eq!(month.number_from_month(), i as u32 + 1);
+            assert_eq!(Month::try_from(i as u8 + 1), Ok(*month));
+            assert_eq!(Month::ALL[(i + 1) % 12], month.succ());
+        }
+    }
+
+    #[test]
+    fn test_month_from_str() {
+        let names = [
+            ("jan", "january", Month::January),
+            ("feb", "february", Month::February),
+            ("mar", "march", Month::March),
+            ("apr", "april", Month::April),
+            ("may", "may", Month::May),
+            ("jun", "june", Month::June),
+            ("jul", "july", Month::July),
+            ("aug", "august", Month::August),
+            ("sep", "september", Month::September),
+            ("oct", "october", Month::October),
+            ("nov", "november", Month::November),
+            ("dec", "december", Month::December),
+        ];
+        for (short, long, month) in names {
+            assert_eq!(short.parse::<Month>(), Ok(month));
+            assert_eq!(long.parse::<Month>(), Ok(month));
+            assert_eq!(short.to_uppercase().parse::<Month>(), Ok(month));
+            assert_eq!(long.to_uppercase().parse::<Month>(), Ok(month));
+            assert_eq!(month.name().parse::<Month>(), Ok(month));
+
+            // trailing input is not accepted
+            assert!(format!("{} ", short).parse::<Month>().is_err());
+            assert!(format!("{}x", long).parse::<Month>().is_err());
+            assert!(format!(" {}", long).parse::<Month>().is_err());
+        }
+
+        assert!("".parse::<Month>().is_err());
+        assert!("ja".parse::<Month>().is_err());
+        assert!("janu".parse::<Month>().is_err());
+        assert!("sept".parse::<Month>().is_err());
+        assert!("1".parse::<Month>().is_err());
+    }
+
+    #[test]
+    fn test_months_to_string_and_from_str() {
+        assert_eq!(Months::new(0).to_string(), "0 months");
+        assert_eq!(Months::new(1).to_string(), "1 month");
+        assert_eq!(Months::new(3).to_string(), "3 months");
+        for n in [0, 1, 2, 12, u32::MAX] {
+            assert_eq!(Months::new(n).to_string().parse::<Months>(), Ok(Months::new(n)));
+        }
+        assert_eq!("1 month".parse::<Months>().map(|m| m.to_string()).as_deref(), Ok("1 month"));
+
+        assert_eq!("1 months".parse::<Months>(), Ok(Months::new(1)));
+        assert_eq!("2 month".parse::<Months>(), Ok(Months::new(2)));
+        assert_eq!("2\tMONTHS".parse::<Months>(), Ok(Months::new(2)));
+        assert_eq!("007".parse::<Months>(), Ok(Months::new(7)));
+
+        assert!("".parse::<Months>().is_err());
+        assert!("months".parse::<Months>().is_err());
+        assert!("+1 month".parse::<Months>().is_err());
+        assert!(" 1 month".parse::<Months>().is_err());
+        assert!("1 month ".parse::<Months>().is_err());
+        assert!("1 ".parse::<Months>().is_err());
+        assert!("1 mon".parse::<Months>().is_err());
+        assert!("4294967296".parse::<Months>().is_err());
+    }
+
     #[test]
     fn test_month_enum_succ_pred() {
         assert_eq!(Month::January.succ(), Month::February);
@@ -275,6 +438,35 @@ mod tests {
         assert_eq!(Month::February.pred(), Month::January);
     }
 
+    #[test]
+    fn test_month_length() {
+        use crate::NaiveDate;
+
+        assert_eq!(Month::February.length(1900), 28);
+        assert_eq!(Month::February.length(2000), 29);
+        assert_eq!(Month::February.length(2100), 28);
+        assert_eq!(Month::February.length(0), 29);
+        assert_eq!(Month::February.length(-4), 29);
+        assert_eq!(Month::February.length(-100), 28);
+
+        for year in [-400, -1, 0, 1900, 2000, 2023, 2024] {
+            let mut month = Month::January;
+            for _ in 0..12 {
+                let m = month.number_from_month();
+                let first = NaiveDate::from_ymd_opt(year, m, 1).unwrap();
+                let next = match m {
+                    12 => NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap(),
+                    _ => NaiveDate::from_ymd_opt(year, m + 1, 1).unwrap(),
+                };
+                assert_eq!(month.length(year) as i64, (next - first).num_days());
+                if month != Month::February {
+                    assert_eq!(month.length(year), month.length_in_common_year());
+                }
+                month = month.succ();
+            }
+        }
+    }
+
     #[test]
     fn test_month_partial_ord() {
         assert!(Month::January <= Month::January);
@@ -22,7 +22,7 @@ use crate::format::{
     parse, parse_and_remainder, write_hundreds, Item, Numeric, Pad, ParseError, ParseResult,
     Parsed, StrftimeItems,
 };
-use crate::month::Months;
+use crate::month::{Month, Months, OverflowPolicy};
 use crate::naive::{IsoWeek, NaiveDateTime, NaiveTime};
 use crate::{Datelike, TimeDelta, Weekday};
 
@@ -121,6 +121,31 @@ impl NaiveWeek {
     pub fn days(&self) -> RangeInclusive<NaiveDate> {
         self.first_day()..=self.last_day()
     }
+
+    /// Returns the week that is the given number of weeks after this one, with the same first day
+    /// of the week. A negative `weeks` goes back in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the date this week was created from, moved by the given number of
+    /// weeks, would be out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2022, 4, 18).unwrap().week(Weekday::Mon);
+    /// let next = week.add_weeks(1).unwrap();
+    /// assert_eq!(next.first_day(), NaiveDate::from_ymd_opt(2022, 4, 25).unwrap());
+    /// let previous = week.add_weeks(-1).unwrap();
+    /// assert_eq!(previous.first_day(), NaiveDate::from_ymd_opt(2022, 4, 11).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn add_weeks(&self, weeks: i64) -> Option<NaiveWeek> {
+        Some(NaiveWeek { date: self.date.add_weeks(weeks)?, start: self.start })
+    }
 }
 
 /// A duration in calendar days.
@@ -139,6 +164,86 @@ impl Days {
     }
 }
 
+/// Formats the number of days followed by the unit, e.g. `1 day` or `10 days`.
+///
+/// The output can be parsed back with [`str::parse`].
+impl fmt::Display for Days {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit = if self.0 == 1 { "day" } else { "days" };
+        write!(f, "{} {}", self.0, unit)
+    }
+}
+
+/// A historical era, used to number years the way they are usually written instead of the
+/// astronomical year numbering of [`Datelike::year`].
+///
+/// See [`NaiveDate::historical_year`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Era {
+    /// Before Christ, the years before year 1 (also known as BCE).
+    BC,
+    /// Anno Domini, the years from year 1 on (also known as CE).
+    AD,
+}
+
+/// The error of [`NaiveDate::checked_add_months_exact`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ClampedError {
+    /// The day of month does not exist in the resulting month, and had to be reduced to its last
+    /// day.
+    Clamped {
+        /// The date with the day clamped to the last day of the month, as returned by
+        /// [`NaiveDate::checked_add_months`].
+        clamped: NaiveDate,
+        /// The day of month that does not exist in the resulting month.
+        requested_day: u32,
+    },
+    /// The resulting date would be out of range.
+    OutOfRange,
+}
+
+impl fmt::Display for ClampedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ClampedError::Clamped { clamped, requested_day } => write!(
+                f,
+                "day {} does not exist in the resulting month, clamped to {}",
+                requested_day, clamped
+            ),
+            ClampedError::OutOfRange => write!(f, "resulting date out of range"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ClampedError {}
+
+/// Returns `true` if `year` is a leap year in the proleptic Gregorian calendar used by chrono.
+///
+/// A year is a leap year if it is divisible by 4, except for years divisible by 100 that are not
+/// divisible by 400. The same rules apply to year 0 and negative years (in the ISO 8601
+/// numbering, where year 0 is 1 BCE), so year 0 and year -4 are leap years.
+///
+/// # Example
+///
+/// ```
+/// use chrono::is_leap_year;
+///
+/// assert!(is_leap_year(2000));
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(1900));
+/// assert!(!is_leap_year(2023));
+/// assert!(is_leap_year(0));
+/// assert!(is_leap_year(-4));
+/// assert!(!is_leap_year(-100));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
 /// ISO 8601 calendar date without timezone.
 /// Allows for every [proleptic Gregorian date] from Jan 1, 262145 BCE to Dec 31, 262143 CE.
 /// Also supports the conversion from ISO 8601 ordinal and week date.
@@ -158,7 +263,9 @@ impl Days {
 ///
 /// * ISO 8601 calendars has the year 0, which is 1 BCE (a year before 1 CE).
 ///   If you need a typical BCE/BC and CE/AD notation for year numbers,
-///   use the [`Datelike::year_ce`](../trait.Datelike.html#method.year_ce) method.
+///   use the [`Datelike::year_ce`](../trait.Datelike.html#method.year_ce) or
+///   [`NaiveDate::historical_year`] methods.
+///   Year 0 is 1 BC, year -1 is 2 BC and so on; there is no year 0 in that notation.
 ///
 /// # Week Date
 ///
@@ -289,6 +396,32 @@ impl NaiveDate {
         NaiveDate::from_mdf(year, Mdf::new(month, day, flags)?)
     }
 
+    /// Makes a new `NaiveDate` from the [calendar date](#calendar-date), with the month given as
+    /// a [`Month`].
+    ///
+    /// This is the same as [`NaiveDate::from_ymd_opt`], but the type of `month` rules out a
+    /// month number that is out of range, or mixing up month numbers starting from 0 and 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if:
+    /// - The specified calendar day does not exist (for example 2023-04-31).
+    /// - The value for `day` is invalid.
+    /// - `year` is out of range for `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Month, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ym_day(2024, Month::February, 29), NaiveDate::from_ymd_opt(2024, 2, 29));
+    /// assert!(NaiveDate::from_ym_day(2023, Month::February, 29).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_ym_day(year: i32, month: Month, day: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+    }
+
     /// Makes a new `NaiveDate` from the [ordinal date](#ordinal-date)
     /// (year and day of the year).
     ///
@@ -354,7 +487,9 @@ impl NaiveDate {
     /// # Errors
     ///
     /// Returns `None` if:
-    /// - The specified week does not exist in that year (for example 2023 week 53).
+    /// - The specified week does not exist in that year. An ISO week year has 53 weeks if it
+    ///   starts on a Thursday, or if it is a leap year starting on a Wednesday, and 52 weeks
+    ///   otherwise. So for example 2023 week 53 does not exist, while 2020 week 53 does.
     /// - The value for `week` is invalid (for example: `0`, `60`).
     /// - If the resulting date is out of range for `NaiveDate`.
     ///
@@ -406,9 +541,10 @@ impl NaiveDate {
             let delta = flags.isoweek_delta();
             if weekord <= delta {
                 // ordinal < 1, previous year
-                let prevflags = YearFlags::from_year(year - 1);
+                let prevyear = year.checked_sub(1)?;
+                let prevflags = YearFlags::from_year(prevyear);
                 NaiveDate::from_ordinal_and_flags(
-                    year - 1,
+                    prevyear,
                     weekord + prevflags.ndays() - delta,
                     prevflags,
                 )
@@ -420,8 +556,9 @@ impl NaiveDate {
                     NaiveDate::from_ordinal_and_flags(year, ordinal, flags)
                 } else {
                     // ordinal > ndays, next year
-                    let nextflags = YearFlags::from_year(year + 1);
-                    NaiveDate::from_ordinal_and_flags(year + 1, ordinal - ndays, nextflags)
+                    let nextyear = year.checked_add(1)?;
+                    let nextflags = YearFlags::from_year(nextyear);
+                    NaiveDate::from_ordinal_and_flags(nextyear, ordinal - ndays, nextflags)
                 }
             }
         } else {
@@ -573,6 +710,35 @@ impl NaiveDate {
         parsed.to_naive_date()
     }
 
+    /// Parses a string with the specified format string and returns a new `NaiveDate`,
+    /// interpreting a two-digit year (`%y` or `%g`) as a year within the 100 years starting at
+    /// `pivot`.
+    ///
+    /// [`NaiveDate::parse_from_str`] uses a `pivot` of 1970, so that two-digit years map to
+    /// 1970--2069. The `pivot` is not used if the century is also parsed, e.g. with `%C`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`NaiveDate::parse_from_str`], and returns an `OutOfRange` error if `pivot` is
+    /// negative while a two-digit year needs it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let parse = |s, pivot| NaiveDate::parse_from_str_with_pivot(s, "%d/%m/%y", pivot);
+    /// assert_eq!(parse("12/04/70", 1970), Ok(NaiveDate::from_ymd_opt(1970, 4, 12).unwrap()));
+    /// assert_eq!(parse("12/04/70", 2000), Ok(NaiveDate::from_ymd_opt(2070, 4, 12).unwrap()));
+    /// assert_eq!(parse("12/04/49", 1950), Ok(NaiveDate::from_ymd_opt(2049, 4, 12).unwrap()));
+    /// ```
+    pub fn parse_from_str_with_pivot(s: &str, fmt: &str, pivot: i32) -> ParseResult<NaiveDate> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.set_two_digit_year_pivot(pivot)?;
+        parsed.to_naive_date()
+    }
+
     /// Parses a string from a user-specified format into a new `NaiveDate` value, and a slice with
     /// the remaining portion of the string.
     /// See the [`format::strftime` module](../format/strftime/index.html)
@@ -623,11 +789,55 @@ impl NaiveDate {
         }
 
         match months.0 <= core::i32::MAX as u32 {
-            true => self.diff_months(months.0 as i32),
+            true => self.diff_months(months.0 as i32, OverflowPolicy::Clamp),
             false => None,
         }
     }
 
+    /// Add a duration in [`Months`] to the date, and report it if the day had to be clamped.
+    ///
+    /// This is [`NaiveDate::checked_add_months`], but it returns an error if the day does not
+    /// exist in the resulting month instead of silently using the last day of that month.
+    ///
+    /// This is the same as [`NaiveDate::add_months_with_policy`] with [`OverflowPolicy::Error`],
+    /// but the error tells what went wrong.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClampedError::Clamped`] if the day had to be reduced. The error carries the
+    /// clamped date, which is what `checked_add_months` would return.
+    ///
+    /// Returns [`ClampedError::OutOfRange`] if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{ClampedError, NaiveDate, Months};
+    /// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(ymd(2023, 1, 15).checked_add_months_exact(Months::new(1)), Ok(ymd(2023, 2, 15)));
+    ///
+    /// assert_eq!(
+    ///     ymd(2023, 1, 31).checked_add_months_exact(Months::new(1)),
+    ///     Err(ClampedError::Clamped { clamped: ymd(2023, 2, 28), requested_day: 31 })
+    /// );
+    ///
+    /// assert_eq!(
+    ///     NaiveDate::MAX.checked_add_months_exact(Months::new(1)),
+    ///     Err(ClampedError::OutOfRange)
+    /// );
+    /// ```
+    pub fn checked_add_months_exact(self, months: Months) -> Result<Self, ClampedError> {
+        match self.add_months_with_policy(months, OverflowPolicy::Error) {
+            Some(date) => Ok(date),
+            // out of range for both policies, or a day that only exists when clamped
+            None => match self.checked_add_months(months) {
+                Some(clamped) => Err(ClampedError::Clamped { clamped, requested_day: self.day() }),
+                None => Err(ClampedError::OutOfRange),
+            },
+        }
+    }
+
     /// Subtract a duration in [`Months`] from the date
     ///
     /// Uses the last day of the month if the day does not exist in the resulting month.
@@ -659,12 +869,46 @@ impl NaiveDate {
 
         // Copy `i32::MAX` here so we don't have to do a complicated cast
         match months.0 <= 2_147_483_647 {
-            true => self.diff_months(-(months.0 as i32)),
+            true => self.diff_months(-(months.0 as i32), OverflowPolicy::Clamp),
             false => None,
         }
     }
 
-    fn diff_months(self, months: i32) -> Option<Self> {
+    /// Add a duration in [`Months`] to the date, with an explicit [`OverflowPolicy`] for days that
+    /// do not exist in the resulting month.
+    ///
+    /// With [`OverflowPolicy::Clamp`] this is the same as [`NaiveDate::checked_add_months`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range, or if the day does not exist in
+    /// the resulting month and the policy is [`OverflowPolicy::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::{NaiveDate, Months, OverflowPolicy};
+    /// let jan31 = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+    /// assert_eq!(
+    ///     jan31.add_months_with_policy(Months::new(1), OverflowPolicy::Clamp),
+    ///     Some(NaiveDate::from_ymd_opt(2023, 2, 28).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     jan31.add_months_with_policy(Months::new(1), OverflowPolicy::Overflow),
+    ///     Some(NaiveDate::from_ymd_opt(2023, 3, 3).unwrap())
+    /// );
+    /// assert_eq!(jan31.add_months_with_policy(Months::new(1), OverflowPolicy::Error), None);
+    /// ```
+    #[must_use]
+    pub fn add_months_with_policy(self, months: Months, policy: OverflowPolicy) -> Option<Self> {
+        if months.0 == 0 {
+            return Some(self);
+        }
+
+        i32::try_from(months.0).ok().and_then(|m| self.diff_months(m, policy))
+    }
+
+    fn diff_months(self, months: i32, policy: OverflowPolicy) -> Option<Self> {
         let (years, left) = ((months / 12), (months % 12));
 
         // Determine new year (without taking months into account for now
@@ -696,14 +940,23 @@ impl NaiveDate {
             (year, month)
         };
 
-        // Clamp original day in case new month is shorter
+        // Handle the original day according to `policy` in case new month is shorter
 
         let flags = YearFlags::from_year(year);
         let feb_days = if flags.ndays() == 366 { 29 } else { 28 };
         let days = [31, feb_days, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-        let day = Ord::min(self.day(), days[(month - 1) as usize]);
+        let last_day = days[(month - 1) as usize];
+        let excess = self.day().saturating_sub(last_day);
+        if excess > 0 && policy == OverflowPolicy::Error {
+            return None;
+        }
+        let day = Ord::min(self.day(), last_day);
 
-        NaiveDate::from_mdf(year, Mdf::new(month as u32, day, flags)?)
+        let date = NaiveDate::from_mdf(year, Mdf::new(month as u32, day, flags)?)?;
+        match policy {
+            OverflowPolicy::Overflow => date.checked_add_days(Days::new(excess as u64)),
+            _ => Some(date),
+        }
     }
 
     /// Add a duration in [`Days`] to the date
@@ -766,6 +1019,140 @@ impl NaiveDate {
         i64::try_from(days.0).ok().and_then(|d| self.diff_days(-d))
     }
 
+    /// Shift the date by a signed number of days.
+    ///
+    /// Equivalent to [`NaiveDate::checked_add_days`] for positive `days` and to
+    /// [`NaiveDate::checked_sub_days`] for negative `days`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let date = NaiveDate::from_ymd_opt(2022, 3, 1).unwrap();
+    /// assert_eq!(date.shift_days(9), Some(NaiveDate::from_ymd_opt(2022, 3, 10).unwrap()));
+    /// assert_eq!(date.shift_days(-1), Some(NaiveDate::from_ymd_opt(2022, 2, 28).unwrap()));
+    /// assert_eq!(date.shift_days(i64::MIN), None);
+    /// ```
+    #[must_use]
+    pub fn shift_days(self, days: i64) -> Option<Self> {
+        self.diff_days(days)
+    }
+
+    /// Add a signed number of days to the date, saturating at the bounds of the supported range.
+    ///
+    /// Returns the resulting date and whether the addition overflowed. On overflow the date is
+    /// [`NaiveDate::MAX`] when `days` is positive and [`NaiveDate::MIN`] when it is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let date = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+    /// assert_eq!(date.overflowing_add_days(1), (NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), false));
+    /// assert_eq!(date.overflowing_add_days(i64::MAX), (NaiveDate::MAX, true));
+    /// assert_eq!(date.overflowing_add_days(i64::MIN), (NaiveDate::MIN, true));
+    /// ```
+    #[must_use]
+    pub fn overflowing_add_days(self, days: i64) -> (Self, bool) {
+        match self.diff_days(days) {
+            Some(date) => (date, false),
+            None if days < 0 => (NaiveDate::MIN, true),
+            None => (NaiveDate::MAX, true),
+        }
+    }
+
+    /// Shift the date by a signed number of months.
+    ///
+    /// Equivalent to [`NaiveDate::checked_add_months`] for positive `months` and to
+    /// [`NaiveDate::checked_sub_months`] for negative `months`. Uses the last day of the month if
+    /// the day does not exist in the resulting month.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let date = NaiveDate::from_ymd_opt(2022, 3, 31).unwrap();
+    /// assert_eq!(date.shift_months(2), Some(NaiveDate::from_ymd_opt(2022, 5, 31).unwrap()));
+    /// assert_eq!(date.shift_months(-1), Some(NaiveDate::from_ymd_opt(2022, 2, 28).unwrap()));
+    /// assert_eq!(date.shift_months(-13), Some(NaiveDate::from_ymd_opt(2021, 2, 28).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn shift_months(self, months: i32) -> Option<Self> {
+        self.diff_months(months, OverflowPolicy::Clamp)
+    }
+
+    /// Shift the date by a signed number of months, keeping dates at the end of the month at the
+    /// end of the month.
+    ///
+    /// A date is at the end of the month if the next day is in a different month, e.g. January 31,
+    /// April 30, or February 28 in a common year (but not in a leap year). For such a date the
+    /// result is always the last day of the resulting month. Other dates behave like
+    /// [`NaiveDate::shift_months`], and are clamped to the last day of the month if the day does
+    /// not exist in the resulting month.
+    ///
+    /// Note that this makes a sequence of end-of-month dates stable: with plain clamping
+    /// January 31 would become February 28 and then March 28, instead of March 31.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let feb28 = ymd(2023, 1, 31).add_months_eom(1).unwrap();
+    /// assert_eq!(feb28, ymd(2023, 2, 28));
+    /// assert_eq!(feb28.add_months_eom(1), Some(ymd(2023, 3, 31)));
+    /// assert_eq!(feb28.shift_months(1), Some(ymd(2023, 3, 28)));
+    ///
+    /// // not at the end of the month
+    /// assert_eq!(ymd(2024, 2, 28).add_months_eom(1), Some(ymd(2024, 3, 28)));
+    /// ```
+    #[must_use]
+    pub fn add_months_eom(self, months: i32) -> Option<Self> {
+        let date = self.diff_months(months, OverflowPolicy::Clamp)?;
+        let is_eom = self.succ_opt().map_or(true, |next| next.month() != self.month());
+        if !is_eom {
+            return Some(date);
+        }
+        let month = Month::try_from(date.month() as u8).ok()?;
+        date.with_day(u32::from(month.length(date.year())))
+    }
+
+    /// Add a signed number of weeks to the date.
+    ///
+    /// This is the same as adding `7 * weeks` days. A negative `weeks` goes back in time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range, including when `7 * weeks`
+    /// overflows an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let date = NaiveDate::from_ymd_opt(2022, 12, 28).unwrap();
+    /// assert_eq!(date.add_weeks(1), Some(NaiveDate::from_ymd_opt(2023, 1, 4).unwrap()));
+    /// assert_eq!(date.add_weeks(-52), Some(NaiveDate::from_ymd_opt(2021, 12, 29).unwrap()));
+    /// assert_eq!(date.add_weeks(i64::MAX), None);
+    /// ```
+    #[must_use]
+    pub fn add_weeks(self, weeks: i64) -> Option<Self> {
+        self.diff_days(weeks.checked_mul(7)?)
+    }
+
     fn diff_days(self, days: i64) -> Option<Self> {
         let secs = days.checked_mul(86400)?; // 86400 seconds in one day
         if secs >= core::i64::MAX / 1000 || secs <= core::i64::MIN / 1000 {
@@ -1202,9 +1589,30 @@ impl NaiveDate {
 
     /// Returns the number of whole years from the given `base` until `self`.
     ///
+    /// A year is complete when the month and day of `base` are reached again. For a `base` of
+    /// February 29 this happens on March 1 in common years, so someone born on 2000-02-29 turns one
+    /// on 2001-03-01 (not on 2001-02-28).
+    ///
     /// # Errors
     ///
-    /// Returns `None` if `base < self`.
+    /// Returns `None` if `base > self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let birthday = ymd(1990, 6, 15);
+    /// assert_eq!(ymd(2023, 6, 14).years_since(birthday), Some(32));
+    /// assert_eq!(ymd(2023, 6, 15).years_since(birthday), Some(33));
+    /// assert_eq!(ymd(1990, 6, 14).years_since(birthday), None);
+    ///
+    /// let leap_birthday = ymd(2000, 2, 29);
+    /// assert_eq!(ymd(2001, 2, 28).years_since(leap_birthday), Some(0));
+    /// assert_eq!(ymd(2001, 3, 1).years_since(leap_birthday), Some(1));
+    /// assert_eq!(ymd(2004, 2, 29).years_since(leap_birthday), Some(4));
+    /// ```
     #[must_use]
     pub fn years_since(&self, base: Self) -> Option<u32> {
         let mut years = self.year() - base.year();
@@ -1218,6 +1626,54 @@ impl NaiveDate {
         }
     }
 
+    /// Returns the difference between `self` and `other` in calendar years, months and days, as
+    /// `(years, months, days)`.
+    ///
+    /// The whole months are counted first, and the days are what remains after adding them to
+    /// `other` with [`NaiveDate::checked_add_months`]. So `other` plus `12 * years + months`
+    /// months plus `days` days is always `self`. If the day of `other` does not exist in the
+    /// month reached, the last day of that month is used: from January 31 to March 1 is 1 month
+    /// (to February 28) and 1 day.
+    ///
+    /// The months are always in the range `0..12` and the days in `0..31`. Only the years carry a
+    /// sign: if `self` is before `other` they are negative, while the months and days still count
+    /// forward. Use `other.diff_ymd(self)` to get the size of such a difference instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(ymd(2023, 10, 20).diff_ymd(ymd(1990, 6, 15)), (33, 4, 5));
+    /// assert_eq!(ymd(2023, 3, 1).diff_ymd(ymd(2023, 1, 31)), (0, 1, 1));
+    /// assert_eq!(ymd(2023, 3, 1).diff_ymd(ymd(2022, 12, 15)), (0, 2, 14));
+    /// assert_eq!(ymd(2023, 3, 1).diff_ymd(ymd(2023, 3, 1)), (0, 0, 0));
+    ///
+    /// // `self` before `other`
+    /// assert_eq!(ymd(2023, 1, 1).diff_ymd(ymd(2023, 3, 15)), (-1, 9, 17));
+    /// assert_eq!(ymd(2023, 3, 15).diff_ymd(ymd(2023, 1, 1)), (0, 2, 14));
+    /// ```
+    #[must_use]
+    pub fn diff_ymd(self, other: NaiveDate) -> (i32, u32, u32) {
+        let mut years = self.year() - other.year();
+        let mut months = self.month() as i32 - other.month() as i32;
+        let mut days = self.day() as i32 - other.day() as i32;
+        if days < 0 {
+            // `other` plus the whole months lands in the month before that of `self`, on the last
+            // day of that month if the day of `other` does not exist there.
+            months -= 1;
+            let year = if self.month() == 1 { self.year() - 1 } else { self.year() };
+            let length = u32::from(self.month_enum().pred().length(year));
+            days = (length - other.day().min(length) + self.day()) as i32;
+        }
+        if months < 0 {
+            years -= 1;
+            months += 12;
+        }
+        (years, months as u32, days as u32)
+    }
+
     /// Formats the date with the specified formatting items.
     /// Otherwise it is the same as the ordinary `format` method.
     ///
@@ -1399,6 +1855,123 @@ impl NaiveDate {
         NaiveWeek { date: *self, start }
     }
 
+    /// Returns the year number and era as used in historical dates.
+    ///
+    /// The year is numbered from 1 in either era: the astronomical year 1 is 1 AD, and year 0
+    /// is 1 BC, year -1 is 2 BC and so on. This is the same as
+    /// [`Datelike::year_ce`](../trait.Datelike.html#method.year_ce) with a typed era.
+    ///
+    /// Note that like the rest of `NaiveDate` this uses the proleptic Gregorian calendar, while
+    /// historical dates before 1582 are usually given in the Julian calendar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Era, NaiveDate};
+    ///
+    /// let year = |y| NaiveDate::from_ymd_opt(y, 1, 1).unwrap().historical_year();
+    /// assert_eq!(year(2023), (2023, Era::AD));
+    /// assert_eq!(year(1), (1, Era::AD));
+    /// assert_eq!(year(0), (1, Era::BC));
+    /// assert_eq!(year(-1), (2, Era::BC));
+    /// ```
+    #[must_use]
+    pub fn historical_year(&self) -> (u32, Era) {
+        match self.year_ce() {
+            (true, year) => (year, Era::AD),
+            (false, year) => (year, Era::BC),
+        }
+    }
+
+    /// Returns the 1-based week of the month that the date falls in, with weeks starting on
+    /// `start`.
+    ///
+    /// Week 1 is the (possibly partial) week containing the first day of the month. If the month
+    /// begins on `start`, week 1 is a full week; otherwise week 2 begins on the first `start`
+    /// after the first day of the month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // August 2020 begins on a Saturday.
+    /// let date = |d| NaiveDate::from_ymd_opt(2020, 8, d).unwrap();
+    /// assert_eq!(date(1).week_of_month(Weekday::Sun), 1);
+    /// assert_eq!(date(2).week_of_month(Weekday::Sun), 2);
+    /// assert_eq!(date(31).week_of_month(Weekday::Sun), 6);
+    /// assert_eq!(date(2).week_of_month(Weekday::Sat), 1);
+    /// ```
+    #[must_use]
+    pub fn week_of_month(&self, start: Weekday) -> u8 {
+        let first = self.with_day(1).expect("the first day of the month always exists");
+        ((self.day0() + first.weekday().days_since(start)) / 7 + 1) as u8
+    }
+
+    /// Returns the number of weeks, starting on `start`, that the given month touches.
+    ///
+    /// This is the [`week_of_month`](NaiveDate::week_of_month) of the last day of the month, so
+    /// it is always 4, 5 or 6. A calendar grid for the month needs this many rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `year` or `month` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// assert_eq!(NaiveDate::weeks_in_month(2015, 2, Weekday::Sun), Some(4));
+    /// assert_eq!(NaiveDate::weeks_in_month(2015, 2, Weekday::Mon), Some(5));
+    /// assert_eq!(NaiveDate::weeks_in_month(2020, 8, Weekday::Sun), Some(6));
+    /// assert_eq!(NaiveDate::weeks_in_month(2020, 13, Weekday::Sun), None);
+    /// ```
+    #[must_use]
+    pub fn weeks_in_month(year: i32, month: u32, start: Weekday) -> Option<u8> {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let length = Month::try_from(month as u8).ok()?.length(year);
+        first.with_day(u32::from(length)).map(|last| last.week_of_month(start))
+    }
+
+    /// Returns `true` if the year of this date is a leap year.
+    ///
+    /// See [`is_leap_year`](crate::is_leap_year) for the rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert!(NaiveDate::from_ymd_opt(2000, 7, 1).unwrap().is_leap_year());
+    /// assert!(!NaiveDate::from_ymd_opt(1900, 7, 1).unwrap().is_leap_year());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_leap_year(&self) -> bool {
+        self.num_days_in_year() == 366
+    }
+
+    /// Returns the number of days in the year of this date, 365 or 366.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    /// assert_eq!(date.num_days_in_year(), 366);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2023, 3, 1).unwrap().num_days_in_year(), 365);
+    ///
+    /// // the fraction of the year that has passed at the start of the day
+    /// assert_eq!(f64::from(date.ordinal0()) / f64::from(date.num_days_in_year()), 60.0 / 366.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn num_days_in_year(&self) -> u32 {
+        self.of().flags().ndays()
+    }
+
     /// The minimum possible `NaiveDate` (January 1, 262145 BCE).
     pub const MIN: NaiveDate = NaiveDate { ymdf: (MIN_YEAR << 13) | (1 << 4) | 0o07 /*FE*/ };
     /// The maximum possible `NaiveDate` (December 31, 262143 CE).
@@ -2307,7 +2880,8 @@ mod serde {
 
 #[cfg(test)]
 mod tests {
-    use super::{Days, Months, NaiveDate, MAX_YEAR, MIN_YEAR};
+    use super::{is_leap_year, ClampedError, Days, Era, Months, NaiveDate, MAX_YEAR, MIN_YEAR};
+    use crate::month::OverflowPolicy;
     use crate::time_delta::TimeDelta;
     use crate::{Datelike, Weekday};
     use std::{i32, u32};
@@ -2340,6 +2914,191 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_months_eom() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // a sequence of month ends stays at the end of the month
+        let mut date = ymd(2023, 1, 31);
+        let expected = [
+            ymd(2023, 2, 28),
+            ymd(2023, 3, 31),
+            ymd(2023, 4, 30),
+            ymd(2023, 5, 31),
+            ymd(2023, 6, 30),
+            ymd(2023, 7, 31),
+            ymd(2023, 8, 31),
+            ymd(2023, 9, 30),
+            ymd(2023, 10, 31),
+            ymd(2023, 11, 30),
+            ymd(2023, 12, 31),
+            ymd(2024, 1, 31),
+            ymd(2024, 2, 29),
+            ymd(2024, 3, 31),
+        ];
+        for next in expected {
+            date = date.add_months_eom(1).unwrap();
+            assert_eq!(date, next);
+        }
+
+        // and backwards
+        assert_eq!(ymd(2023, 4, 30).add_months_eom(-1), Some(ymd(2023, 3, 31)));
+        assert_eq!(ymd(2023, 3, 31).add_months_eom(-1), Some(ymd(2023, 2, 28)));
+        assert_eq!(ymd(2024, 2, 29).add_months_eom(-12), Some(ymd(2023, 2, 28)));
+        assert_eq!(ymd(2023, 2, 28).add_months_eom(12), Some(ymd(2024, 2, 29)));
+        assert_eq!(ymd(2023, 2, 28).add_months_eom(0), Some(ymd(2023, 2, 28)));
+
+        // February 28 in a leap year is not the end of the month
+        assert_eq!(ymd(2024, 2, 28).add_months_eom(1), Some(ymd(2024, 3, 28)));
+
+        // dates not at the end of the month are clamped
+        assert_eq!(ymd(2023, 1, 30).add_months_eom(1), Some(ymd(2023, 2, 28)));
+        assert_eq!(ymd(2023, 1, 30).add_months_eom(2), Some(ymd(2023, 3, 30)));
+        assert_eq!(ymd(2023, 3, 15).add_months_eom(-1), Some(ymd(2023, 2, 15)));
+
+        // out of range
+        assert_eq!(NaiveDate::MAX.add_months_eom(1), None);
+        assert_eq!(NaiveDate::MIN.add_months_eom(-1), None);
+        assert_eq!(NaiveDate::MAX.add_months_eom(-1), Some(NaiveDate::MAX - Days::new(31)));
+        assert_eq!(ymd(2023, 1, 31).add_months_eom(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_add_months_with_policy() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let add = |d: NaiveDate, m, p| d.add_months_with_policy(Months::new(m), p);
+        use OverflowPolicy::{Clamp, Error, Overflow};
+
+        // day exists in the resulting month
+        for policy in [Clamp, Overflow, Error] {
+            assert_eq!(add(ymd(2023, 1, 28), 1, policy), Some(ymd(2023, 2, 28)));
+            assert_eq!(add(ymd(2023, 1, 30), 2, policy), Some(ymd(2023, 3, 30)));
+            assert_eq!(add(ymd(2023, 5, 31), 0, policy), Some(ymd(2023, 5, 31)));
+            assert_eq!(add(NaiveDate::MAX, 1, policy), None);
+        }
+
+        // common year February
+        assert_eq!(add(ymd(2023, 1, 29), 1, Clamp), Some(ymd(2023, 2, 28)));
+        assert_eq!(add(ymd(2023, 1, 29), 1, Overflow), Some(ymd(2023, 3, 1)));
+        assert_eq!(add(ymd(2023, 1, 29), 1, Error), None);
+        assert_eq!(add(ymd(2023, 1, 31), 1, Clamp), Some(ymd(2023, 2, 28)));
+        assert_eq!(add(ymd(2023, 1, 31), 1, Overflow), Some(ymd(2023, 3, 3)));
+        assert_eq!(add(ymd(2023, 1, 31), 1, Error), None);
+
+        // leap year February
+        assert_eq!(add(ymd(2024, 1, 29), 1, Clamp), Some(ymd(2024, 2, 29)));
+        assert_eq!(add(ymd(2024, 1, 29), 1, Overflow), Some(ymd(2024, 2, 29)));
+        assert_eq!(add(ymd(2024, 1, 29), 1, Error), Some(ymd(2024, 2, 29)));
+        assert_eq!(add(ymd(2024, 1, 31), 1, Clamp), Some(ymd(2024, 2, 29)));
+        assert_eq!(add(ymd(2024, 1, 31), 1, Overflow), Some(ymd(2024, 3, 2)));
+        assert_eq!(add(ymd(2024, 1, 31), 1, Error), None);
+        assert_eq!(add(ymd(2023, 2, 28), 12, Error), Some(ymd(2024, 2, 28)));
+
+        // 31-day month into 30-day month
+        assert_eq!(add(ymd(2023, 3, 31), 1, Clamp), Some(ymd(2023, 4, 30)));
+        assert_eq!(add(ymd(2023, 3, 31), 1, Overflow), Some(ymd(2023, 5, 1)));
+        assert_eq!(add(ymd(2023, 3, 31), 1, Error), None);
+        assert_eq!(add(ymd(2023, 8, 31), 1, Overflow), Some(ymd(2023, 10, 1)));
+        assert_eq!(add(ymd(2023, 10, 31), 1, Overflow), Some(ymd(2023, 12, 1)));
+
+        // 30-day month into 31-day month
+        assert_eq!(add(ymd(2023, 4, 30), 1, Error), Some(ymd(2023, 5, 30)));
+        assert_eq!(add(ymd(2023, 7, 31), 1, Error), Some(ymd(2023, 8, 31)));
+        assert_eq!(add(ymd(2023, 12, 31), 1, Error), Some(ymd(2024, 1, 31)));
+    }
+
+    #[test]
+    fn test_shift_days_and_months() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(ymd(2022, 3, 1).shift_days(0), Some(ymd(2022, 3, 1)));
+        assert_eq!(ymd(2022, 3, 1).shift_days(-1), Some(ymd(2022, 2, 28)));
+        assert_eq!(ymd(2024, 3, 1).shift_days(-1), Some(ymd(2024, 2, 29)));
+        assert_eq!(ymd(2022, 1, 1).shift_days(-365), Some(ymd(2021, 1, 1)));
+        assert_eq!(ymd(2022, 1, 1).shift_days(365), Some(ymd(2023, 1, 1)));
+        assert_eq!(NaiveDate::MIN.shift_days(-1), None);
+        assert_eq!(NaiveDate::MAX.shift_days(1), None);
+        assert_eq!(NaiveDate::MAX.shift_days(i64::MIN), None);
+        assert_eq!(NaiveDate::MIN.shift_days(i64::MAX), None);
+
+        assert_eq!(ymd(2022, 3, 31).shift_months(0), Some(ymd(2022, 3, 31)));
+        assert_eq!(ymd(2022, 3, 31).shift_months(-1), Some(ymd(2022, 2, 28)));
+        assert_eq!(ymd(2024, 3, 31).shift_months(-1), Some(ymd(2024, 2, 29)));
+        assert_eq!(ymd(2022, 5, 31).shift_months(-1), Some(ymd(2022, 4, 30)));
+        assert_eq!(ymd(2022, 1, 15).shift_months(-1), Some(ymd(2021, 12, 15)));
+        assert_eq!(ymd(2022, 1, 15).shift_months(-25), Some(ymd(2019, 12, 15)));
+        assert_eq!(ymd(2022, 1, 31).shift_months(1), Some(ymd(2022, 2, 28)));
+        assert_eq!(NaiveDate::MIN.shift_months(-1), None);
+        assert_eq!(NaiveDate::MAX.shift_months(1), None);
+        assert_eq!(ymd(2022, 1, 1).shift_months(i32::MIN), None);
+        assert_eq!(ymd(2022, 1, 1).shift_months(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_shift_days() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(ymd(2022, 3, 1).shift_days(0), Some(ymd(2022, 3, 1)));
+        assert_eq!(ymd(2022, 3, 1).shift_days(-1), Some(ymd(2022, 2, 28)));
+        assert_eq!(ymd(2022, 2, 28).shift_days(1), Some(ymd(2022, 3, 1)));
+
+        // crossing year 0, which is a leap year
+        assert_eq!(ymd(1, 1, 1).shift_days(-1), Some(ymd(0, 12, 31)));
+        assert_eq!(ymd(1, 1, 1).shift_days(-366), Some(ymd(0, 1, 1)));
+        assert_eq!(ymd(1, 1, 1).shift_days(-367), Some(ymd(-1, 12, 31)));
+        assert_eq!(ymd(-1, 12, 31).shift_days(367), Some(ymd(1, 1, 1)));
+        assert_eq!(ymd(0, 2, 28).shift_days(1), Some(ymd(0, 2, 29)));
+
+        // the full range
+        let span = NaiveDate::MAX.signed_duration_since(NaiveDate::MIN).num_days();
+        assert_eq!(NaiveDate::MIN.shift_days(span), Some(NaiveDate::MAX));
+        assert_eq!(NaiveDate::MAX.shift_days(-span), Some(NaiveDate::MIN));
+        assert_eq!(NaiveDate::MIN.shift_days(span + 1), None);
+        assert_eq!(NaiveDate::MAX.shift_days(-(span + 1)), None);
+        assert_eq!(NaiveDate::MIN.shift_days(-1), None);
+        assert_eq!(NaiveDate::MAX.shift_days(1), None);
+
+        for days in [i64::MIN, i64::MIN + 1, i64::MAX] {
+            assert_eq!(ymd(2022, 1, 1).shift_days(days), None);
+        }
+
+        // consistent with the `Days` based methods
+        for days in [0, 1, 30, 365, 1_000_000] {
+            let date = ymd(2022, 7, 31);
+            assert_eq!(date.shift_days(days), date.checked_add_days(Days::new(days as u64)));
+            assert_eq!(date.shift_days(-days), date.checked_sub_days(Days::new(days as u64)));
+        }
+    }
+
+    #[test]
+    fn test_overflowing_add_days() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(ymd(2022, 3, 1).overflowing_add_days(0), (ymd(2022, 3, 1), false));
+        assert_eq!(ymd(2022, 3, 1).overflowing_add_days(-1), (ymd(2022, 2, 28), false));
+        assert_eq!(ymd(1, 1, 1).overflowing_add_days(-1), (ymd(0, 12, 31), false));
+
+        let span = NaiveDate::MAX.signed_duration_since(NaiveDate::MIN).num_days();
+        assert_eq!(NaiveDate::MIN.overflowing_add_days(span), (NaiveDate::MAX, false));
+        assert_eq!(NaiveDate::MAX.overflowing_add_days(-span), (NaiveDate::MIN, false));
+        assert_eq!(NaiveDate::MAX.overflowing_add_days(0), (NaiveDate::MAX, false));
+
+        // at the bounds
+        assert_eq!(NaiveDate::MAX.overflowing_add_days(1), (NaiveDate::MAX, true));
+        assert_eq!(NaiveDate::MIN.overflowing_add_days(-1), (NaiveDate::MIN, true));
+        assert_eq!(NaiveDate::MIN.overflowing_add_days(span + 1), (NaiveDate::MAX, true));
+        assert_eq!(NaiveDate::MAX.overflowing_add_days(-span - 1), (NaiveDate::MIN, true));
+        assert_eq!(ymd(2022, 1, 1).overflowing_add_days(i64::MAX), (NaiveDate::MAX, true));
+        assert_eq!(ymd(2022, 1, 1).overflowing_add_days(i64::MIN), (NaiveDate::MIN, true));
+
+        for days in [-1_000_000, -1, 0, 1, 365, 100_000_000] {
+            let date = ymd(2022, 7, 31);
+            let (result, overflowed) = date.overflowing_add_days(days);
+            assert_eq!(date.shift_days(days).is_none(), overflowed);
+            assert_eq!(date.shift_days(days).unwrap_or(result), result);
+        }
+    }
+
     #[test]
     fn diff_months() {
         // identity
@@ -2450,6 +3209,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_date_from_ym_day() {
+        use crate::Month;
+
+        let from_ym_day = NaiveDate::from_ym_day;
+        assert_eq!(from_ym_day(2024, Month::February, 29), NaiveDate::from_ymd_opt(2024, 2, 29));
+        assert_eq!(from_ym_day(2023, Month::February, 28), NaiveDate::from_ymd_opt(2023, 2, 28));
+        assert!(from_ym_day(2023, Month::February, 29).is_none());
+        assert!(from_ym_day(2024, Month::February, 30).is_none());
+        assert!(from_ym_day(2024, Month::February, 0).is_none());
+        assert!(from_ym_day(400000, Month::January, 1).is_none());
+        for month in Month::ALL {
+            let date = from_ym_day(2024, month, 1).unwrap();
+            assert_eq!(date.month(), month.number_from_month());
+            assert_eq!(date.month_enum(), month);
+        }
+    }
+
     #[test]
     fn test_date_from_ymd() {
         let ymd_opt = NaiveDate::from_ymd_opt;
@@ -2523,6 +3300,37 @@ mod tests {
         assert_eq!(isoywd_opt(2018, 52, Weekday::Mon), Some(ymd(2018, 12, 24)));
         assert_eq!(isoywd_opt(2018, 52, Weekday::Sun), Some(ymd(2018, 12, 30)));
         assert_eq!(isoywd_opt(2018, 53, Weekday::Mon), None);
+
+        // week 53 only exists in 53-week years, for every day of the week
+        for weekday in [Weekday::Mon, Weekday::Thu, Weekday::Sun] {
+            for (year, weeks) in [(2015, 53), (2019, 52), (2020, 53), (2021, 52), (2026, 53)] {
+                let last = isoywd_opt(year, weeks, weekday).unwrap();
+                assert_eq!((last.iso_week().year(), last.iso_week().week()), (year, weeks));
+                assert_eq!(isoywd_opt(year, weeks + 1, weekday), None);
+            }
+        }
+        assert_eq!(isoywd_opt(2020, 53, Weekday::Sun), Some(ymd(2021, 1, 3)));
+        assert_eq!(isoywd_opt(2021, 53, Weekday::Mon), None);
+        assert_eq!(isoywd_opt(2021, 1, Weekday::Mon), Some(ymd(2021, 1, 4)));
+        assert_eq!(isoywd_opt(2004, u32::MAX, Weekday::Mon), None);
+
+        // years far out of range don't overflow
+        for year in [i32::MIN, i32::MIN + 1, i32::MAX - 1, i32::MAX] {
+            for week in [1, 52, 53] {
+                assert_eq!(isoywd_opt(year, week, Weekday::Mon), None);
+                assert_eq!(isoywd_opt(year, week, Weekday::Sun), None);
+            }
+        }
+        let max = NaiveDate::MAX.iso_week();
+        assert_eq!(
+            isoywd_opt(max.year(), max.week(), NaiveDate::MAX.weekday()),
+            Some(NaiveDate::MAX)
+        );
+        let min = NaiveDate::MIN.iso_week();
+        assert_eq!(
+            isoywd_opt(min.year(), min.week(), NaiveDate::MIN.weekday()),
+            Some(NaiveDate::MIN)
+        );
     }
 
     #[test]
@@ -2813,6 +3621,63 @@ mod tests {
         check((0, 1, 1), TimeDelta::min_value(), None);
     }
 
+    #[test]
+    fn test_date_is_leap_year() {
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2004));
+        assert!(!is_leap_year(2100));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(-1));
+        assert!(!is_leap_year(-100));
+        assert!(is_leap_year(-400));
+        assert!(is_leap_year(i32::MIN));
+        assert!(!is_leap_year(i32::MAX));
+
+        let date = |y| NaiveDate::from_ymd_opt(y, 6, 1).unwrap();
+        assert!(!date(1900).is_leap_year());
+        assert_eq!(date(1900).num_days_in_year(), 365);
+        assert!(date(2000).is_leap_year());
+        assert_eq!(date(2000).num_days_in_year(), 366);
+        assert!(date(-4).is_leap_year());
+        assert_eq!(date(-4).num_days_in_year(), 366);
+        assert_eq!(date(-5).num_days_in_year(), 365);
+
+        // consistent with the calendar for every year in range
+        for year in MIN_YEAR..=MAX_YEAR {
+            let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+            assert_eq!(is_leap_year(year), leap, "{}", year);
+            assert_eq!(date(year).is_leap_year(), leap, "{}", year);
+            let last = NaiveDate::from_ymd_opt(year, 12, 31).unwrap();
+            assert_eq!(last.num_days_in_year(), last.ordinal(), "{}", year);
+        }
+    }
+
+    #[test]
+    fn test_date_add_extreme_durations() {
+        let extremes = [
+            TimeDelta::max_value(),
+            TimeDelta::min_value(),
+            TimeDelta::max_value() - TimeDelta::nanoseconds(1),
+            TimeDelta::min_value() + TimeDelta::nanoseconds(1),
+            TimeDelta::seconds(i64::MAX / 1000),
+            TimeDelta::seconds(-i64::MAX / 1000),
+        ];
+        let dates = [
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+            NaiveDate::from_ymd_opt(0, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2014, 1, 1).unwrap(),
+        ];
+        for date in dates {
+            for rhs in extremes {
+                assert_eq!(date.checked_add_signed(rhs), None, "{} + {:?}", date, rhs);
+                assert_eq!(date.checked_sub_signed(rhs), None, "{} - {:?}", date, rhs);
+            }
+        }
+    }
+
     #[test]
     fn test_date_sub() {
         fn check((y1, m1, d1): (i32, u32, u32), (y2, m2, d2): (i32, u32, u32), diff: TimeDelta) {
@@ -2833,6 +3698,157 @@ mod tests {
         check((MIN_YEAR, 1, 1), (0, 1, 1), TimeDelta::days(MIN_DAYS_FROM_YEAR_0 as i64));
     }
 
+    #[test]
+    fn test_date_checked_add_months_exact() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let add = |date: NaiveDate, months| date.checked_add_months_exact(Months::new(months));
+
+        assert_eq!(add(ymd(2023, 1, 15), 1), Ok(ymd(2023, 2, 15)));
+        assert_eq!(add(ymd(2023, 1, 28), 1), Ok(ymd(2023, 2, 28)));
+        assert_eq!(add(ymd(2023, 1, 31), 0), Ok(ymd(2023, 1, 31)));
+        assert_eq!(add(ymd(2023, 1, 31), 2), Ok(ymd(2023, 3, 31)));
+        assert_eq!(add(ymd(2024, 1, 29), 1), Ok(ymd(2024, 2, 29)));
+        assert_eq!(add(ymd(2023, 12, 31), 1), Ok(ymd(2024, 1, 31)));
+
+        let clamped = |clamped: NaiveDate, requested_day| {
+            Err(ClampedError::Clamped { clamped, requested_day })
+        };
+        assert_eq!(add(ymd(2023, 1, 31), 1), clamped(ymd(2023, 2, 28), 31));
+        assert_eq!(add(ymd(2024, 1, 31), 1), clamped(ymd(2024, 2, 29), 31));
+        assert_eq!(add(ymd(2023, 1, 29), 1), clamped(ymd(2023, 2, 28), 29));
+        assert_eq!(add(ymd(2023, 3, 31), 1), clamped(ymd(2023, 4, 30), 31));
+        assert_eq!(add(ymd(2024, 2, 29), 12), clamped(ymd(2025, 2, 28), 29));
+        assert_eq!(
+            add(ymd(2023, 1, 31), 1).unwrap_err().to_string(),
+            "day 31 does not exist in the resulting month, clamped to 2023-02-28"
+        );
+
+        // the clamped date is that of `checked_add_months`, and `Ok` matches the `Error` policy
+        for date in ymd(2023, 1, 1).iter_days().take(800) {
+            for months in 0..14 {
+                let expected = date.checked_add_months(Months::new(months));
+                let strict =
+                    date.add_months_with_policy(Months::new(months), OverflowPolicy::Error);
+                match add(date, months) {
+                    Ok(d) => {
+                        assert_eq!(Some(d), expected);
+                        assert_eq!(Some(d), strict);
+                    }
+                    Err(ClampedError::Clamped { clamped, requested_day }) => {
+                        assert_eq!(Some(clamped), expected);
+                        assert_eq!(requested_day, date.day());
+                        assert!(clamped.day() < date.day());
+                        assert_eq!(strict, None);
+                    }
+                    Err(ClampedError::OutOfRange) => panic!("{} + {} months", date, months),
+                }
+            }
+        }
+
+        // out of range
+        assert_eq!(add(NaiveDate::MAX, 1), Err(ClampedError::OutOfRange));
+        assert_eq!(add(ymd(2023, 1, 31), u32::MAX), Err(ClampedError::OutOfRange));
+        assert_eq!(add(ymd(2023, 1, 15), u32::MAX), Err(ClampedError::OutOfRange));
+        assert_eq!(add(NaiveDate::MAX, 1).unwrap_err().to_string(), "resulting date out of range");
+    }
+
+    #[test]
+    fn test_date_diff_ymd() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(ymd(2024, 5, 17).diff_ymd(ymd(2024, 5, 17)), (0, 0, 0));
+        assert_eq!(ymd(2024, 5, 18).diff_ymd(ymd(2024, 5, 17)), (0, 0, 1));
+        assert_eq!(ymd(2024, 6, 17).diff_ymd(ymd(2024, 5, 17)), (0, 1, 0));
+        assert_eq!(ymd(2025, 5, 17).diff_ymd(ymd(2024, 5, 17)), (1, 0, 0));
+        assert_eq!(ymd(2025, 5, 16).diff_ymd(ymd(2024, 5, 17)), (0, 11, 29)); // April has 30 days
+        assert_eq!(ymd(2025, 4, 16).diff_ymd(ymd(2024, 4, 17)), (0, 11, 30)); // March has 31 days
+        assert_eq!(ymd(2023, 3, 1).diff_ymd(ymd(2022, 12, 15)), (0, 2, 14)); // February has 28 days
+
+        // borrowing the length of the month before that of `self`
+        assert_eq!(ymd(2023, 3, 1).diff_ymd(ymd(2023, 1, 31)), (0, 1, 1));
+        assert_eq!(ymd(2023, 3, 1).diff_ymd(ymd(2023, 2, 28)), (0, 0, 1));
+        assert_eq!(ymd(2024, 3, 1).diff_ymd(ymd(2024, 2, 28)), (0, 0, 2));
+        assert_eq!(ymd(2024, 3, 28).diff_ymd(ymd(2024, 2, 29)), (0, 0, 28));
+        assert_eq!(ymd(2024, 3, 29).diff_ymd(ymd(2024, 2, 29)), (0, 1, 0));
+        assert_eq!(ymd(2023, 5, 30).diff_ymd(ymd(2023, 4, 30)), (0, 1, 0));
+        assert_eq!(ymd(2023, 5, 1).diff_ymd(ymd(2023, 4, 30)), (0, 0, 1));
+        assert_eq!(ymd(2024, 1, 1).diff_ymd(ymd(2023, 12, 31)), (0, 0, 1));
+
+        // leap day birthdays
+        assert_eq!(ymd(2025, 2, 28).diff_ymd(ymd(2024, 2, 29)), (0, 11, 30));
+        assert_eq!(ymd(2025, 3, 1).diff_ymd(ymd(2024, 2, 29)), (1, 0, 1));
+
+        // `self` before `other`
+        assert_eq!(ymd(2023, 1, 1).diff_ymd(ymd(2023, 3, 15)), (-1, 9, 17));
+        assert_eq!(ymd(2023, 5, 17).diff_ymd(ymd(2023, 5, 18)), (-1, 11, 29));
+        assert_eq!(ymd(2020, 5, 17).diff_ymd(ymd(2023, 5, 17)), (-3, 0, 0));
+
+        // the years agree with `years_since`, and the result is always in range
+        let base = ymd(2000, 2, 29);
+        for date in ymd(1999, 1, 1).iter_days().take(2000) {
+            let (years, months, days) = date.diff_ymd(base);
+            assert!(months < 12 && days < 31, "{}", date);
+            if let Some(since) = date.years_since(base) {
+                assert_eq!(years, since as i32, "{}", date);
+            } else {
+                assert!(years < 0, "{}", date);
+            }
+            let (years, months, days) = base.diff_ymd(date);
+            assert!(months < 12 && days < 31, "{}", date);
+            assert_eq!(years >= 0, date <= base, "{}", date);
+        }
+
+        // adding the difference to `other` gives back `self`
+        let add = |date: NaiveDate, (years, months, days): (i32, u32, u32)| {
+            let months = years * 12 + months as i32;
+            let date = match months >= 0 {
+                true => date.checked_add_months(Months::new(months as u32)),
+                false => date.checked_sub_months(Months::new(months.unsigned_abs())),
+            };
+            date.and_then(|d| d.checked_add_days(Days::new(u64::from(days))))
+        };
+        let dates: Vec<_> = ymd(2023, 12, 1).iter_days().take(460).step_by(3).collect();
+        for &date in &dates {
+            for &other in &dates {
+                assert_eq!(add(other, date.diff_ymd(other)), Some(date), "{} - {}", date, other);
+            }
+        }
+
+        assert_eq!(NaiveDate::MAX.diff_ymd(NaiveDate::MIN), (MAX_YEAR - MIN_YEAR, 11, 30));
+        assert_eq!(NaiveDate::MIN.diff_ymd(NaiveDate::MAX), (MIN_YEAR - MAX_YEAR - 1, 0, 1));
+    }
+
+    #[test]
+    fn test_date_years_since() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let base = ymd(2000, 6, 15);
+        assert_eq!(base.years_since(base), Some(0));
+        assert_eq!(ymd(2000, 6, 14).years_since(base), None);
+        assert_eq!(ymd(2001, 6, 14).years_since(base), Some(0));
+        assert_eq!(ymd(2001, 6, 15).years_since(base), Some(1));
+        assert_eq!(ymd(2001, 12, 31).years_since(base), Some(1));
+        assert_eq!(ymd(2100, 6, 15).years_since(base), Some(100));
+
+        // February 29 completes a year on March 1 in common years
+        let leap = ymd(2000, 2, 29);
+        assert_eq!(ymd(2000, 2, 28).years_since(leap), None);
+        assert_eq!(ymd(2000, 3, 1).years_since(leap), Some(0));
+        assert_eq!(ymd(2001, 2, 28).years_since(leap), Some(0));
+        assert_eq!(ymd(2001, 3, 1).years_since(leap), Some(1));
+        assert_eq!(ymd(2003, 2, 28).years_since(leap), Some(2));
+        assert_eq!(ymd(2004, 2, 28).years_since(leap), Some(3));
+        assert_eq!(ymd(2004, 2, 29).years_since(leap), Some(4));
+        assert_eq!(ymd(2100, 3, 1).years_since(leap), Some(100));
+
+        // a base in a common year that is later compared with a leap year
+        assert_eq!(ymd(2004, 2, 29).years_since(ymd(2003, 3, 1)), Some(0));
+        assert_eq!(ymd(2004, 2, 29).years_since(ymd(2003, 2, 28)), Some(1));
+
+        assert_eq!(NaiveDate::MAX.years_since(NaiveDate::MIN), Some((MAX_YEAR - MIN_YEAR) as u32));
+        assert_eq!(NaiveDate::MIN.years_since(NaiveDate::MAX), None);
+    }
+
     #[test]
     fn test_date_add_days() {
         fn check((y1, m1, d1): (i32, u32, u32), rhs: Days, ymd: Option<(i32, u32, u32)>) {
@@ -2878,6 +3894,28 @@ mod tests {
         check((0, 1, 1), (MIN_YEAR, 1, 1), Days::new((-MIN_DAYS_FROM_YEAR_0).try_into().unwrap()));
     }
 
+    #[test]
+    fn test_days_to_string_and_from_str() {
+        assert_eq!(Days::new(0).to_string(), "0 days");
+        assert_eq!(Days::new(1).to_string(), "1 day");
+        assert_eq!(Days::new(10).to_string(), "10 days");
+        for n in [0, 1, 2, 365, u64::MAX] {
+            assert_eq!(Days::new(n).to_string().parse::<Days>(), Ok(Days::new(n)));
+        }
+        assert_eq!("0 days".parse::<Days>().map(|d| d.to_string()).as_deref(), Ok("0 days"));
+
+        assert_eq!("1 days".parse::<Days>(), Ok(Days::new(1)));
+        assert_eq!("3 Day".parse::<Days>(), Ok(Days::new(3)));
+        assert_eq!("3".parse::<Days>(), Ok(Days::new(3)));
+
+        assert!("".parse::<Days>().is_err());
+        assert!("-1 days".parse::<Days>().is_err());
+        assert!("1.5 days".parse::<Days>().is_err());
+        assert!("1days".parse::<Days>().is_err());
+        assert!("1 weeks".parse::<Days>().is_err());
+        assert!("18446744073709551616 days".parse::<Days>().is_err());
+    }
+
     #[test]
     fn test_date_addassignment() {
         let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
@@ -3027,6 +4065,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_date_parse_from_str_isoweek_round_trip() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let parse = NaiveDate::parse_from_str;
+
+        assert_eq!(parse("2024-W05-3", "%G-W%V-%u"), Ok(ymd(2024, 1, 31)));
+
+        // the ISO week year differs from the calendar year around January 1
+        let start = ymd(2019, 12, 20);
+        for date in start.iter_days().take(60).chain(ymd(2020, 12, 20).iter_days().take(20)) {
+            let s = date.format("%G-W%V-%u").to_string();
+            assert_eq!(parse(&s, "%G-W%V-%u"), Ok(date), "{}", s);
+            let s = date.format("%Y-%m-%d %G-W%V-%u").to_string();
+            assert_eq!(parse(&s, "%Y-%m-%d %G-W%V-%u"), Ok(date), "{}", s);
+            let s = date.format("%G-W%V-%u %Y").to_string();
+            assert_eq!(parse(&s, "%G-W%V-%u %Y"), Ok(date), "{}", s);
+        }
+        assert_eq!(parse("2020-W01-1", "%G-W%V-%u"), Ok(ymd(2019, 12, 30)));
+        assert_eq!(parse("2020-W53-5", "%G-W%V-%u"), Ok(ymd(2021, 1, 1)));
+
+        // calendar fields parsed alongside are checked for consistency
+        assert_eq!(parse("2020-W01-1 2019", "%G-W%V-%u %Y"), Ok(ymd(2019, 12, 30)));
+        assert!(parse("2020-W01-1 2020", "%G-W%V-%u %Y").is_err());
+        assert!(parse("2019-12-30 2019-W01-1", "%Y-%m-%d %G-W%V-%u").is_err());
+        assert!(parse("2019-12-30 2020-W01-2", "%Y-%m-%d %G-W%V-%u").is_err());
+        assert_eq!(parse("2020-W53-5 Jan", "%G-W%V-%u %b"), Ok(ymd(2021, 1, 1)));
+        assert!(parse("2020-W53-5 Dec", "%G-W%V-%u %b").is_err());
+        assert!(parse("2019-W53-1", "%G-W%V-%u").is_err());
+    }
+
+    #[test]
+    fn test_date_parse_from_str_with_pivot() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let parse = NaiveDate::parse_from_str_with_pivot;
+
+        // the default pivot gives the same result as `parse_from_str`
+        for s in ["01-02-00", "01-02-69", "01-02-70", "01-02-99"] {
+            assert_eq!(parse(s, "%d-%m-%y", 1970), NaiveDate::parse_from_str(s, "%d-%m-%y"));
+        }
+
+        assert_eq!(parse("01-02-00", "%d-%m-%y", 2000), Ok(ymd(2000, 2, 1)));
+        assert_eq!(parse("01-02-70", "%d-%m-%y", 2000), Ok(ymd(2070, 2, 1)));
+        assert_eq!(parse("01-02-99", "%d-%m-%y", 2000), Ok(ymd(2099, 2, 1)));
+        assert_eq!(parse("01-02-49", "%d-%m-%y", 1950), Ok(ymd(2049, 2, 1)));
+        assert_eq!(parse("01-02-50", "%d-%m-%y", 1950), Ok(ymd(1950, 2, 1)));
+        assert_eq!(parse("01-02-20", "%d-%m-%y", 1800), Ok(ymd(1820, 2, 1)));
+
+        // an explicit century or full year takes precedence
+        assert_eq!(parse("01-02-1970", "%d-%m-%Y", 2000), Ok(ymd(1970, 2, 1)));
+        assert_eq!(parse("01-02-1970", "%d-%m-%C%y", 2000), Ok(ymd(1970, 2, 1)));
+
+        // two-digit ISO week years
+        assert_eq!(parse("70-W01-4", "%g-W%V-%u", 2000), Ok(ymd(2070, 1, 2)));
+        assert_eq!(parse("70-W01-4", "%g-W%V-%u", 1970), Ok(ymd(1970, 1, 1)));
+
+        assert!(parse("01-02-70", "%d-%m-%y", -1).is_err());
+        assert_eq!(parse("01-02-1970", "%d-%m-%Y", -1), Ok(ymd(1970, 2, 1)));
+    }
+
     #[test]
     fn test_date_format() {
         let d = NaiveDate::from_ymd_opt(2012, 3, 4).unwrap();
@@ -3092,6 +4189,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_week_iterator_fused() {
+        let mut forward = NaiveDate::from_ymd_opt(262143, 12, 24).unwrap().iter_weeks();
+        assert_eq!(forward.next(), NaiveDate::from_ymd_opt(262143, 12, 24));
+        assert_eq!(forward.next(), None);
+        assert_eq!(forward.next(), None);
+
+        let mut backward = NaiveDate::from_ymd_opt(-262144, 1, 8).unwrap().iter_weeks().rev();
+        assert_eq!(backward.next(), NaiveDate::from_ymd_opt(-262144, 1, 8));
+        assert_eq!(backward.next(), None);
+        assert_eq!(backward.next(), None);
+    }
+
     #[test]
     fn test_naiveweek() {
         let date = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap();
@@ -3113,6 +4223,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_naiveweek_add_weeks() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let week = ymd(2022, 5, 18).week(Weekday::Sun);
+        assert_eq!(week.add_weeks(0).unwrap().first_day(), ymd(2022, 5, 15));
+        assert_eq!(week.add_weeks(1).unwrap().first_day(), ymd(2022, 5, 22));
+        assert_eq!(week.add_weeks(33).unwrap().first_day(), ymd(2023, 1, 1));
+        assert_eq!(week.add_weeks(-1).unwrap().first_day(), ymd(2022, 5, 8));
+        assert_eq!(week.add_weeks(-20).unwrap().last_day(), ymd(2022, 1, 1));
+        assert!(week.add_weeks(i64::MAX).is_none());
+        assert!(week.add_weeks(i64::MIN).is_none());
+        assert!(NaiveDate::MAX.week(Weekday::Mon).add_weeks(1).is_none());
+        assert!(NaiveDate::MIN.week(Weekday::Mon).add_weeks(-1).is_none());
+    }
+
+    #[test]
+    fn test_add_weeks() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(ymd(2022, 5, 18).add_weeks(0), Some(ymd(2022, 5, 18)));
+        assert_eq!(ymd(2022, 5, 18).add_weeks(2), Some(ymd(2022, 6, 1)));
+        assert_eq!(ymd(2024, 2, 22).add_weeks(1), Some(ymd(2024, 2, 29)));
+        assert_eq!(ymd(2022, 5, 18).add_weeks(-2), Some(ymd(2022, 5, 4)));
+        assert_eq!(ymd(2022, 1, 5).add_weeks(-1), Some(ymd(2021, 12, 29)));
+        assert_eq!(ymd(2022, 5, 18).add_weeks(-522), Some(ymd(2012, 5, 16)));
+        assert_eq!(NaiveDate::MAX.add_weeks(1), None);
+        assert_eq!(NaiveDate::MIN.add_weeks(-1), None);
+        assert_eq!(NaiveDate::MAX.add_weeks(i64::MIN / 7 - 1), None);
+        assert_eq!(ymd(2022, 5, 18).add_weeks(i64::MAX / 7), None);
+    }
+
+    #[test]
+    fn test_historical_year() {
+        let year = |y| NaiveDate::from_ymd_opt(y, 6, 15).unwrap().historical_year();
+        assert_eq!(year(2023), (2023, Era::AD));
+        assert_eq!(year(2), (2, Era::AD));
+        assert_eq!(year(1), (1, Era::AD));
+        assert_eq!(year(0), (1, Era::BC));
+        assert_eq!(year(-1), (2, Era::BC));
+        assert_eq!(year(-2023), (2024, Era::BC));
+        assert_eq!(NaiveDate::MIN.historical_year(), ((1 - MIN_YEAR) as u32, Era::BC));
+        assert_eq!(NaiveDate::MAX.historical_year(), (MAX_YEAR as u32, Era::AD));
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // August 2020 begins on a Saturday and spans six Sunday-based weeks
+        let weeks: Vec<u8> =
+            (1..=31).map(|d| ymd(2020, 8, d).week_of_month(Weekday::Sun)).collect();
+        let mut expected = vec![1];
+        for week in 2..=6 {
+            expected.extend(core::iter::repeat(week).take(7));
+        }
+        expected.truncate(31);
+        assert_eq!(weeks, expected);
+        assert_eq!(NaiveDate::weeks_in_month(2020, 8, Weekday::Sun), Some(6));
+
+        // February 2015 begins on a Sunday, so week 1 is a full week
+        assert_eq!(ymd(2015, 2, 7).week_of_month(Weekday::Sun), 1);
+        assert_eq!(ymd(2015, 2, 8).week_of_month(Weekday::Sun), 2);
+        assert_eq!(NaiveDate::weeks_in_month(2015, 2, Weekday::Sun), Some(4));
+        assert_eq!(ymd(2015, 2, 1).week_of_month(Weekday::Mon), 1);
+        assert_eq!(ymd(2015, 2, 2).week_of_month(Weekday::Mon), 2);
+        assert_eq!(NaiveDate::weeks_in_month(2015, 2, Weekday::Mon), Some(5));
+
+        // agrees with `NaiveDate::week` for every start day
+        for month in 1..=12 {
+            let first = ymd(2024, month, 1);
+            for start in Weekday::ALL {
+                let first_week = first.week(start).first_day();
+                let mut last = 0;
+                for date in first.iter_days().take_while(|d| d.month() == month) {
+                    let rows = date.week(start).first_day().signed_duration_since(first_week);
+                    last = date.week_of_month(start);
+                    assert_eq!(i64::from(last), rows.num_days() / 7 + 1);
+                }
+                assert_eq!(NaiveDate::weeks_in_month(2024, month, start), Some(last));
+            }
+        }
+
+        assert_eq!(NaiveDate::MAX.week_of_month(Weekday::Mon), 6);
+        assert_eq!(NaiveDate::weeks_in_month(2015, 0, Weekday::Sun), None);
+        assert_eq!(NaiveDate::weeks_in_month(MAX_YEAR + 1, 1, Weekday::Sun), None);
+    }
+
     #[test]
     fn test_naiveweek_min_max() {
         let date_max = NaiveDate::MAX;
@@ -17,8 +17,10 @@ use crate::format::DelayedFormat;
 use crate::format::{parse, parse_and_remainder, ParseError, ParseResult, Parsed, StrftimeItems};
 use crate::format::{Fixed, Item, Numeric, Pad};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveTime};
-use crate::offset::Utc;
-use crate::{DateTime, Datelike, LocalResult, Months, TimeDelta, TimeZone, Timelike, Weekday};
+use crate::offset::{AmbiguityStrategy, FixedOffset, Offset, Utc};
+use crate::{
+    DateTime, Datelike, LocalResult, Months, OverflowPolicy, TimeDelta, TimeZone, Timelike, Weekday,
+};
 
 /// Tools to help serializing/deserializing `NaiveDateTime`s
 #[cfg(feature = "serde")]
@@ -97,6 +99,120 @@ impl NaiveDateTime {
         NaiveDateTime { date, time }
     }
 
+    /// Makes a new `NaiveDateTime` from year, month, day, hour, minute and second.
+    ///
+    /// This is the same as `NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(hour, min,
+    /// sec)` in one call, the naive counterpart of [`TimeZone::with_ymd_and_hms`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if any of the components is invalid, or if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let dt = NaiveDateTime::from_ymd_hms_opt(2015, 6, 3, 12, 34, 56).unwrap();
+    /// assert_eq!(dt, NaiveDate::from_ymd_opt(2015, 6, 3).unwrap().and_hms_opt(12, 34, 56).unwrap());
+    ///
+    /// assert!(NaiveDateTime::from_ymd_hms_opt(2015, 2, 29, 12, 34, 56).is_none());
+    /// assert!(NaiveDateTime::from_ymd_hms_opt(2015, 6, 3, 24, 0, 0).is_none());
+    /// ```
+    ///
+    /// [`TimeZone::with_ymd_and_hms`]: crate::TimeZone::with_ymd_and_hms
+    #[inline]
+    #[must_use]
+    pub fn from_ymd_hms_opt(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(hour, min, sec)
+    }
+
+    /// Makes a new `NaiveDateTime` from year, month, day, hour, minute, second and millisecond.
+    ///
+    /// The millisecond part can exceed 1,000 in order to represent a
+    /// [leap second](./struct.NaiveTime.html#leap-second-handling), as in
+    /// [`NaiveDate::and_hms_milli_opt`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if any of the components is invalid, or if the date is out of range.
+    #[inline]
+    #[must_use]
+    pub fn from_ymd_hms_milli_opt(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        milli: u32,
+    ) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(year, month, day)?.and_hms_milli_opt(hour, min, sec, milli)
+    }
+
+    /// Makes a new `NaiveDateTime` from year, month, day, hour, minute, second and microsecond.
+    ///
+    /// The microsecond part can exceed 1,000,000 in order to represent a
+    /// [leap second](./struct.NaiveTime.html#leap-second-handling), as in
+    /// [`NaiveDate::and_hms_micro_opt`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if any of the components is invalid, or if the date is out of range.
+    #[inline]
+    #[must_use]
+    pub fn from_ymd_hms_micro_opt(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        micro: u32,
+    ) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(year, month, day)?.and_hms_micro_opt(hour, min, sec, micro)
+    }
+
+    /// Makes a new `NaiveDateTime` from year, month, day, hour, minute, second and nanosecond.
+    ///
+    /// The nanosecond part can exceed 1,000,000,000 in order to represent a
+    /// [leap second](./struct.NaiveTime.html#leap-second-handling), as in
+    /// [`NaiveDate::and_hms_nano_opt`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if any of the components is invalid, or if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDateTime, Timelike};
+    ///
+    /// let dt = NaiveDateTime::from_ymd_hms_nano_opt(2016, 12, 31, 23, 59, 59, 1_000_000_000);
+    /// assert_eq!(dt.map(|dt| dt.nanosecond()), Some(1_000_000_000)); // leap second
+    /// assert!(NaiveDateTime::from_ymd_hms_nano_opt(2016, 12, 31, 23, 59, 59, 2_000_000_000).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_ymd_hms_nano_opt(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        nano: u32,
+    ) -> Option<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(year, month, day)?.and_hms_nano_opt(hour, min, sec, nano)
+    }
+
     /// Makes a new `NaiveDateTime` corresponding to a UTC date and time,
     /// from the number of non-leap seconds
     /// since the midnight UTC on January 1, 1970 (aka "UNIX timestamp")
@@ -308,6 +424,29 @@ impl NaiveDateTime {
         parsed.to_naive_datetime_with_offset(0) // no offset adjustment
     }
 
+    /// Parses a string with the specified format string and returns a new `NaiveDateTime`,
+    /// interpreting a two-digit year (`%y` or `%g`) as a year within the 100 years starting at
+    /// `pivot`.
+    ///
+    /// See [`NaiveDate::parse_from_str_with_pivot`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let parse = |s, pivot| NaiveDateTime::parse_from_str_with_pivot(s, "%y-%m-%d %H:%M", pivot);
+    /// let date = |y| NaiveDate::from_ymd_opt(y, 4, 12).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    /// assert_eq!(parse("70-04-12 09:30", 1970), Ok(date(1970)));
+    /// assert_eq!(parse("70-04-12 09:30", 2000), Ok(date(2070)));
+    /// ```
+    pub fn parse_from_str_with_pivot(s: &str, fmt: &str, pivot: i32) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.set_two_digit_year_pivot(pivot)?;
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDateTime`, and a
     /// slice with the remaining portion of the string.
     /// See the [`format::strftime` module](../format/strftime/index.html)
@@ -363,6 +502,56 @@ impl NaiveDateTime {
         self.time
     }
 
+    /// Returns the date and time with the seconds and fractional seconds set to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_milli_opt(9, 10, 11, 12).unwrap();
+    /// assert_eq!(dt.truncate_to_minute(), NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(9, 10, 0).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn truncate_to_minute(self) -> NaiveDateTime {
+        let time = NaiveTime::from_hms_opt(self.time.hour(), self.time.minute(), 0).unwrap();
+        NaiveDateTime { date: self.date, time }
+    }
+
+    /// Returns the date and time with the minutes, seconds and fractional seconds set to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_milli_opt(9, 10, 11, 12).unwrap();
+    /// assert_eq!(dt.truncate_to_hour(), NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(9, 0, 0).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn truncate_to_hour(self) -> NaiveDateTime {
+        let time = NaiveTime::from_hms_opt(self.time.hour(), 0, 0).unwrap();
+        NaiveDateTime { date: self.date, time }
+    }
+
+    /// Returns midnight at the start of the same date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_milli_opt(9, 10, 11, 12).unwrap();
+    /// assert_eq!(dt.truncate_to_day(), NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate_to_day(self) -> NaiveDateTime {
+        NaiveDateTime { date: self.date, time: NaiveTime::MIN }
+    }
+
     /// Returns the number of non-leap seconds since the midnight on January 1, 1970.
     ///
     /// Note that this does *not* account for the timezone!
@@ -659,6 +848,36 @@ impl NaiveDateTime {
         Some(Self { date: self.date.checked_add_months(rhs)?, time: self.time })
     }
 
+    /// Adds given `Months` to the current date and time, with an explicit [`OverflowPolicy`] for
+    /// days that do not exist in the resulting month.
+    ///
+    /// See [`NaiveDate::add_months_with_policy`] for more details on behavior.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the resulting date would be out of range, or if the day does not exist in
+    /// the resulting month and the policy is [`OverflowPolicy::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Months, NaiveDate, OverflowPolicy};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap().and_hms_opt(1, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.add_months_with_policy(Months::new(1), OverflowPolicy::Overflow),
+    ///     Some(NaiveDate::from_ymd_opt(2023, 3, 3).unwrap().and_hms_opt(1, 0, 0).unwrap())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn add_months_with_policy(
+        self,
+        rhs: Months,
+        policy: OverflowPolicy,
+    ) -> Option<NaiveDateTime> {
+        Some(Self { date: self.date.add_months_with_policy(rhs, policy)?, time: self.time })
+    }
+
     /// Subtracts given `TimeDelta` from the current date and time.
     ///
     /// As a part of Chrono's [leap second handling](./struct.NaiveTime.html#leap-second-handling),
@@ -781,6 +1000,27 @@ impl NaiveDateTime {
         Some(Self { date: self.date.checked_sub_days(days)?, ..self })
     }
 
+    /// Shift the date part of the `NaiveDateTime` by a signed number of days.
+    ///
+    /// See [`NaiveDate::shift_days`].
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    #[must_use]
+    pub fn shift_days(self, days: i64) -> Option<Self> {
+        Some(Self { date: self.date.shift_days(days)?, ..self })
+    }
+
+    /// Shift the date part of the `NaiveDateTime` by a signed number of months.
+    ///
+    /// Uses the last day of the month if the day does not exist in the resulting month. See
+    /// [`NaiveDate::shift_months`].
+    ///
+    /// Returns `None` if the resulting date would be out of range.
+    #[must_use]
+    pub fn shift_months(self, months: i32) -> Option<Self> {
+        Some(Self { date: self.date.shift_months(months)?, ..self })
+    }
+
     /// Subtracts another `NaiveDateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     ///
@@ -824,6 +1064,31 @@ impl NaiveDateTime {
         self.date.signed_duration_since(rhs.date) + self.time.signed_duration_since(rhs.time)
     }
 
+    /// Returns the absolute difference between two `NaiveDateTime`s as a non-negative `TimeDelta`.
+    ///
+    /// This never overflows: the difference between [`NaiveDateTime::MIN`] and
+    /// [`NaiveDateTime::MAX`] (about 525,000 years) fits easily in a `TimeDelta`.
+    ///
+    /// Leap seconds are handled like in [`NaiveDateTime::signed_duration_since`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2016, 7, 8).unwrap();
+    /// let a = d.and_hms_opt(3, 5, 7).unwrap();
+    /// let b = d.and_hms_opt(2, 4, 6).unwrap();
+    /// assert_eq!(a.abs_diff(b), TimeDelta::seconds(3600 + 60 + 1));
+    /// assert_eq!(b.abs_diff(a), TimeDelta::seconds(3600 + 60 + 1));
+    ///
+    /// assert!(NaiveDateTime::MIN.abs_diff(NaiveDateTime::MAX) > TimeDelta::days(365 * 500_000));
+    /// ```
+    #[must_use]
+    pub fn abs_diff(self, other: NaiveDateTime) -> TimeDelta {
+        self.signed_duration_since(other).abs()
+    }
+
     /// Formats the combined date and time with the specified formatting items.
     /// Otherwise it is the same as the ordinary [`format`](#method.format) method.
     ///
@@ -928,8 +1193,58 @@ impl NaiveDateTime {
         tz.from_local_datetime(self)
     }
 
+    /// Converts the `NaiveDateTime` into the timezone-aware `DateTime<Tz>`, resolving local times
+    /// that are ambiguous or do not exist with the given [`AmbiguityStrategy`].
+    ///
+    /// Unlike [`NaiveDateTime::and_local_timezone`], this returns at most one result.
+    ///
+    /// With [`AmbiguityStrategy::Compatible`] a local time inside a gap is interpreted with the
+    /// offset in effect before the gap, which moves it forward by the length of the gap. That
+    /// offset is looked up one day before the local time, so this assumes the time zone has no
+    /// other transition within a day of the gap.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if the strategy rejects the local time, or if the result would be out of
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{AmbiguityStrategy, FixedOffset, NaiveDate};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_opt(23, 56, 4).unwrap();
+    /// let zoned = dt.and_local_timezone_with(tz, AmbiguityStrategy::RejectAmbiguous).unwrap();
+    /// assert_eq!(zoned.naive_local(), dt);
+    /// ```
+    #[must_use]
+    pub fn and_local_timezone_with<Tz: TimeZone>(
+        &self,
+        tz: Tz,
+        strategy: AmbiguityStrategy,
+    ) -> Option<DateTime<Tz>> {
+        use AmbiguityStrategy::*;
+
+        match (tz.from_local_datetime(self), strategy) {
+            (LocalResult::Single(dt), _) => Some(dt),
+            (LocalResult::Ambiguous(a, b), Earliest | Compatible) => Some(a.min(b)),
+            (LocalResult::Ambiguous(a, b), Latest) => Some(a.max(b)),
+            (LocalResult::None, Compatible) => {
+                let before_gap = self.checked_sub_signed(TimeDelta::days(1))?;
+                let offset = tz.offset_from_utc_datetime(&before_gap).fix();
+                let offset = TimeDelta::seconds(i64::from(offset.local_minus_utc()));
+                Some(tz.from_utc_datetime(&self.checked_sub_signed(offset)?))
+            }
+            (LocalResult::Ambiguous(..), RejectAmbiguous) | (LocalResult::None, _) => None,
+        }
+    }
+
     /// Converts the `NaiveDateTime` into the timezone-aware `DateTime<Utc>`.
     ///
+    /// The `NaiveDateTime` is interpreted as being in UTC, so this is the inverse of
+    /// [`DateTime::naive_utc`].
+    ///
     /// # Example
     ///
     /// ```
@@ -937,9 +1252,40 @@ impl NaiveDateTime {
     /// let dt = NaiveDate::from_ymd_opt(2023, 1, 30).unwrap().and_hms_opt(19, 32, 33).unwrap().and_utc();
     /// assert_eq!(dt.timezone(), Utc);
     /// ```
+    #[inline]
+    #[must_use]
+    pub const fn and_utc(&self) -> DateTime<Utc> {
+        DateTime::from_naive_utc(*self)
+    }
+
+    /// Converts the `NaiveDateTime` into a `DateTime<FixedOffset>` with the given offset.
+    ///
+    /// The `NaiveDateTime` is interpreted as the local time at that offset, so this is the
+    /// inverse of [`DateTime::naive_local`]. Unlike [`and_local_timezone`] this can't be
+    /// ambiguous, because a fixed offset has no transitions.
+    ///
+    /// [`and_local_timezone`]: #method.and_local_timezone
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `NaiveDateTime` is so close to the limits of its range that the UTC
+    /// datetime would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate};
+    ///
+    /// let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+    /// let ndt = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_opt(23, 56, 4).unwrap();
+    /// let dt = ndt.and_offset(offset);
+    /// assert_eq!(dt.naive_local(), ndt);
+    /// assert_eq!(dt.to_rfc3339(), "2015-09-05T23:56:04+05:00");
+    /// ```
+    #[inline]
     #[must_use]
-    pub fn and_utc(&self) -> DateTime<Utc> {
-        Utc.from_utc_datetime(self)
+    pub fn and_offset(&self, offset: FixedOffset) -> DateTime<FixedOffset> {
+        DateTime::from_local(*self, offset)
     }
 
     /// The minimum possible `NaiveDateTime`.
@@ -1,9 +1,53 @@
 use super::NaiveDateTime;
 use crate::time_delta::TimeDelta;
-use crate::NaiveDate;
-use crate::{Datelike, FixedOffset, Utc};
+use crate::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+use crate::{NaiveDate, NaiveTime};
 use std::i64;
 
+#[test]
+fn test_datetime_from_ymd_hms_opt() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(
+        NaiveDateTime::from_ymd_hms_opt(2015, 6, 3, 12, 34, 56),
+        ymd(2015, 6, 3).and_hms_opt(12, 34, 56)
+    );
+    assert_eq!(
+        NaiveDateTime::from_ymd_hms_milli_opt(2015, 6, 3, 12, 34, 56, 789),
+        ymd(2015, 6, 3).and_hms_milli_opt(12, 34, 56, 789)
+    );
+    assert_eq!(
+        NaiveDateTime::from_ymd_hms_micro_opt(2015, 6, 3, 12, 34, 56, 789_012),
+        ymd(2015, 6, 3).and_hms_micro_opt(12, 34, 56, 789_012)
+    );
+    assert_eq!(
+        NaiveDateTime::from_ymd_hms_nano_opt(2015, 6, 3, 12, 34, 56, 789_012_345),
+        ymd(2015, 6, 3).and_hms_nano_opt(12, 34, 56, 789_012_345)
+    );
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(-262144, 1, 1, 0, 0, 0), Some(NaiveDateTime::MIN));
+    assert_eq!(
+        NaiveDateTime::from_ymd_hms_nano_opt(262143, 12, 31, 23, 59, 59, 999_999_999),
+        Some(NaiveDateTime::MAX)
+    );
+
+    // leap seconds
+    assert!(NaiveDateTime::from_ymd_hms_milli_opt(2016, 12, 31, 23, 59, 59, 1_999).is_some());
+    assert!(NaiveDateTime::from_ymd_hms_micro_opt(2016, 12, 31, 23, 59, 59, 1_999_999).is_some());
+    assert!(NaiveDateTime::from_ymd_hms_milli_opt(2016, 12, 31, 23, 59, 59, 2_000).is_none());
+    assert!(NaiveDateTime::from_ymd_hms_micro_opt(2016, 12, 31, 23, 59, 59, 2_000_000).is_none());
+    assert!(NaiveDateTime::from_ymd_hms_nano_opt(2016, 12, 31, 23, 59, 59, 2_000_000_000).is_none());
+
+    // any invalid component
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(262144, 1, 1, 0, 0, 0), None);
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(2015, 0, 3, 12, 34, 56), None);
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(2015, 13, 3, 12, 34, 56), None);
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(2015, 2, 29, 12, 34, 56), None);
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(2015, 6, 0, 12, 34, 56), None);
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(2015, 6, 3, 24, 34, 56), None);
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(2015, 6, 3, 12, 60, 56), None);
+    assert_eq!(NaiveDateTime::from_ymd_hms_opt(2015, 6, 3, 12, 34, 60), None);
+}
+
 #[test]
 fn test_datetime_from_timestamp_millis() {
     let valid_map = [
@@ -135,6 +179,35 @@ fn test_datetime_add() {
     check((0, 1, 1, 0, 0, 0), TimeDelta::min_value(), None);
 }
 
+#[test]
+fn test_datetime_add_extreme_durations() {
+    let extremes = [
+        TimeDelta::max_value(),
+        TimeDelta::min_value(),
+        TimeDelta::max_value() - TimeDelta::nanoseconds(1),
+        TimeDelta::min_value() + TimeDelta::nanoseconds(1),
+        TimeDelta::seconds(i64::MAX / 1000),
+        TimeDelta::seconds(-i64::MAX / 1000),
+    ];
+    let datetimes = [
+        NaiveDateTime::MIN,
+        NaiveDateTime::MAX,
+        NaiveDate::MIN.and_hms_nano_opt(23, 59, 59, 1_999_999_999).unwrap(),
+        NaiveDate::MAX.and_hms_nano_opt(0, 0, 0, 1).unwrap(),
+        NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_milli_opt(23, 59, 59, 1_500)
+            .unwrap(),
+    ];
+    for dt in datetimes {
+        for rhs in extremes {
+            assert_eq!(dt.checked_add_signed(rhs), None, "{} + {:?}", dt, rhs);
+            assert_eq!(dt.checked_sub_signed(rhs), None, "{} - {:?}", dt, rhs);
+        }
+    }
+}
+
 #[test]
 fn test_datetime_sub() {
     let ymdhms =
@@ -159,6 +232,34 @@ fn test_datetime_sub() {
     );
 }
 
+#[test]
+fn test_datetime_abs_diff() {
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    let a = ymdhms(2014, 5, 6, 7, 8, 9);
+    assert_eq!(a.abs_diff(a), TimeDelta::zero());
+    assert_eq!(a.abs_diff(ymdhms(2014, 5, 6, 7, 8, 10)), TimeDelta::seconds(1));
+    assert_eq!(ymdhms(2014, 5, 6, 7, 8, 10).abs_diff(a), TimeDelta::seconds(1));
+    assert_eq!(
+        ymdhms(1970, 1, 1, 0, 0, 0).abs_diff(ymdhms(2001, 9, 9, 1, 46, 39)),
+        TimeDelta::seconds(999_999_999)
+    );
+
+    // leap seconds
+    let leap =
+        NaiveDate::from_ymd_opt(2015, 6, 30).unwrap().and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    let after = ymdhms(2015, 7, 1, 0, 0, 0);
+    assert_eq!(leap.abs_diff(after), TimeDelta::milliseconds(500));
+    assert_eq!(after.abs_diff(leap), TimeDelta::milliseconds(500));
+
+    // the extremes
+    let span = NaiveDateTime::MAX.signed_duration_since(NaiveDateTime::MIN);
+    assert!(span > TimeDelta::zero());
+    assert_eq!(NaiveDateTime::MIN.abs_diff(NaiveDateTime::MAX), span);
+    assert_eq!(NaiveDateTime::MAX.abs_diff(NaiveDateTime::MIN), span);
+    assert_eq!(NaiveDateTime::MIN.abs_diff(NaiveDateTime::MIN), TimeDelta::zero());
+}
+
 #[test]
 fn test_datetime_addassignment() {
     let ymdhms =
@@ -181,6 +282,38 @@ fn test_datetime_subassignment() {
     assert_eq!(date, ymdhms(1997, 9, 16, 23, 30, 10));
 }
 
+#[test]
+fn test_datetime_shift_days_and_months() {
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    let date = ymdhms(2016, 3, 31, 10, 10, 10);
+    assert_eq!(date.shift_days(-31), Some(ymdhms(2016, 2, 29, 10, 10, 10)));
+    assert_eq!(date.shift_days(1), Some(ymdhms(2016, 4, 1, 10, 10, 10)));
+    assert_eq!(date.shift_months(-1), Some(ymdhms(2016, 2, 29, 10, 10, 10)));
+    assert_eq!(date.shift_months(-13), Some(ymdhms(2015, 2, 28, 10, 10, 10)));
+    assert_eq!(date.shift_months(1), Some(ymdhms(2016, 4, 30, 10, 10, 10)));
+    assert_eq!(NaiveDateTime::MIN.shift_days(-1), None);
+    assert_eq!(NaiveDateTime::MIN.shift_months(-1), None);
+}
+
+#[test]
+fn test_datetime_truncate() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let dt = ymd(2016, 7, 8).and_hms_nano_opt(9, 10, 11, 123_456_789).unwrap();
+    assert_eq!(dt.truncate_to_minute(), ymd(2016, 7, 8).and_hms_opt(9, 10, 0).unwrap());
+    assert_eq!(dt.truncate_to_hour(), ymd(2016, 7, 8).and_hms_opt(9, 0, 0).unwrap());
+    assert_eq!(dt.truncate_to_day(), ymd(2016, 7, 8).and_hms_opt(0, 0, 0).unwrap());
+
+    // leap second
+    let leap = ymd(2016, 12, 31).and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    assert_eq!(leap.truncate_to_minute(), ymd(2016, 12, 31).and_hms_opt(23, 59, 0).unwrap());
+    assert_eq!(leap.truncate_to_hour(), ymd(2016, 12, 31).and_hms_opt(23, 0, 0).unwrap());
+    assert_eq!(leap.truncate_to_day(), ymd(2016, 12, 31).and_hms_opt(0, 0, 0).unwrap());
+
+    assert_eq!(NaiveDateTime::MIN.truncate_to_day(), NaiveDateTime::MIN);
+    assert_eq!(NaiveDateTime::MAX.truncate_to_day(), NaiveDate::MAX.and_hms_opt(0, 0, 0).unwrap());
+}
+
 #[test]
 fn test_datetime_timestamp() {
     let to_timestamp = |y, m, d, h, n, s| {
@@ -326,6 +459,67 @@ fn test_datetime_parse_from_str() {
     );
 }
 
+#[test]
+fn test_datetime_parse_and_remainder() {
+    let dt = NaiveDate::from_ymd_opt(2015, 2, 18).unwrap().and_hms_opt(23, 16, 9).unwrap();
+    let parse = NaiveDateTime::parse_and_remainder;
+
+    assert_eq!(parse("2015-02-18 23:16:09", "%Y-%m-%d %H:%M:%S"), Ok((dt, "")));
+    assert_eq!(
+        parse("2015-02-18 23:16:09 INFO starting up", "%Y-%m-%d %H:%M:%S"),
+        Ok((dt, " INFO starting up"))
+    );
+    assert_eq!(parse("2015-02-18 23:16:0912", "%Y-%m-%d %H:%M:%S"), Ok((dt, "12")));
+    assert_eq!(parse("2015-02-18T23:16:09Z", "%Y-%m-%dT%H:%M:%S"), Ok((dt, "Z")));
+
+    // whitespace in the format string consumes exactly that whitespace in the input
+    assert_eq!(parse("2015-02-18 23:16:09  message", "%Y-%m-%d %H:%M:%S "), Ok((dt, " message")));
+    assert!(parse("2015-02-18 23:16:09", "%Y-%m-%d %H:%M:%S ").is_err());
+    assert!(parse("2015-02-18  23:16:09", "%Y-%m-%d %H:%M:%S").is_err());
+
+    // the parsed prefix must still be a complete and valid date and time
+    assert!(parse("2015-02-18 trailing", "%Y-%m-%d").is_err());
+    assert!(parse("2015-02-30 23:16:09 trailing", "%Y-%m-%d %H:%M:%S").is_err());
+}
+
+#[test]
+fn test_datetime_parse_from_str_rounded_nanosecond() {
+    let ymdhmsn = |y, m, d, h, n, s, nano| {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_nano_opt(h, n, s, nano).unwrap()
+    };
+    let parse = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%#.f");
+    // truncation stays the default
+    assert_eq!(
+        NaiveDateTime::parse_from_str("2023-01-02 03:04:05.1234567895", "%Y-%m-%d %H:%M:%S%.f"),
+        Ok(ymdhmsn(2023, 1, 2, 3, 4, 5, 123_456_789))
+    );
+    assert_eq!(
+        parse("2023-01-02 03:04:05.1234567894"),
+        Ok(ymdhmsn(2023, 1, 2, 3, 4, 5, 123_456_789))
+    );
+    assert_eq!(
+        parse("2023-01-02 03:04:05.1234567895"),
+        Ok(ymdhmsn(2023, 1, 2, 3, 4, 5, 123_456_790))
+    );
+    assert_eq!(parse("2023-01-02 03:04:05.25"), Ok(ymdhmsn(2023, 1, 2, 3, 4, 5, 250_000_000)));
+    assert_eq!(parse("2023-01-02 03:04:05"), Ok(ymdhmsn(2023, 1, 2, 3, 4, 5, 0)));
+    // the carry ripples through to the next day
+    assert_eq!(parse("2023-12-31 23:59:59.9999999995"), Ok(ymdhmsn(2024, 1, 1, 0, 0, 0, 0)));
+    assert_eq!(
+        parse("2023-12-31 23:59:59.9999999994"),
+        Ok(ymdhmsn(2023, 12, 31, 23, 59, 59, 999_999_999))
+    );
+    assert_eq!(
+        NaiveDateTime::parse_from_str("1441497364.9999999995", "%s%#.f"),
+        Ok(ymdhmsn(2015, 9, 5, 23, 56, 5, 0))
+    );
+    assert_eq!(
+        NaiveTime::parse_from_str("23:59:59.9999999995", "%H:%M:%S%#.f"),
+        Ok(NaiveTime::from_hms_opt(0, 0, 0).unwrap())
+    );
+    assert!(parse("+262143-12-31 23:59:59.9999999995").is_err());
+}
+
 #[test]
 fn test_datetime_format() {
     let dt = NaiveDate::from_ymd_opt(2010, 9, 8).unwrap().and_hms_milli_opt(7, 6, 54, 321).unwrap();
@@ -406,4 +600,33 @@ fn test_and_utc() {
     let dt_utc = ndt.and_utc();
     assert_eq!(dt_utc.naive_local(), ndt);
     assert_eq!(dt_utc.timezone(), Utc);
+    assert_eq!(dt_utc, Utc.from_utc_datetime(&ndt));
+
+    const MAX_UTC: DateTime<Utc> = NaiveDateTime::MAX.and_utc();
+    assert_eq!(MAX_UTC, DateTime::<Utc>::MAX_UTC);
+}
+
+#[test]
+fn test_and_offset() {
+    let ndt = NaiveDate::from_ymd_opt(2023, 1, 30).unwrap().and_hms_opt(19, 32, 33).unwrap();
+    for secs in [0, 5 * 3600 + 30 * 60, -8 * 3600, 86_399, -86_399] {
+        let offset = FixedOffset::east_opt(secs).unwrap();
+        let dt = ndt.and_offset(offset);
+        assert_eq!(dt.naive_local(), ndt);
+        assert_eq!(dt.offset(), &offset);
+        assert_eq!(Some(dt), ndt.and_local_timezone(offset).single());
+        assert_eq!(dt.naive_utc(), ndt - offset);
+    }
+    assert_eq!(ndt.and_offset(FixedOffset::ZERO), ndt.and_utc());
+
+    // leap seconds are kept
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_500);
+    let leap = leap.unwrap().and_offset(FixedOffset::east_opt(3600).unwrap());
+    assert_eq!(leap.to_rfc3339(), "2016-12-31T23:59:60.500+01:00");
+}
+
+#[test]
+#[should_panic]
+fn test_and_offset_out_of_range() {
+    let _ = NaiveDateTime::MIN.and_offset(FixedOffset::east_opt(3600).unwrap());
 }
@@ -6,6 +6,8 @@
 use core::fmt;
 
 use super::internals::{DateImpl, Of, YearFlags};
+use super::NaiveDate;
+use crate::Weekday;
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -109,6 +111,72 @@ impl IsoWeek {
     pub const fn week0(&self) -> u32 {
         ((self.ywf >> 4) & 0x3f) as u32 - 1
     }
+
+    /// Returns the first day (the Monday) of this ISO week.
+    ///
+    /// This date might not be in the same calendar year as the ISO week year.
+    ///
+    /// Returns `None` if the first day of the week happens to fall just out of range of
+    /// `NaiveDate`, which is the case for the ISO week containing [`NaiveDate::MIN`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2015, 1, 1).unwrap().iso_week();
+    /// assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2014, 12, 29));
+    /// assert_eq!(NaiveDate::MIN.iso_week().first_day(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(self.year(), self.week(), Weekday::Mon)
+    }
+
+    /// Returns the last day (the Sunday) of this ISO week.
+    ///
+    /// This date might not be in the same calendar year as the ISO week year.
+    ///
+    /// Returns `None` if the last day of the week happens to fall just out of range of
+    /// `NaiveDate`, which is the case for the ISO week containing [`NaiveDate::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap().iso_week();
+    /// assert_eq!(week.last_day(), NaiveDate::from_ymd_opt(2027, 1, 3));
+    /// assert_eq!(NaiveDate::MAX.iso_week().last_day(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(self.year(), self.week(), Weekday::Sun)
+    }
+
+    /// Returns an iterator over the seven days of this ISO week, from Monday to Sunday.
+    ///
+    /// Days before [`NaiveDate::MIN`] or after [`NaiveDate::MAX`] are skipped, so the first and
+    /// the last ISO week yield fewer than seven days.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// let week = NaiveDate::from_ymd_opt(2015, 1, 1).unwrap().iso_week();
+    /// let mut days = week.days();
+    /// assert_eq!(days.next(), Some(NaiveDate::from_ymd_opt(2014, 12, 29).unwrap()));
+    /// assert_eq!(days.last(), Some(NaiveDate::from_ymd_opt(2015, 1, 4).unwrap()));
+    /// assert!(week.days().all(|d| d.iso_week() == week));
+    /// ```
+    #[inline]
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let (year, week) = (self.year(), self.week());
+        Weekday::ALL.into_iter().filter_map(move |day| NaiveDate::from_isoywd_opt(year, week, day))
+    }
 }
 
 /// The `Debug` output of the ISO week `w` is the same as
@@ -147,8 +215,8 @@ impl fmt::Debug for IsoWeek {
 
 #[cfg(test)]
 mod tests {
-    use crate::naive::{internals, NaiveDate};
-    use crate::Datelike;
+    use crate::naive::{internals, Days, NaiveDate};
+    use crate::{Datelike, Weekday};
 
     #[test]
     fn test_iso_week_extremes() {
@@ -166,6 +234,86 @@ mod tests {
         assert_eq!(format!("{:?}", maxweek), NaiveDate::MAX.format("%G-W%V").to_string());
     }
 
+    #[test]
+    fn test_iso_week_first_and_last_day() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // week 1 starting in the previous calendar year
+        let week = ymd(2025, 1, 1).iso_week();
+        assert_eq!((week.year(), week.week()), (2025, 1));
+        assert_eq!(week.first_day().unwrap(), ymd(2024, 12, 30));
+        assert_eq!(week.last_day().unwrap(), ymd(2025, 1, 5));
+
+        // week 53 ending in the next calendar year
+        let week = ymd(2026, 12, 31).iso_week();
+        assert_eq!((week.year(), week.week()), (2026, 53));
+        assert_eq!(week.first_day().unwrap(), ymd(2026, 12, 28));
+        assert_eq!(week.last_day().unwrap(), ymd(2027, 1, 3));
+
+        // days in the previous ISO week year
+        let week = ymd(2021, 1, 3).iso_week();
+        assert_eq!((week.year(), week.week()), (2020, 53));
+        assert_eq!(week.first_day().unwrap(), ymd(2020, 12, 28));
+        assert_eq!(week.last_day().unwrap(), ymd(2021, 1, 3));
+
+        // week fully within a calendar year
+        let week = ymd(2023, 6, 15).iso_week();
+        assert_eq!(week.first_day().unwrap(), ymd(2023, 6, 12));
+        assert_eq!(week.last_day().unwrap(), ymd(2023, 6, 18));
+
+        for date in ymd(2020, 12, 1).iter_days().take(800) {
+            let week = date.iso_week();
+            assert_eq!(week.first_day().unwrap().weekday(), Weekday::Mon);
+            assert_eq!(week.last_day().unwrap().weekday(), Weekday::Sun);
+            assert!(week.first_day().unwrap() <= date && date <= week.last_day().unwrap());
+            assert_eq!(week.first_day().unwrap().iso_week(), week);
+            assert_eq!(week.last_day().unwrap().iso_week(), week);
+        }
+    }
+
+    #[test]
+    fn test_iso_week_days() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let week = ymd(2025, 1, 1).iso_week();
+        let days: Vec<_> = week.days().collect();
+        assert_eq!(
+            days,
+            [
+                ymd(2024, 12, 30),
+                ymd(2024, 12, 31),
+                ymd(2025, 1, 1),
+                ymd(2025, 1, 2),
+                ymd(2025, 1, 3),
+                ymd(2025, 1, 4),
+                ymd(2025, 1, 5),
+            ]
+        );
+
+        let maxweek = NaiveDate::MAX.iso_week();
+        assert_eq!(maxweek.days().last(), Some(NaiveDate::MAX));
+        assert!(maxweek.days().count() < 7);
+
+        // `NaiveDate::MIN` is a Tuesday, so the Monday of its week is out of range
+        let minweek = NaiveDate::MIN.iso_week();
+        assert_eq!(NaiveDate::MIN.weekday(), Weekday::Tue);
+        assert_eq!(minweek.days().next(), Some(NaiveDate::MIN));
+        assert_eq!(minweek.days().count(), 6);
+        assert!(minweek.days().all(|d| d.iso_week() == minweek));
+    }
+
+    #[test]
+    fn test_iso_week_first_and_last_day_extremes() {
+        let minweek = NaiveDate::MIN.iso_week();
+        assert_eq!(minweek.first_day(), None);
+        assert_eq!(minweek.last_day(), NaiveDate::MIN.checked_add_days(Days::new(5)));
+
+        let maxweek = NaiveDate::MAX.iso_week();
+        assert_eq!(maxweek.first_day().map(|d| d.weekday()), Some(Weekday::Mon));
+        assert!(maxweek.first_day().unwrap() <= NaiveDate::MAX);
+        assert_eq!(maxweek.last_day(), None);
+    }
+
     #[test]
     fn test_iso_week_equivalence_for_first_week() {
         let monday = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
@@ -10,7 +10,10 @@ mod internals;
 mod isoweek;
 mod time;
 
-pub use self::date::{Days, NaiveDate, NaiveDateDaysIterator, NaiveDateWeeksIterator, NaiveWeek};
+pub use self::date::{
+    is_leap_year, ClampedError, Days, Era, NaiveDate, NaiveDateDaysIterator,
+    NaiveDateWeeksIterator, NaiveWeek,
+};
 #[allow(deprecated)]
 pub use self::date::{MAX_DATE, MIN_DATE};
 #[allow(deprecated)]
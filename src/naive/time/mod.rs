@@ -17,7 +17,7 @@ use crate::format::{
     parse, parse_and_remainder, write_hundreds, Fixed, Item, Numeric, Pad, ParseError, ParseResult,
     Parsed, StrftimeItems,
 };
-use crate::{TimeDelta, Timelike};
+use crate::{FixedOffset, RoundMode, TimeDelta, Timelike};
 
 #[cfg(feature = "serde")]
 mod serde;
@@ -387,6 +387,31 @@ impl NaiveTime {
         Some(NaiveTime { secs, frac: nano })
     }
 
+    /// Makes a new `NaiveTime` from an hour, minute, second and nanosecond tuple.
+    ///
+    /// This is the inverse of [`NaiveTime::as_hmsn`] and accepts the same values as
+    /// [`NaiveTime::from_hms_nano_opt`]: a [leap second](#leap-second-handling) is represented
+    /// by a nanosecond value of 1,000,000,000 or more on the preceding second.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` on invalid hour, minute, second and/or nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hmsn(23, 59, 59, 1_500_000_000).unwrap(); // during a leap second
+    /// assert_eq!(t.as_hmsn(), (23, 59, 59, 1_500_000_000));
+    /// assert!(NaiveTime::from_hmsn(23, 59, 60, 0).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_hmsn(hour: u32, min: u32, sec: u32, nano: u32) -> Option<NaiveTime> {
+        NaiveTime::from_hms_nano_opt(hour, min, sec, nano)
+    }
+
     /// Makes a new `NaiveTime` from the number of seconds since midnight and nanosecond.
     ///
     /// The nanosecond part can exceed 1,000,000,000
@@ -433,6 +458,41 @@ impl NaiveTime {
         Some(NaiveTime { secs, frac: nano })
     }
 
+    /// Makes a new `NaiveTime` from the number of nanoseconds since midnight.
+    ///
+    /// This is the representation of columnar time types such as Arrow's `Time64(Nanosecond)`.
+    /// A plain count of nanoseconds can not express a [leap second](#leap-second-handling), so
+    /// values of 86,400,000,000,000 and more, which would fall into a leap second at the end of
+    /// the day, are rejected rather than mapped onto `23:59:59` with a nanosecond of 1,000,000,000
+    /// or more. Use [`NaiveTime::from_num_seconds_from_midnight_opt`] to construct a leap second.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `nanos` is not less than 86,400,000,000,000.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let from_nanos = NaiveTime::from_num_nanoseconds_from_midnight_opt;
+    ///
+    /// assert_eq!(from_nanos(0), Some(NaiveTime::MIN));
+    /// assert_eq!(from_nanos(45_296_789_012_345), NaiveTime::from_hms_nano_opt(12, 34, 56, 789_012_345));
+    /// assert_eq!(from_nanos(86_399_999_999_999), NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999));
+    /// assert_eq!(from_nanos(86_400_000_000_000), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_num_nanoseconds_from_midnight_opt(nanos: u64) -> Option<NaiveTime> {
+        if nanos >= 86_400 * 1_000_000_000 {
+            return None;
+        }
+        let secs = (nanos / 1_000_000_000) as u32;
+        let frac = (nanos % 1_000_000_000) as u32;
+        Some(NaiveTime { secs, frac })
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveTime`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -522,6 +582,63 @@ impl NaiveTime {
         parsed.to_naive_time().map(|t| (t, remainder))
     }
 
+    /// Rounds the time to a multiple of `interval`, counted from midnight.
+    ///
+    /// The `interval` does not have to divide a day evenly. In that case the last bucket of the
+    /// day is shorter: with a 7-minute interval the multiples are 00:00, 00:07, ..., 23:48 and
+    /// 23:55, followed by the midnight of the next day. Rounding to that midnight wraps around to
+    /// 00:00:00, and so does rounding with an interval of a day or more.
+    ///
+    /// A [leap second](#leap-second-handling) is rounded like the last nanosecond before
+    /// midnight, 23:59:59.999999999, so the result is never a leap second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, RoundMode, TimeDelta};
+    ///
+    /// let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    /// let quarter = TimeDelta::minutes(15);
+    ///
+    /// assert_eq!(hms(10, 22, 30).round_to_multiple(quarter, RoundMode::Nearest), hms(10, 30, 0));
+    /// assert_eq!(hms(10, 22, 30).round_to_multiple(quarter, RoundMode::Down), hms(10, 15, 0));
+    /// assert_eq!(hms(10, 15, 1).round_to_multiple(quarter, RoundMode::Up), hms(10, 30, 0));
+    /// assert_eq!(hms(23, 50, 0).round_to_multiple(quarter, RoundMode::Up), hms(0, 0, 0));
+    ///
+    /// // 7 minutes do not divide a day evenly
+    /// let seven = TimeDelta::minutes(7);
+    /// assert_eq!(hms(23, 56, 0).round_to_multiple(seven, RoundMode::Down), hms(23, 55, 0));
+    /// assert_eq!(hms(23, 58, 0).round_to_multiple(seven, RoundMode::Nearest), hms(0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn round_to_multiple(self, interval: TimeDelta, mode: RoundMode) -> NaiveTime {
+        const NANOS_PER_SEC: i64 = 1_000_000_000;
+        const NANOS_PER_DAY: i64 = 86_400 * NANOS_PER_SEC;
+
+        assert!(interval > TimeDelta::zero(), "`interval` must be positive");
+        // anything longer than a day gives the same result
+        let interval = interval.num_nanoseconds().unwrap_or(i64::MAX).min(NANOS_PER_DAY);
+
+        let nanos = i64::from(self.secs) * NANOS_PER_SEC + i64::from(self.frac);
+        let nanos = nanos.min(NANOS_PER_DAY - 1);
+        let down = nanos / interval * interval;
+        // the last bucket of the day ends at midnight
+        let up = if down == nanos { down } else { (down + interval).min(NANOS_PER_DAY) };
+        let rounded = match mode {
+            RoundMode::Down => down,
+            RoundMode::Up => up,
+            RoundMode::Nearest if nanos - down < up - nanos => down,
+            RoundMode::Nearest => up,
+        };
+
+        let rounded = rounded % NANOS_PER_DAY;
+        NaiveTime { secs: (rounded / NANOS_PER_SEC) as u32, frac: (rounded % NANOS_PER_SEC) as u32 }
+    }
+
     /// Adds given `TimeDelta` to the current time, and also returns the number of *seconds*
     /// in the integral number of days ignored from the addition.
     ///
@@ -627,6 +744,101 @@ impl NaiveTime {
         (time, -rhs) // safe to negate, rhs is within +/- (2^63 / 1000)
     }
 
+    /// Adds given `TimeDelta` to the current time, wrapping around midnight.
+    ///
+    /// The whole days that the addition rolls over are dropped, so this is pure time-of-day
+    /// arithmetic: 3 hours after 23:00 is 02:00. Use
+    /// [`overflowing_add_signed`](#method.overflowing_add_signed) to also get the number of
+    /// seconds in the dropped days, or add to a [`NaiveDateTime`](crate::NaiveDateTime) to keep
+    /// them. This is the same as `self + rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, TimeDelta};
+    ///
+    /// let from_hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    ///
+    /// assert_eq!(from_hms(23, 0, 0).wrapping_add(TimeDelta::hours(3)), from_hms(2, 0, 0));
+    /// assert_eq!(from_hms(1, 0, 0).wrapping_add(TimeDelta::hours(-3)), from_hms(22, 0, 0));
+    /// assert_eq!(from_hms(1, 0, 0).wrapping_add(TimeDelta::days(5)), from_hms(1, 0, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add(self, rhs: TimeDelta) -> NaiveTime {
+        self.overflowing_add_signed(rhs).0
+    }
+
+    /// Subtracts given `TimeDelta` from the current time, wrapping around midnight.
+    ///
+    /// Like [`wrapping_add`](#method.wrapping_add), the whole days that the subtraction rolls
+    /// over are dropped: 3 hours before 01:00 is 22:00. This is the same as `self - rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveTime, TimeDelta};
+    ///
+    /// let from_hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    ///
+    /// assert_eq!(from_hms(1, 0, 0).wrapping_sub(TimeDelta::hours(3)), from_hms(22, 0, 0));
+    /// assert_eq!(from_hms(23, 0, 0).wrapping_sub(TimeDelta::hours(-3)), from_hms(2, 0, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn wrapping_sub(self, rhs: TimeDelta) -> NaiveTime {
+        self.overflowing_sub_signed(rhs).0
+    }
+
+    /// Adds given `FixedOffset` to the current time, and also returns the number of *days* the
+    /// clock rolled over (`-1`, `0` or `1`, as an offset is always less than 24 hours).
+    ///
+    /// This is the same as `self + offset`, so a leap second is kept as a leap second
+    /// of the shifted time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveTime};
+    ///
+    /// let from_hms = |h, m, s| { NaiveTime::from_hms_opt(h, m, s).unwrap() };
+    /// let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    ///
+    /// assert_eq!(from_hms(3, 4, 5).overflowing_add_offset(offset), (from_hms(8, 34, 5), 0));
+    /// assert_eq!(from_hms(20, 4, 5).overflowing_add_offset(offset), (from_hms(1, 34, 5), 1));
+    /// assert_eq!(from_hms(3, 4, 5).overflowing_add_offset(-offset), (from_hms(21, 34, 5), -1));
+    /// ```
+    #[must_use]
+    pub fn overflowing_add_offset(&self, offset: FixedOffset) -> (NaiveTime, i64) {
+        let secs = self.secs as i32 + offset.local_minus_utc();
+        let days = secs.div_euclid(86_400);
+        let secs = secs.rem_euclid(86_400) as u32;
+        (NaiveTime { secs, frac: self.frac }, i64::from(days))
+    }
+
+    /// Subtracts given `FixedOffset` from the current time, and also returns the number of *days*
+    /// the clock rolled over (`-1`, `0` or `1`, as an offset is always less than 24 hours).
+    ///
+    /// This is the same as `self - offset`, so a leap second is kept as a leap second
+    /// of the shifted time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveTime};
+    ///
+    /// let from_hms = |h, m, s| { NaiveTime::from_hms_opt(h, m, s).unwrap() };
+    /// let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    ///
+    /// assert_eq!(from_hms(8, 34, 5).overflowing_sub_offset(offset), (from_hms(3, 4, 5), 0));
+    /// assert_eq!(from_hms(3, 4, 5).overflowing_sub_offset(offset), (from_hms(21, 34, 5), -1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_sub_offset(&self, offset: FixedOffset) -> (NaiveTime, i64) {
+        self.overflowing_add_offset(-offset)
+    }
+
     /// Subtracts another `NaiveTime` from the current time.
     /// Returns a `TimeDelta` within +/- 1 day.
     /// This does not overflow or underflow at all.
@@ -795,6 +1007,29 @@ impl NaiveTime {
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Decomposes this time into an hour, minute, second and nanosecond tuple.
+    ///
+    /// A [leap second](#leap-second-handling) is not reported as second 60, but as a nanosecond
+    /// value of 1,000,000,000 or more on the preceding second, so the tuple can always be fed
+    /// back into [`NaiveTime::from_hmsn`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_nano_opt(12, 34, 56, 789_012_345).unwrap();
+    /// assert_eq!(t.as_hmsn(), (12, 34, 56, 789_012_345));
+    ///
+    /// let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_250).unwrap();
+    /// assert_eq!(leap.as_hmsn(), (23, 59, 59, 1_250_000_000));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_hmsn(&self) -> (u32, u32, u32, u32) {
+        (self.secs / 3600, self.secs / 60 % 60, self.secs % 60, self.frac)
+    }
+
     /// Returns a triple of the hour, minute and second numbers.
     fn hms(&self) -> (u32, u32, u32) {
         let sec = self.secs % 60;
@@ -1,7 +1,7 @@
 use std::u32;
 
 use super::NaiveTime;
-use crate::{TimeDelta, Timelike};
+use crate::{FixedOffset, RoundMode, TimeDelta, Timelike};
 
 #[test]
 fn test_time_from_hms_milli() {
@@ -84,6 +84,46 @@ fn test_time_hms() {
     assert_eq!(NaiveTime::from_hms_opt(3, 5, 7).unwrap().with_second(u32::MAX), None);
 }
 
+#[test]
+fn test_time_hmsn_round_trip() {
+    for &(h, m, s, n) in &[
+        (0, 0, 0, 0),
+        (3, 5, 7, 11),
+        (12, 34, 56, 789_012_345),
+        (23, 59, 59, 999_999_999),
+        (23, 59, 59, 1_000_000_000), // start of a leap second
+        (23, 59, 59, 1_999_999_999), // end of a leap second
+        (8, 59, 59, 1_500_000_000),  // leap seconds are allowed on any second
+    ] {
+        let t = NaiveTime::from_hmsn(h, m, s, n).unwrap();
+        assert_eq!(t, NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap());
+        assert_eq!(t.as_hmsn(), (h, m, s, n));
+    }
+
+    assert_eq!(NaiveTime::from_hmsn(24, 0, 0, 0), None);
+    assert_eq!(NaiveTime::from_hmsn(23, 60, 0, 0), None);
+    assert_eq!(NaiveTime::from_hmsn(23, 59, 60, 0), None);
+    assert_eq!(NaiveTime::from_hmsn(23, 59, 59, 2_000_000_000), None);
+    assert_eq!(NaiveTime::MIN.as_hmsn(), (0, 0, 0, 0));
+}
+
+#[test]
+fn test_time_from_num_nanoseconds_from_midnight() {
+    let from_nanos = NaiveTime::from_num_nanoseconds_from_midnight_opt;
+    for &(h, m, s, n) in
+        &[(0, 0, 0, 0), (0, 0, 0, 1), (3, 5, 7, 999_999_999), (23, 59, 59, 999_999_999)]
+    {
+        let t = NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+        let nanos = u64::from(t.num_seconds_from_midnight()) * 1_000_000_000 + u64::from(n);
+        assert_eq!(from_nanos(nanos), Some(t));
+    }
+
+    // the leap second range and beyond
+    assert_eq!(from_nanos(86_400_000_000_000), None);
+    assert_eq!(from_nanos(86_400_999_999_999), None);
+    assert_eq!(from_nanos(u64::MAX), None);
+}
+
 #[test]
 fn test_time_add() {
     macro_rules! check {
@@ -114,6 +154,147 @@ fn test_time_add() {
     check!(hmsm(0, 0, 0, 0), TimeDelta::milliseconds(-9990), hmsm(23, 59, 50, 10));
 }
 
+#[test]
+fn test_time_overflowing_add_offset() {
+    let hmsm = |h, m, s, ms| NaiveTime::from_hms_milli_opt(h, m, s, ms).unwrap();
+    let east = |secs| FixedOffset::east_opt(secs).unwrap();
+    let west = |secs| FixedOffset::west_opt(secs).unwrap();
+
+    assert_eq!(hmsm(3, 4, 5, 678).overflowing_add_offset(east(0)), (hmsm(3, 4, 5, 678), 0));
+    assert_eq!(hmsm(3, 4, 5, 678).overflowing_add_offset(east(3600)), (hmsm(4, 4, 5, 678), 0));
+    assert_eq!(hmsm(23, 4, 5, 678).overflowing_add_offset(east(3600)), (hmsm(0, 4, 5, 678), 1));
+    assert_eq!(hmsm(0, 4, 5, 678).overflowing_add_offset(west(3600)), (hmsm(23, 4, 5, 678), -1));
+    assert_eq!(hmsm(23, 59, 59, 999).overflowing_add_offset(east(1)), (hmsm(0, 0, 0, 999), 1));
+    assert_eq!(hmsm(0, 0, 0, 0).overflowing_add_offset(west(1)), (hmsm(23, 59, 59, 0), -1));
+
+    // offsets of almost 24 hours
+    assert_eq!(hmsm(0, 0, 0, 0).overflowing_add_offset(east(86_399)), (hmsm(23, 59, 59, 0), 0));
+    assert_eq!(hmsm(0, 0, 1, 0).overflowing_add_offset(east(86_399)), (hmsm(0, 0, 0, 0), 1));
+    assert_eq!(hmsm(23, 59, 59, 0).overflowing_add_offset(west(86_399)), (hmsm(0, 0, 0, 0), 0));
+    assert_eq!(hmsm(23, 59, 58, 0).overflowing_add_offset(west(86_399)), (hmsm(23, 59, 59, 0), -1));
+
+    // leap seconds are kept, and carry like the second they are attached to
+    assert_eq!(
+        hmsm(23, 59, 59, 1_500).overflowing_add_offset(east(0)),
+        (hmsm(23, 59, 59, 1_500), 0)
+    );
+    assert_eq!(
+        hmsm(23, 59, 59, 1_500).overflowing_add_offset(west(3600)),
+        (hmsm(22, 59, 59, 1_500), 0)
+    );
+    assert_eq!(
+        hmsm(23, 59, 59, 1_500).overflowing_add_offset(east(3600)),
+        (hmsm(0, 59, 59, 1_500), 1)
+    );
+    for offset in [east(3600), west(3600), east(1), west(86_399)] {
+        let time = hmsm(23, 59, 59, 1_500);
+        assert_eq!(time.overflowing_add_offset(offset).0, time + offset);
+        assert_eq!(time.overflowing_sub_offset(offset).0, time - offset);
+    }
+
+    assert_eq!(hmsm(0, 4, 5, 678).overflowing_sub_offset(east(3600)), (hmsm(23, 4, 5, 678), -1));
+    assert_eq!(hmsm(23, 4, 5, 678).overflowing_sub_offset(west(3600)), (hmsm(0, 4, 5, 678), 1));
+    assert_eq!(hmsm(12, 0, 0, 0).overflowing_sub_offset(west(3600)), (hmsm(13, 0, 0, 0), 0));
+}
+
+#[test]
+fn test_time_round_to_multiple() {
+    use RoundMode::*;
+
+    let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    let hmsn = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+    let round = |t: NaiveTime, interval, mode| t.round_to_multiple(interval, mode);
+
+    let quarter = TimeDelta::minutes(15);
+    for mode in [Nearest, Down, Up] {
+        assert_eq!(round(hms(0, 0, 0), quarter, mode), hms(0, 0, 0));
+        assert_eq!(round(hms(10, 45, 0), quarter, mode), hms(10, 45, 0));
+    }
+    assert_eq!(round(hms(10, 52, 29), quarter, Nearest), hms(10, 45, 0));
+    assert_eq!(round(hms(10, 52, 30), quarter, Nearest), hms(11, 0, 0)); // half-up
+    assert_eq!(round(hms(10, 59, 59), quarter, Down), hms(10, 45, 0));
+    assert_eq!(round(hmsn(10, 45, 0, 1), quarter, Up), hms(11, 0, 0));
+    assert_eq!(round(hms(23, 52, 30), quarter, Nearest), hms(0, 0, 0));
+    assert_eq!(round(hms(23, 45, 1), quarter, Up), hms(0, 0, 0));
+
+    let ninety_secs = TimeDelta::seconds(90);
+    assert_eq!(round(hms(0, 2, 59), ninety_secs, Down), hms(0, 1, 30));
+    assert_eq!(round(hms(0, 2, 59), ninety_secs, Up), hms(0, 3, 0));
+    assert_eq!(round(hms(0, 2, 14), ninety_secs, Nearest), hms(0, 1, 30));
+
+    // 1440 minutes are not a multiple of 7 minutes: the last bucket of the day is 5 minutes
+    let seven = TimeDelta::minutes(7);
+    assert_eq!(round(hms(0, 10, 0), seven, Down), hms(0, 7, 0));
+    assert_eq!(round(hms(0, 10, 0), seven, Up), hms(0, 14, 0));
+    assert_eq!(round(hms(0, 10, 30), seven, Nearest), hms(0, 14, 0));
+    assert_eq!(round(hms(12, 0, 0), seven, Down), hms(11, 54, 0)); // 714 minutes
+    assert_eq!(round(hms(23, 55, 0), seven, Up), hms(23, 55, 0));
+    assert_eq!(round(hms(23, 59, 59), seven, Down), hms(23, 55, 0));
+    assert_eq!(round(hms(23, 57, 29), seven, Nearest), hms(23, 55, 0));
+    assert_eq!(round(hms(23, 57, 30), seven, Nearest), hms(0, 0, 0));
+    assert_eq!(round(hms(23, 55, 1), seven, Up), hms(0, 0, 0));
+
+    // intervals of a day or more
+    for interval in [TimeDelta::days(1), TimeDelta::days(3), TimeDelta::max_value()] {
+        assert_eq!(round(hms(11, 59, 59), interval, Nearest), hms(0, 0, 0));
+        assert_eq!(round(hms(12, 0, 0), interval, Nearest), hms(0, 0, 0));
+        assert_eq!(round(hms(23, 0, 0), interval, Down), hms(0, 0, 0));
+        assert_eq!(round(hms(0, 0, 1), interval, Up), hms(0, 0, 0));
+    }
+
+    // leap seconds
+    let leap = hmsn(23, 59, 59, 1_600_000_000);
+    assert_eq!(round(leap, TimeDelta::seconds(1), Down), hms(23, 59, 59));
+    assert_eq!(round(leap, TimeDelta::seconds(1), Up), hms(0, 0, 0));
+    assert_eq!(round(leap, TimeDelta::seconds(1), Nearest), hms(0, 0, 0));
+    assert_eq!(round(leap, TimeDelta::nanoseconds(1), Nearest), hmsn(23, 59, 59, 999_999_999));
+    assert_eq!(round(leap, quarter, Down), hms(23, 45, 0));
+    assert_eq!(round(leap, quarter, Nearest), hms(0, 0, 0));
+    assert_eq!(round(leap, TimeDelta::days(1), Up), hms(0, 0, 0));
+}
+
+#[test]
+#[should_panic]
+fn test_time_round_to_multiple_zero_interval() {
+    let _ = NaiveTime::MIN.round_to_multiple(TimeDelta::zero(), RoundMode::Nearest);
+}
+
+#[test]
+fn test_time_wrapping_add_sub() {
+    let hmsm = |h, m, s, ms| NaiveTime::from_hms_milli_opt(h, m, s, ms).unwrap();
+
+    // forward past midnight
+    assert_eq!(hmsm(23, 0, 0, 0).wrapping_add(TimeDelta::hours(3)), hmsm(2, 0, 0, 0));
+    assert_eq!(hmsm(23, 59, 59, 999).wrapping_add(TimeDelta::milliseconds(1)), hmsm(0, 0, 0, 0));
+    assert_eq!(hmsm(1, 0, 0, 0).wrapping_sub(TimeDelta::hours(-23)), hmsm(0, 0, 0, 0));
+    // backward past midnight
+    assert_eq!(hmsm(1, 0, 0, 0).wrapping_sub(TimeDelta::hours(3)), hmsm(22, 0, 0, 0));
+    assert_eq!(hmsm(0, 0, 0, 0).wrapping_add(TimeDelta::milliseconds(-1)), hmsm(23, 59, 59, 999));
+    assert_eq!(hmsm(2, 0, 0, 0).wrapping_add(TimeDelta::hours(-3)), hmsm(23, 0, 0, 0));
+    // within the day
+    assert_eq!(hmsm(3, 4, 5, 678).wrapping_add(TimeDelta::hours(11)), hmsm(14, 4, 5, 678));
+    assert_eq!(hmsm(14, 4, 5, 678).wrapping_sub(TimeDelta::hours(11)), hmsm(3, 4, 5, 678));
+    // whole days are dropped
+    assert_eq!(hmsm(3, 4, 5, 678).wrapping_add(TimeDelta::days(7)), hmsm(3, 4, 5, 678));
+    assert_eq!(hmsm(3, 4, 5, 678).wrapping_sub(TimeDelta::days(7)), hmsm(3, 4, 5, 678));
+    assert_eq!(
+        hmsm(3, 4, 5, 678).wrapping_add(TimeDelta::days(-3) + TimeDelta::hours(-4)),
+        hmsm(23, 4, 5, 678)
+    );
+    assert_eq!(hmsm(0, 0, 0, 0).wrapping_add(TimeDelta::max_value()), hmsm(7, 12, 55, 807));
+    assert_eq!(hmsm(0, 0, 0, 0).wrapping_sub(TimeDelta::min_value()), hmsm(7, 12, 55, 808));
+    // a leap second
+    assert_eq!(hmsm(23, 59, 59, 1_500).wrapping_add(TimeDelta::seconds(1)), hmsm(0, 0, 0, 500));
+    assert_eq!(hmsm(23, 59, 59, 1_500).wrapping_add(TimeDelta::days(1)), hmsm(23, 59, 59, 500));
+
+    // the same as the operators
+    for rhs in [TimeDelta::hours(25), TimeDelta::minutes(-90), TimeDelta::nanoseconds(1)] {
+        let time = hmsm(23, 30, 0, 0);
+        assert_eq!(time.wrapping_add(rhs), time + rhs);
+        assert_eq!(time.wrapping_sub(rhs), time - rhs);
+    }
+}
+
 #[test]
 fn test_time_overflowing_add() {
     let hmsm = |h, m, s, ms| NaiveTime::from_hms_milli_opt(h, m, s, ms).unwrap();
@@ -140,6 +321,24 @@ fn test_time_overflowing_add() {
         hmsm(3, 4, 5, 1_678).overflowing_add_signed(TimeDelta::days(-1)),
         (hmsm(3, 4, 6, 678), -86_400)
     );
+
+    // the extreme durations do not overflow
+    assert_eq!(
+        hmsm(0, 0, 0, 0).overflowing_add_signed(TimeDelta::max_value()),
+        (hmsm(7, 12, 55, 807), 9_223_372_036_828_800)
+    );
+    assert_eq!(
+        hmsm(0, 0, 0, 0).overflowing_add_signed(TimeDelta::min_value()),
+        (hmsm(16, 47, 4, 192), -9_223_372_036_915_200)
+    );
+    assert_eq!(
+        hmsm(0, 0, 0, 0).overflowing_sub_signed(TimeDelta::min_value()),
+        (hmsm(7, 12, 55, 808), -9_223_372_036_828_800)
+    );
+    assert_eq!(
+        hmsm(23, 59, 59, 1_999).overflowing_add_signed(TimeDelta::max_value()),
+        (hmsm(7, 12, 55, 806), 9_223_372_036_915_200)
+    );
 }
 
 #[test]
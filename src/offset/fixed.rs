@@ -4,7 +4,7 @@
 //! The time zone which has a fixed offset from UTC.
 
 use core::fmt;
-use core::ops::{Add, Sub};
+use core::ops::{Add, Neg, Sub};
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -17,6 +17,10 @@ use crate::Timelike;
 
 /// The time zone with fixed offset, from UTC-23:59:59 to UTC+23:59:59.
 ///
+/// A `FixedOffset` can only be created within this range, with a precision of one second, and
+/// is stored as the single number of seconds east of UTC. So every offset has exactly one
+/// representation, and a `DateTime<FixedOffset>` never needs to be normalized after arithmetic.
+///
 /// Using the [`TimeZone`](./trait.TimeZone.html) methods
 /// on a `FixedOffset` struct is the preferred way to construct
 /// `DateTime<FixedOffset>` instances. See the [`east_opt`](#method.east_opt) and
@@ -28,6 +32,9 @@ pub struct FixedOffset {
 }
 
 impl FixedOffset {
+    /// The `FixedOffset` of UTC itself, i.e. `+00:00`.
+    pub const ZERO: FixedOffset = FixedOffset { local_minus_utc: 0 };
+
     /// Makes a new `FixedOffset` for the Eastern Hemisphere with given timezone difference.
     /// The negative `secs` means the Western Hemisphere.
     ///
@@ -111,6 +118,103 @@ impl FixedOffset {
     pub const fn utc_minus_local(&self) -> i32 {
         -self.local_minus_utc
     }
+
+    /// Returns the offset from UTC as a signed number of minutes, e.g. `330` for +05:30.
+    ///
+    /// The result is within ±1439. Any seconds in the offset are truncated towards zero.
+    ///
+    /// See [`FixedOffset::from_str_total_minutes`] for the parsing counterpart.
+    #[inline]
+    #[must_use]
+    pub const fn total_minutes(&self) -> i32 {
+        self.local_minus_utc / 60
+    }
+
+    /// Adds two offsets, returning `None` if the sum is out of range.
+    ///
+    /// Like the offsets themselves, the sum must be less than 24 hours in either direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let hours = |h| FixedOffset::east_opt(h * 3600).unwrap();
+    /// assert_eq!(hours(5).checked_add(hours(-8)), Some(hours(-3)));
+    /// assert_eq!(hours(14).checked_add(hours(10)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_add(self, rhs: FixedOffset) -> Option<FixedOffset> {
+        // both offsets are within ±86_399, so this can not overflow
+        FixedOffset::east_opt(self.local_minus_utc + rhs.local_minus_utc)
+    }
+
+    /// Subtracts an offset from this offset, returning `None` if the difference is out of range.
+    ///
+    /// This gives the offset of the time zone of `self` relative to the time zone of `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let hours = |h| FixedOffset::east_opt(h * 3600).unwrap();
+    /// // Tokyo is 9 hours ahead of London in winter
+    /// assert_eq!(hours(9).checked_sub(hours(0)), Some(hours(9)));
+    /// assert_eq!(hours(-10).checked_sub(hours(14)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_sub(self, rhs: FixedOffset) -> Option<FixedOffset> {
+        FixedOffset::east_opt(self.local_minus_utc - rhs.local_minus_utc)
+    }
+
+    /// Adds two offsets, saturating at the largest offsets of ±23:59:59.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let hours = |h| FixedOffset::east_opt(h * 3600).unwrap();
+    /// assert_eq!(hours(5).saturating_add(hours(-8)), hours(-3));
+    /// assert_eq!(hours(14).saturating_add(hours(10)), FixedOffset::east_opt(86_399).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add(self, rhs: FixedOffset) -> FixedOffset {
+        FixedOffset::saturating_from(self.local_minus_utc + rhs.local_minus_utc)
+    }
+
+    /// Subtracts an offset from this offset, saturating at the largest offsets of ±23:59:59.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let hours = |h| FixedOffset::east_opt(h * 3600).unwrap();
+    /// assert_eq!(hours(9).saturating_sub(hours(1)), hours(8));
+    /// assert_eq!(hours(-10).saturating_sub(hours(14)), FixedOffset::west_opt(86_399).unwrap());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: FixedOffset) -> FixedOffset {
+        FixedOffset::saturating_from(self.local_minus_utc - rhs.local_minus_utc)
+    }
+
+    /// Makes a `FixedOffset` from a number of seconds east of UTC, clamped to the valid range.
+    const fn saturating_from(secs: i32) -> FixedOffset {
+        let local_minus_utc = if secs > 86_399 {
+            86_399
+        } else if secs < -86_399 {
+            -86_399
+        } else {
+            secs
+        };
+        FixedOffset { local_minus_utc }
+    }
 }
 
 impl TimeZone for FixedOffset {
@@ -141,6 +245,29 @@ impl Offset for FixedOffset {
     }
 }
 
+/// Flips the direction of the offset, swapping east and west.
+///
+/// This converts between the "local time minus UTC" and "UTC minus local time" conventions.
+///
+/// # Example
+///
+/// ```
+/// use chrono::FixedOffset;
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// assert_eq!(-offset, FixedOffset::west_opt(3600).unwrap());
+/// assert_eq!(-(-offset), offset);
+/// ```
+impl Neg for FixedOffset {
+    type Output = FixedOffset;
+
+    #[inline]
+    fn neg(self) -> FixedOffset {
+        // the valid range is symmetric, so this can never go out of bounds
+        FixedOffset { local_minus_utc: -self.local_minus_utc }
+    }
+}
+
 impl fmt::Debug for FixedOffset {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let offset = self.local_minus_utc;
@@ -246,6 +373,27 @@ impl<Tz: TimeZone> Sub<FixedOffset> for DateTime<Tz> {
 mod tests {
     use super::FixedOffset;
     use crate::offset::TimeZone;
+    use crate::{Months, TimeDelta, Utc};
+
+    #[test]
+    fn test_offset_is_canonical() {
+        assert_eq!(FixedOffset::east_opt(86_400), None);
+        assert_eq!(FixedOffset::west_opt(86_400), None);
+        assert_eq!(FixedOffset::east_opt(-86_399), FixedOffset::west_opt(86_399));
+        assert_eq!(-FixedOffset::east_opt(86_399).unwrap(), FixedOffset::west_opt(86_399).unwrap());
+        assert_eq!(FixedOffset::east_opt(0), Some(FixedOffset::ZERO));
+        assert_eq!(FixedOffset::west_opt(0), Some(FixedOffset::ZERO));
+
+        // arithmetic keeps the offset, including any seconds, unchanged
+        let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60 + 15).unwrap();
+        let dt = offset.with_ymd_and_hms(2023, 12, 31, 23, 59, 59).unwrap();
+        let shifted = dt + TimeDelta::days(400) - Months::new(13) + TimeDelta::seconds(86_399);
+        assert_eq!(shifted.offset(), &offset);
+        assert_eq!(shifted.offset().local_minus_utc(), 19_815);
+        let round_trip = shifted.with_timezone(&Utc).with_timezone(&offset);
+        assert_eq!(round_trip.offset(), &offset);
+        assert_eq!(round_trip, shifted);
+    }
 
     #[test]
     fn test_date_extreme_offset() {
@@ -292,4 +440,67 @@ mod tests {
             "2012-03-04T05:06:07-23:59:59".to_string()
         );
     }
+
+    #[test]
+    fn test_fixed_offset_neg() {
+        let east = FixedOffset::east_opt(3600).unwrap();
+        let west = FixedOffset::west_opt(3600).unwrap();
+        assert_eq!(-east, west);
+        assert_eq!(-west, east);
+        assert_eq!((-east).local_minus_utc(), east.utc_minus_local());
+        assert_eq!(-FixedOffset::east_opt(86_399).unwrap(), FixedOffset::west_opt(86_399).unwrap());
+        assert_eq!(-FixedOffset::ZERO, FixedOffset::ZERO);
+    }
+
+    #[test]
+    fn test_fixed_offset_zero() {
+        assert_eq!(FixedOffset::ZERO, FixedOffset::east_opt(0).unwrap());
+        assert_eq!(FixedOffset::ZERO.local_minus_utc(), 0);
+        assert_eq!(format!("{}", FixedOffset::ZERO), "+00:00");
+    }
+
+    #[test]
+    fn test_fixed_offset_total_minutes() {
+        assert_eq!(FixedOffset::east_opt(19_800).unwrap().total_minutes(), 330);
+        assert_eq!(FixedOffset::west_opt(28_800).unwrap().total_minutes(), -480);
+        assert_eq!(FixedOffset::east_opt(86_399).unwrap().total_minutes(), 1439);
+        assert_eq!(FixedOffset::west_opt(86_399).unwrap().total_minutes(), -1439);
+        assert_eq!(FixedOffset::west_opt(59).unwrap().total_minutes(), 0);
+    }
+
+    #[test]
+    fn test_fixed_offset_arithmetic() {
+        let offset = |secs| FixedOffset::east_opt(secs).unwrap();
+        let max = offset(86_399);
+        let min = offset(-86_399);
+
+        assert_eq!(offset(3600).checked_add(offset(1800)), Some(offset(5400)));
+        assert_eq!(offset(3600).checked_add(offset(-5400)), Some(offset(-1800)));
+        assert_eq!(offset(3600).checked_sub(offset(-5400)), Some(offset(9000)));
+        assert_eq!(offset(3600).checked_sub(offset(3600)), Some(FixedOffset::ZERO));
+        assert_eq!(max.checked_add(FixedOffset::ZERO), Some(max));
+        assert_eq!(max.checked_add(offset(1)), None);
+        assert_eq!(min.checked_add(offset(-1)), None);
+        assert_eq!(min.checked_sub(offset(1)), None);
+        assert_eq!(max.checked_sub(min), None);
+        assert_eq!(max.checked_add(min), Some(FixedOffset::ZERO));
+
+        assert_eq!(offset(3600).saturating_add(offset(1800)), offset(5400));
+        assert_eq!(offset(3600).saturating_sub(offset(5400)), offset(-1800));
+        assert_eq!(max.saturating_add(offset(1)), max);
+        assert_eq!(max.saturating_add(max), max);
+        assert_eq!(min.saturating_add(min), min);
+        assert_eq!(max.saturating_sub(min), max);
+        assert_eq!(min.saturating_sub(max), min);
+
+        for a in [-86_399, -50_400, -1, 0, 1, 19_800, 86_399] {
+            for b in [-86_399, -3600, 0, 45, 86_399] {
+                assert_eq!(offset(a).checked_sub(offset(b)), offset(a).checked_add(-offset(b)));
+                assert_eq!(
+                    offset(a).saturating_sub(offset(b)),
+                    offset(a).saturating_add(-offset(b))
+                );
+            }
+        }
+    }
 }
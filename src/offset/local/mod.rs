@@ -113,12 +113,49 @@ impl Local {
         Local::now().date()
     }
 
+    /// Returns the current date in the local time zone.
+    ///
+    /// This is a shorthand for `Local::now().date_naive()`.
+    #[must_use]
+    pub fn today_naive() -> NaiveDate {
+        Local::now().date_naive()
+    }
+
     /// Returns a `DateTime` which corresponds to the current date and time.
     pub fn now() -> DateTime<Local> {
         Utc::now().with_timezone(&Local)
     }
 }
 
+#[cfg(any(unix, windows))]
+impl DateTime<Local> {
+    /// Returns whether daylight saving time is in effect for this date and time in the local time
+    /// zone.
+    ///
+    /// On Unix this uses the daylight saving time indicator of the time zone data, on Windows the
+    /// offset is compared to the standard offset of the time zone.
+    ///
+    /// Returns `None` if this can not be determined, for example if the date is out of the range
+    /// supported by the operating system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Local, TimeZone};
+    ///
+    /// let winter = Local.with_ymd_and_hms(2023, 1, 15, 12, 0, 0).unwrap();
+    /// let summer = Local.with_ymd_and_hms(2023, 7, 15, 12, 0, 0).unwrap();
+    /// if winter.offset() == summer.offset() {
+    ///     // no daylight saving time in this time zone
+    ///     assert_eq!(winter.is_dst(), summer.is_dst());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_dst(&self) -> Option<bool> {
+        inner::is_dst(&self.naive_utc())
+    }
+}
+
 impl TimeZone for Local {
     type Offset = FixedOffset;
 
@@ -166,6 +203,17 @@ mod tests {
         assert_eq!(now, from_utc);
     }
 
+    #[test]
+    fn test_today_naive() {
+        let before = Utc::today_naive();
+        let today = Local::today_naive();
+        let after = Utc::today_naive();
+        assert!(before <= after && after <= before.succ_opt().unwrap());
+        // the local date is at most one day away from the UTC date
+        assert!(before.pred_opt().unwrap() <= today && today <= after.succ_opt().unwrap());
+        assert!(today.signed_duration_since(Local::now().date_naive()) <= TimeDelta::zero());
+    }
+
     #[test]
     fn verify_correct_offsets_distant_past() {
         // let distant_past = Local::now() - Duration::days(365 * 100);
@@ -228,6 +276,26 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(any(unix, windows))]
+    fn test_is_dst() {
+        let winter = Local.with_ymd_and_hms(2023, 1, 15, 12, 0, 0).unwrap();
+        let summer = Local.with_ymd_and_hms(2023, 7, 15, 12, 0, 0).unwrap();
+        let (winter_dst, summer_dst) = match (winter.is_dst(), summer.is_dst()) {
+            (Some(winter_dst), Some(summer_dst)) => (winter_dst, summer_dst),
+            _ => return, // not supported by the system time zone
+        };
+
+        if winter.offset() == summer.offset() {
+            // no daylight saving time in 2023, e.g. UTC
+            assert_eq!(winter_dst, summer_dst);
+        } else {
+            // exactly one of the two is in daylight saving time, which one depends on the
+            // hemisphere
+            assert_ne!(winter_dst, summer_dst);
+        }
+    }
+
     /// Test Issue #866
     #[test]
     fn test_issue_866() {
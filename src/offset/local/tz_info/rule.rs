@@ -6,6 +6,7 @@ use super::{
     Error, CUMUL_DAY_IN_MONTHS_NORMAL_YEAR, DAYS_PER_WEEK, DAY_IN_MONTHS_NORMAL_YEAR,
     SECONDS_PER_DAY,
 };
+use crate::is_leap_year;
 
 /// Transition rule
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -767,11 +768,6 @@ pub(crate) const fn days_since_unix_epoch(year: i32, month: usize, month_day: i6
     result
 }
 
-/// Check if a year is a leap year
-pub(crate) const fn is_leap_year(year: i32) -> bool {
-    year % 400 == 0 || (year % 4 == 0 && year % 100 != 0)
-}
-
 #[cfg(test)]
 mod tests {
     use super::super::timezone::Transition;
@@ -585,7 +585,7 @@ impl LocalTimeType {
     }
 
     /// Returns daylight saving time indicator
-    pub(super) const fn is_dst(&self) -> bool {
+    pub(crate) const fn is_dst(&self) -> bool {
         self.is_dst
     }
 
@@ -22,6 +22,12 @@ pub(super) fn offset_from_local_datetime(local: &NaiveDateTime) -> LocalResult<F
     offset(local, true)
 }
 
+pub(super) fn is_dst(utc: &NaiveDateTime) -> Option<bool> {
+    TZ_INFO.with(|maybe_cache| {
+        maybe_cache.borrow_mut().get_or_insert_with(Cache::default).is_dst(*utc)
+    })
+}
+
 fn offset(d: &NaiveDateTime, local: bool) -> LocalResult<FixedOffset> {
     TZ_INFO.with(|maybe_cache| {
         maybe_cache.borrow_mut().get_or_insert_with(Cache::default).offset(*d, local)
@@ -104,7 +110,7 @@ fn current_zone(var: Option<&str>) -> TimeZone {
 }
 
 impl Cache {
-    fn offset(&mut self, d: NaiveDateTime, local: bool) -> LocalResult<FixedOffset> {
+    fn refresh(&mut self) {
         let now = SystemTime::now();
 
         match now.duration_since(self.last_checked) {
@@ -147,6 +153,15 @@ impl Cache {
                 self.source = new_source;
             }
         }
+    }
+
+    fn is_dst(&mut self, utc: NaiveDateTime) -> Option<bool> {
+        self.refresh();
+        self.zone.find_local_time_type(utc.timestamp()).ok().map(|ltt| ltt.is_dst())
+    }
+
+    fn offset(&mut self, d: NaiveDateTime, local: bool) -> LocalResult<FixedOffset> {
+        self.refresh();
 
         if !local {
             let offset = self
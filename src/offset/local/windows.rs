@@ -15,9 +15,11 @@ use std::result::Result;
 
 use windows_sys::Win32::Foundation::FILETIME;
 use windows_sys::Win32::Foundation::SYSTEMTIME;
+use windows_sys::Win32::System::Time::GetTimeZoneInformationForYear;
 use windows_sys::Win32::System::Time::SystemTimeToFileTime;
 use windows_sys::Win32::System::Time::SystemTimeToTzSpecificLocalTime;
 use windows_sys::Win32::System::Time::TzSpecificLocalTimeToSystemTime;
+use windows_sys::Win32::System::Time::TIME_ZONE_INFORMATION;
 
 use super::FixedOffset;
 use crate::{Datelike, LocalResult, NaiveDateTime, Timelike};
@@ -47,6 +49,29 @@ pub(super) fn offset_from_local_datetime(local: &NaiveDateTime) -> LocalResult<F
     offset(local, true)
 }
 
+/// Returns whether daylight saving time is in effect at the given UTC time, by comparing the
+/// offset to the standard offset of the time zone for that year.
+pub(super) fn is_dst(utc: &NaiveDateTime) -> Option<bool> {
+    let offset = from_utc_time(system_time_from_naive_date_time(utc)).ok()?;
+    let year = u16::try_from(utc.year()).ok()?;
+
+    let mut tz_info = MaybeUninit::<TIME_ZONE_INFORMATION>::uninit();
+    if unsafe { GetTimeZoneInformationForYear(year, ptr::null(), tz_info.as_mut_ptr()) } == 0 {
+        return None;
+    }
+    // SAFETY: GetTimeZoneInformationForYear must have succeeded at this point, so we can assume
+    // the value is initialized.
+    let tz_info = unsafe { tz_info.assume_init() };
+
+    // A zero month means the time zone does not observe daylight saving time.
+    if tz_info.DaylightDate.wMonth == 0 {
+        return Some(false);
+    }
+    // All biases are in minutes, with UTC = local time + bias.
+    let standard_offset = -(tz_info.Bias + tz_info.StandardBias) * 60;
+    Some(offset.local_minus_utc() != standard_offset)
+}
+
 /// Converts a local `NaiveDateTime` to the `time::Timespec`.
 pub(super) fn offset(d: &NaiveDateTime, local: bool) -> LocalResult<FixedOffset> {
     let naive_sys_time = system_time_from_naive_date_time(d);
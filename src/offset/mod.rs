@@ -18,6 +18,11 @@
 //! and provides implementations for 1 and 3.
 //! An `TimeZone` instance can be reconstructed from the corresponding `Offset` instance.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
 use core::fmt;
 
 use crate::format::{parse, ParseResult, Parsed, StrftimeItems};
@@ -89,6 +94,28 @@ impl<T> LocalResult<T> {
     }
 }
 
+/// How to resolve a local time that does not map to exactly one instant in a time zone.
+///
+/// Used by [`NaiveDateTime::and_local_timezone_with`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AmbiguityStrategy {
+    /// Use the earlier instant if the local time is ambiguous (in a fold).
+    ///
+    /// A local time that does not exist (in a gap) is rejected.
+    Earliest,
+    /// Use the later instant if the local time is ambiguous (in a fold).
+    ///
+    /// A local time that does not exist (in a gap) is rejected.
+    Latest,
+    /// Reject local times that are ambiguous or do not exist.
+    RejectAmbiguous,
+    /// Use the earlier instant if the local time is ambiguous, and shift a local time that does
+    /// not exist forward by the length of the gap.
+    ///
+    /// This matches the default behavior of `java.time.ZonedDateTime`.
+    Compatible,
+}
+
 #[allow(deprecated)]
 impl<Tz: TimeZone> LocalResult<Date<Tz>> {
     /// Makes a new `DateTime` from the current date and given `NaiveTime`.
@@ -483,6 +510,33 @@ pub trait TimeZone: Sized + Clone {
     /// Creates the offset for given UTC `NaiveDateTime`. This cannot fail.
     fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset;
 
+    /// Creates the offsets for a slice of UTC `NaiveDateTime`s, which must be sorted in ascending
+    /// order.
+    ///
+    /// The result has one offset per input value, equal to what `offset_from_utc_datetime` would
+    /// return for it. The default implementation does exactly that for every value. Time zones
+    /// backed by a table of transitions can override it to walk the table once alongside the
+    /// input instead of searching it for every value.
+    ///
+    /// If `utcs` is not sorted an implementation may return wrong offsets, but it must not panic
+    /// or return fewer offsets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(3600).unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2023, 3, 26).unwrap();
+    /// let utcs: Vec<_> = (0..3).map(|h| date.and_hms_opt(h, 0, 0).unwrap()).collect();
+    /// assert_eq!(tz.offsets_for_sorted(&utcs), vec![tz; 3]);
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    fn offsets_for_sorted(&self, utcs: &[NaiveDateTime]) -> Vec<Self::Offset> {
+        utcs.iter().map(|utc| self.offset_from_utc_datetime(utc)).collect()
+    }
+
     /// Converts the UTC `NaiveDate` to the local time.
     /// The UTC is continuous and thus this cannot fail (but can give the duplicate local time).
     #[allow(clippy::wrong_self_convention)]
@@ -551,6 +605,24 @@ mod tests {
         assert_eq!(dt.to_string(), "1969-12-31 23:00:00 UTC");
     }
 
+    #[test]
+    fn test_offsets_for_sorted() {
+        let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let utcs: Vec<_> = (0..100).map(|i| start + crate::TimeDelta::hours(i * 97)).collect();
+
+        assert_eq!(Utc.offsets_for_sorted(&utcs), vec![Utc; utcs.len()]);
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(tz.offsets_for_sorted(&utcs), vec![tz; utcs.len()]);
+        assert_eq!(tz.offsets_for_sorted(&[]), Vec::new());
+
+        #[cfg(feature = "clock")]
+        {
+            let expected: Vec<_> =
+                utcs.iter().map(|utc| Local.offset_from_utc_datetime(utc)).collect();
+            assert_eq!(Local.offsets_for_sorted(&utcs), expected);
+        }
+    }
+
     #[test]
     fn test_nanos_never_panics() {
         Utc.timestamp_nanos(i64::max_value());
@@ -59,6 +59,23 @@ impl Utc {
         Utc::now().date()
     }
 
+    /// Returns the current date in UTC.
+    ///
+    /// This is a shorthand for `Utc::now().date_naive()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Utc;
+    ///
+    /// let today = Utc::today_naive();
+    /// assert!(today <= Utc::now().date_naive());
+    /// ```
+    #[must_use]
+    pub fn today_naive() -> NaiveDate {
+        Utc::now().date_naive()
+    }
+
     /// Returns a `DateTime` which corresponds to the current date and time.
     #[cfg(not(all(
         target_arch = "wasm32",
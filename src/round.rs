@@ -93,6 +93,29 @@ const fn span_for_digits(digits: u16) -> u32 {
 
 /// Extension trait for rounding or truncating a DateTime by a TimeDelta.
 ///
+/// The `TimeDelta` does not have to divide a day or any other calendar unit evenly: values are
+/// rounded to a multiple of the `TimeDelta` counted from the UNIX epoch (1970-01-01 00:00:00) of
+/// the local date and time. So 90-minute buckets start at midnight every day because a day is a
+/// multiple of 90 minutes, but 7-day buckets start on Thursdays because the epoch was a Thursday.
+/// As `NaiveDateTime` has no time zone, it has no daylight saving transitions to take into
+/// account either.
+///
+/// Rounding is half-up: a value exactly in the middle of two multiples rounds to the later one.
+///
+/// # Example
+///
+/// ```
+/// # use chrono::{DurationRound, NaiveDate, TimeDelta, Weekday, Datelike};
+/// let dt = NaiveDate::from_ymd_opt(2023, 6, 18).unwrap().and_hms_opt(13, 10, 0).unwrap();
+///
+/// let week = dt.duration_trunc(TimeDelta::days(7)).unwrap();
+/// assert_eq!(week.to_string(), "2023-06-15 00:00:00");
+/// assert_eq!(week.weekday(), Weekday::Thu);
+///
+/// let bucket = dt.duration_trunc(TimeDelta::minutes(90)).unwrap();
+/// assert_eq!(bucket.to_string(), "2023-06-18 12:00:00");
+/// ```
+///
 /// # Limitations
 /// Both rounding and truncating are done via [`TimeDelta::num_nanoseconds`] and
 /// [`DateTime::timestamp_nanos`]. This means that they will fail if either the
@@ -241,6 +264,19 @@ where
     }
 }
 
+/// The direction to round in, used by [`NaiveTime::round_to_multiple`].
+///
+/// [`NaiveTime::round_to_multiple`]: crate::NaiveTime::round_to_multiple
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round to the nearest multiple, or to the later one if both are equally near.
+    Nearest,
+    /// Round down to the previous multiple, like truncating.
+    Down,
+    /// Round up to the next multiple.
+    Up,
+}
+
 /// An error from rounding by `TimeDelta`
 ///
 /// See: [`DurationRound`]
@@ -602,6 +638,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_round_naive_buckets() {
+        let ymdhms = |y, m, d, h, n, s| {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap()
+        };
+
+        // 7-day buckets are anchored at the epoch, which was a Thursday
+        let week = TimeDelta::days(7);
+        let dt = ymdhms(2023, 6, 18, 11, 59, 59);
+        assert_eq!(dt.duration_trunc(week), Ok(ymdhms(2023, 6, 15, 0, 0, 0)));
+        assert_eq!(dt.duration_round(week), Ok(ymdhms(2023, 6, 15, 0, 0, 0)));
+        let dt = ymdhms(2023, 6, 18, 12, 0, 0); // halfway
+        assert_eq!(dt.duration_trunc(week), Ok(ymdhms(2023, 6, 15, 0, 0, 0)));
+        assert_eq!(dt.duration_round(week), Ok(ymdhms(2023, 6, 22, 0, 0, 0)));
+        let dt = ymdhms(2023, 6, 15, 0, 0, 0);
+        assert_eq!(dt.duration_trunc(week), Ok(dt));
+        assert_eq!(dt.duration_round(week), Ok(dt));
+        let dt = ymdhms(1969, 12, 20, 6, 0, 0);
+        assert_eq!(dt.duration_trunc(week), Ok(ymdhms(1969, 12, 18, 0, 0, 0)));
+        assert_eq!(dt.duration_round(week), Ok(ymdhms(1969, 12, 18, 0, 0, 0)));
+
+        // 90-minute buckets do not divide an hour, but do divide a day
+        let span = TimeDelta::minutes(90);
+        let dt = ymdhms(2023, 6, 18, 12, 44, 59);
+        assert_eq!(dt.duration_trunc(span), Ok(ymdhms(2023, 6, 18, 12, 0, 0)));
+        assert_eq!(dt.duration_round(span), Ok(ymdhms(2023, 6, 18, 12, 0, 0)));
+        let dt = ymdhms(2023, 6, 18, 12, 45, 0); // halfway
+        assert_eq!(dt.duration_trunc(span), Ok(ymdhms(2023, 6, 18, 12, 0, 0)));
+        assert_eq!(dt.duration_round(span), Ok(ymdhms(2023, 6, 18, 13, 30, 0)));
+        let dt = ymdhms(2023, 6, 18, 23, 59, 0);
+        assert_eq!(dt.duration_trunc(span), Ok(ymdhms(2023, 6, 18, 22, 30, 0)));
+        assert_eq!(dt.duration_round(span), Ok(ymdhms(2023, 6, 19, 0, 0, 0)));
+        let dt = ymdhms(1969, 12, 31, 20, 0, 0);
+        assert_eq!(dt.duration_trunc(span), Ok(ymdhms(1969, 12, 31, 19, 30, 0)));
+        assert_eq!(dt.duration_round(span), Ok(ymdhms(1969, 12, 31, 19, 30, 0)));
+
+        // large buckets, as long as they are not larger than the timestamp
+        let span = TimeDelta::days(365 * 10);
+        let dt = ymdhms(2023, 6, 18, 12, 0, 0);
+        assert_eq!(dt.duration_trunc(span), Ok(ymdhms(2019, 12, 20, 0, 0, 0)));
+        assert_eq!(dt.duration_round(span), Ok(ymdhms(2019, 12, 20, 0, 0, 0)));
+        let span = TimeDelta::days(365 * 100);
+        assert_eq!(dt.duration_trunc(span), Err(RoundingError::DurationExceedsTimestamp));
+        assert_eq!(dt.duration_round(span), Err(RoundingError::DurationExceedsTimestamp));
+    }
+
     #[test]
     fn test_duration_round_pre_epoch() {
         let dt = Utc.with_ymd_and_hms(1969, 12, 12, 12, 12, 12).unwrap();
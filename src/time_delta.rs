@@ -10,6 +10,13 @@
 
 //! Temporal quantification
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::String;
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use core::fmt::Write;
 use core::ops::{Add, Div, Mul, Neg, Sub};
 use core::time::Duration as StdDuration;
 use core::{fmt, i64};
@@ -69,6 +76,29 @@ pub(crate) const MAX: TimeDelta = TimeDelta {
     nanos: (i64::MAX % MILLIS_PER_SEC) as i32 * NANOS_PER_MILLI,
 };
 
+/// A unit of time, as returned by [`TimeDelta::coarse`].
+///
+/// The units are ordered from the smallest to the largest.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TimeUnit {
+    /// Nanoseconds.
+    Nanoseconds,
+    /// Microseconds.
+    Microseconds,
+    /// Milliseconds.
+    Milliseconds,
+    /// Seconds.
+    Seconds,
+    /// Minutes.
+    Minutes,
+    /// Hours.
+    Hours,
+    /// Days of 24 hours.
+    Days,
+    /// Weeks of 7 days.
+    Weeks,
+}
+
 impl TimeDelta {
     /// Makes a new `Duration` with given number of weeks.
     /// Equivalent to `Duration::seconds(weeks * 7 * 24 * 60 * 60)` with overflow checks.
@@ -76,8 +106,17 @@ impl TimeDelta {
     #[inline]
     #[must_use]
     pub fn weeks(weeks: i64) -> TimeDelta {
-        let secs = weeks.checked_mul(SECS_PER_WEEK).expect("Duration::weeks out of bounds");
-        TimeDelta::seconds(secs)
+        TimeDelta::try_weeks(weeks).expect("Duration::weeks out of bounds")
+    }
+
+    /// Makes a new `TimeDelta` with given number of weeks, or `None` when the duration is out of
+    /// bounds.
+    ///
+    /// The number of weeks must be between -15,250,284,452 and 15,250,284,452 inclusive.
+    #[inline]
+    #[must_use]
+    pub fn try_weeks(weeks: i64) -> Option<TimeDelta> {
+        TimeDelta::try_seconds(weeks.checked_mul(SECS_PER_WEEK)?)
     }
 
     /// Makes a new `Duration` with given number of days.
@@ -86,8 +125,17 @@ impl TimeDelta {
     #[inline]
     #[must_use]
     pub fn days(days: i64) -> TimeDelta {
-        let secs = days.checked_mul(SECS_PER_DAY).expect("Duration::days out of bounds");
-        TimeDelta::seconds(secs)
+        TimeDelta::try_days(days).expect("Duration::days out of bounds")
+    }
+
+    /// Makes a new `TimeDelta` with given number of days, or `None` when the duration is out of
+    /// bounds.
+    ///
+    /// The number of days must be between -106,751,991,167 and 106,751,991,167 inclusive.
+    #[inline]
+    #[must_use]
+    pub fn try_days(days: i64) -> Option<TimeDelta> {
+        TimeDelta::try_seconds(days.checked_mul(SECS_PER_DAY)?)
     }
 
     /// Makes a new `Duration` with given number of hours.
@@ -96,8 +144,17 @@ impl TimeDelta {
     #[inline]
     #[must_use]
     pub fn hours(hours: i64) -> TimeDelta {
-        let secs = hours.checked_mul(SECS_PER_HOUR).expect("Duration::hours ouf of bounds");
-        TimeDelta::seconds(secs)
+        TimeDelta::try_hours(hours).expect("Duration::hours ouf of bounds")
+    }
+
+    /// Makes a new `TimeDelta` with given number of hours, or `None` when the duration is out of
+    /// bounds.
+    ///
+    /// The number of hours must be between -2,562,047,788,015 and 2,562,047,788,015 inclusive.
+    #[inline]
+    #[must_use]
+    pub fn try_hours(hours: i64) -> Option<TimeDelta> {
+        TimeDelta::try_seconds(hours.checked_mul(SECS_PER_HOUR)?)
     }
 
     /// Makes a new `Duration` with given number of minutes.
@@ -106,21 +163,53 @@ impl TimeDelta {
     #[inline]
     #[must_use]
     pub fn minutes(minutes: i64) -> TimeDelta {
-        let secs = minutes.checked_mul(SECS_PER_MINUTE).expect("Duration::minutes out of bounds");
-        TimeDelta::seconds(secs)
+        TimeDelta::try_minutes(minutes).expect("Duration::minutes out of bounds")
+    }
+
+    /// Makes a new `TimeDelta` with given number of minutes, or `None` when the duration is out
+    /// of bounds.
+    ///
+    /// The number of minutes must be between -153,722,867,280,912 and 153,722,867,280,912
+    /// inclusive.
+    #[inline]
+    #[must_use]
+    pub fn try_minutes(minutes: i64) -> Option<TimeDelta> {
+        TimeDelta::try_seconds(minutes.checked_mul(SECS_PER_MINUTE)?)
     }
 
     /// Makes a new `Duration` with given number of seconds.
-    /// Panics when the duration is more than `i64::MAX` seconds
-    /// or less than `i64::MIN` seconds.
+    /// Panics when the duration is more than `i64::MAX` milliseconds
+    /// or less than `-i64::MAX` milliseconds.
     #[inline]
     #[must_use]
     pub fn seconds(seconds: i64) -> TimeDelta {
+        TimeDelta::try_seconds(seconds).expect("Duration::seconds out of bounds")
+    }
+
+    /// Makes a new `TimeDelta` with given number of seconds, or `None` when the duration is out
+    /// of bounds.
+    ///
+    /// The number of seconds must be between -9,223,372,036,854,775 and 9,223,372,036,854,775
+    /// inclusive, the whole seconds within `i64::MAX` milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::try_seconds(90), Some(TimeDelta::minutes(1) + TimeDelta::seconds(30)));
+    /// assert!(TimeDelta::try_seconds(i64::MAX / 1000).is_some());
+    /// assert_eq!(TimeDelta::try_seconds(i64::MAX / 1000 + 1), None);
+    /// assert_eq!(TimeDelta::try_days(i64::MAX), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_seconds(seconds: i64) -> Option<TimeDelta> {
         let d = TimeDelta { secs: seconds, nanos: 0 };
         if d < MIN || d > MAX {
-            panic!("Duration::seconds out of bounds");
+            return None;
         }
-        d
+        Some(d)
     }
 
     /// Makes a new `TimeDelta` with given number of milliseconds.
@@ -146,6 +235,170 @@ impl TimeDelta {
         TimeDelta { secs, nanos: nanos as i32 }
     }
 
+    /// Makes a new `TimeDelta` from a floating-point number of seconds.
+    ///
+    /// See [`try_from_secs_f64`](#method.try_from_secs_f64) for how the value is rounded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `secs` is NaN, infinite, or out of the range of a `TimeDelta`.
+    #[must_use]
+    pub fn from_secs_f64(secs: f64) -> TimeDelta {
+        TimeDelta::try_from_secs_f64(secs).expect("TimeDelta::from_secs_f64 out of bounds")
+    }
+
+    /// Makes a new `TimeDelta` from a floating-point number of seconds, or `None` if `secs` is
+    /// NaN, infinite, or out of the range of a `TimeDelta`.
+    ///
+    /// The exact value of `secs` is rounded to the nearest nanosecond, with ties rounding to even.
+    /// Note that most decimal fractions are not exactly representable as an `f64`, so ties are
+    /// rare: `0.0009765625` (2<sup>-10</sup>) is exactly 976,562.5 nanoseconds and becomes
+    /// 976,562.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::try_from_secs_f64(1.5), Some(TimeDelta::milliseconds(1500)));
+    /// assert_eq!(TimeDelta::try_from_secs_f64(-0.1), Some(TimeDelta::milliseconds(-100)));
+    /// assert_eq!(TimeDelta::try_from_secs_f64(1e-9), Some(TimeDelta::nanoseconds(1)));
+    /// assert_eq!(TimeDelta::try_from_secs_f64(0.4e-9), Some(TimeDelta::zero()));
+    /// assert_eq!(TimeDelta::try_from_secs_f64(1e16), None);
+    /// assert_eq!(TimeDelta::try_from_secs_f64(f64::NAN), None);
+    /// assert_eq!(TimeDelta::try_from_secs_f64(f64::INFINITY), None);
+    /// ```
+    #[must_use]
+    pub fn try_from_secs_f64(secs: f64) -> Option<TimeDelta> {
+        if !secs.is_finite() {
+            return None;
+        }
+        let (negative, mantissa, exp) = f64_parts(secs);
+        let nanos = i128::try_from(mul_f64_parts(NANOS_PER_SEC as u128, mantissa, exp)).ok()?;
+        let nanos = if negative { -nanos } else { nanos };
+        if nanos < MIN.total_nanos() || nanos > MAX.total_nanos() {
+            return None;
+        }
+        let secs = nanos.div_euclid(NANOS_PER_SEC as i128) as i64;
+        let nanos = nanos.rem_euclid(NANOS_PER_SEC as i128) as i32;
+        Some(TimeDelta { secs, nanos })
+    }
+
+    /// Makes a new `TimeDelta` from a number of hours, minutes and seconds.
+    ///
+    /// Like [`NaiveTime::from_hms_opt`](crate::NaiveTime::from_hms_opt), but the components are
+    /// added together rather than range checked: `from_hms(0, 90, 0)` is the same as
+    /// `from_hms(1, 30, 0)`, and negative components are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the duration is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let d = TimeDelta::from_hms(1, 30, 45).unwrap();
+    /// assert_eq!(d, TimeDelta::hours(1) + TimeDelta::minutes(30) + TimeDelta::seconds(45));
+    /// assert_eq!(TimeDelta::from_hms(0, 90, 45), Some(d));
+    /// assert_eq!(TimeDelta::from_hms(-1, 0, 30), Some(TimeDelta::seconds(-3570)));
+    /// assert_eq!(TimeDelta::from_hms(i64::MAX, 0, 0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Option<TimeDelta> {
+        let secs = try_opt!(hours.checked_mul(SECS_PER_HOUR));
+        let secs = try_opt!(secs.checked_add(try_opt!(minutes.checked_mul(SECS_PER_MINUTE))));
+        let secs = try_opt!(secs.checked_add(seconds));
+        TimeDelta::zero().checked_add(&TimeDelta { secs, nanos: 0 })
+    }
+
+    /// Makes a new `TimeDelta` from a number of hours, minutes, seconds and milliseconds.
+    ///
+    /// The components are added together as in [`TimeDelta::from_hms`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the duration is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn from_hms_milli(
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        millis: i64,
+    ) -> Option<TimeDelta> {
+        try_opt!(TimeDelta::from_hms(hours, minutes, seconds))
+            .checked_add(&TimeDelta::milliseconds(millis))
+    }
+
+    /// Makes a new `TimeDelta` from a number of hours, minutes, seconds and nanoseconds.
+    ///
+    /// The components are added together as in [`TimeDelta::from_hms`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` when the duration is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn from_hms_nano(hours: i64, minutes: i64, seconds: i64, nanos: i64) -> Option<TimeDelta> {
+        try_opt!(TimeDelta::from_hms(hours, minutes, seconds))
+            .checked_add(&TimeDelta::nanoseconds(nanos))
+    }
+
+    /// Splits the duration into whole hours, minutes and seconds, discarding any fractional
+    /// second.
+    ///
+    /// The components are floored, so that `hours * 3600 + minutes * 60 + seconds` is never
+    /// more than the duration. For a negative duration only the hours are negative; minutes and
+    /// seconds are always in the range `0..60`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(3725).hms(), (1, 2, 5));
+    /// assert_eq!(TimeDelta::seconds(-1).hms(), (-1, 59, 59));
+    /// assert_eq!(TimeDelta::seconds(-3725).hms(), (-2, 57, 55));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn hms(self) -> (i64, u8, u8) {
+        let hours = self.secs.div_euclid(SECS_PER_HOUR);
+        let secs = self.secs.rem_euclid(SECS_PER_HOUR);
+        (hours, (secs / SECS_PER_MINUTE) as u8, (secs % SECS_PER_MINUTE) as u8)
+    }
+
+    /// Returns the duration formatted as `HH:MM:SS`, or `-HH:MM:SS` if it is negative.
+    ///
+    /// Unlike [`hms`](#method.hms) this formats the magnitude of the duration, truncating any
+    /// fractional second. Hours are padded to at least two digits and are not wrapped at 24.
+    /// [`parse_clock`](#method.parse_clock) parses this format back into a `TimeDelta`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(3725).to_clock_string(), "01:02:05");
+    /// assert_eq!(TimeDelta::seconds(-3725).to_clock_string(), "-01:02:05");
+    /// assert_eq!(TimeDelta::hours(100).to_clock_string(), "100:00:00");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[must_use]
+    pub fn to_clock_string(&self) -> String {
+        let abs = self.abs();
+        let (hours, minutes, seconds) = abs.hms();
+        let sign = if self.secs < 0 && abs.secs != 0 { "-" } else { "" };
+        let mut result = String::with_capacity(9);
+        write!(result, "{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds)
+            .expect("writing a duration to string should never fail");
+        result
+    }
+
     /// Returns the total number of whole weeks in the duration.
     #[inline]
     pub const fn num_weeks(&self) -> i64 {
@@ -190,6 +443,40 @@ impl TimeDelta {
         }
     }
 
+    /// Returns the number of whole seconds in the duration, truncated towards zero.
+    ///
+    /// Together with [`subsec_nanos`](#method.subsec_nanos) this splits the duration like
+    /// [`Duration::as_secs`](core::time::Duration::as_secs) and
+    /// [`Duration::subsec_nanos`](core::time::Duration::subsec_nanos), but keeps the sign: both
+    /// parts have the same sign as the duration, so that
+    /// `whole_seconds() * 1_000_000_000 + subsec_nanos()` is the total number of nanoseconds.
+    ///
+    /// This is the same as [`num_seconds`](#method.num_seconds).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let delta = TimeDelta::milliseconds(-1500);
+    /// assert_eq!(delta.whole_seconds(), -1);
+    /// assert_eq!(delta.subsec_nanos(), -500_000_000);
+    /// ```
+    #[inline]
+    pub const fn whole_seconds(&self) -> i64 {
+        self.num_seconds()
+    }
+
+    /// Returns the fractional part of the duration in nanoseconds, with the same sign as the
+    /// duration.
+    ///
+    /// The result is in the range `-999_999_999..=999_999_999`. See
+    /// [`whole_seconds`](#method.whole_seconds) for the other part.
+    #[inline]
+    pub const fn subsec_nanos(&self) -> i32 {
+        self.nanos_mod_sec()
+    }
+
     /// Returns the total number of whole milliseconds in the duration,
     pub const fn num_milliseconds(&self) -> i64 {
         // A proper Duration will not overflow, because MIN and MAX are defined
@@ -215,6 +502,84 @@ impl TimeDelta {
         secs_part.checked_add(nanos_part as i64)
     }
 
+    /// Returns the duration as a floating-point number of seconds.
+    ///
+    /// The whole seconds and the nanoseconds are converted separately, so this works for the
+    /// whole range of `TimeDelta`, unlike dividing [`num_nanoseconds`](#method.num_nanoseconds)
+    /// by 10<sup>9</sup>. An `f64` has 53 bits of precision, so the result can only tell single
+    /// nanoseconds apart for durations up to about 52 days.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::milliseconds(1500).as_secs_f64(), 1.5);
+    /// assert_eq!(TimeDelta::milliseconds(-1500).as_secs_f64(), -1.5);
+    /// assert_eq!(TimeDelta::days(365_000_000).as_secs_f64(), 3.1536e13);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_secs_f64(&self) -> f64 {
+        self.num_seconds() as f64 + f64::from(self.nanos_mod_sec()) / NANOS_PER_SEC as f64
+    }
+
+    /// Returns the duration as a floating-point number of seconds, in an `f32`.
+    ///
+    /// See [`as_secs_f64`](#method.as_secs_f64); an `f32` only has 24 bits of precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::milliseconds(-250).as_secs_f32(), -0.25);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_secs_f32(&self) -> f32 {
+        self.as_secs_f64() as f32
+    }
+
+    /// Returns the duration in the largest unit in which it is at least one, along with that
+    /// unit.
+    ///
+    /// The value is truncated towards zero like [`num_hours`](#method.num_hours) and friends, and
+    /// keeps the sign of the duration. A zero duration is returned as zero nanoseconds.
+    ///
+    /// This is useful as the basis of approximate, human-readable output such as "3 hours ago".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeUnit};
+    ///
+    /// assert_eq!(TimeDelta::minutes(200).coarse(), (3, TimeUnit::Hours));
+    /// assert_eq!(TimeDelta::days(-20).coarse(), (-2, TimeUnit::Weeks));
+    /// assert_eq!(TimeDelta::microseconds(1500).coarse(), (1, TimeUnit::Milliseconds));
+    /// assert_eq!(TimeDelta::zero().coarse(), (0, TimeUnit::Nanoseconds));
+    /// ```
+    #[must_use]
+    pub fn coarse(self) -> (i64, TimeUnit) {
+        let units = [
+            (self.num_weeks(), TimeUnit::Weeks),
+            (self.num_days(), TimeUnit::Days),
+            (self.num_hours(), TimeUnit::Hours),
+            (self.num_minutes(), TimeUnit::Minutes),
+            (self.num_seconds(), TimeUnit::Seconds),
+            (self.num_milliseconds(), TimeUnit::Milliseconds),
+        ];
+        if let Some(&unit) = units.iter().find(|(value, _)| *value != 0) {
+            return unit;
+        }
+        // less than a millisecond, so this fits in an `i64`
+        let nanos = self.total_nanos() as i64;
+        match nanos / 1000 {
+            0 => (nanos, TimeUnit::Nanoseconds),
+            micros => (micros, TimeUnit::Microseconds),
+        }
+    }
+
     /// Add two durations, returning `None` if overflow occurred.
     #[must_use]
     pub fn checked_add(&self, rhs: &TimeDelta) -> Option<TimeDelta> {
@@ -253,6 +618,252 @@ impl TimeDelta {
         }
     }
 
+    /// Divide this duration by another, returning the ratio as a floating-point number.
+    ///
+    /// Dividing by a zero duration follows IEEE 754 semantics: the result is infinite with the
+    /// sign of `self`, or NaN if `self` is zero as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let elapsed = TimeDelta::seconds(90);
+    /// let total = TimeDelta::minutes(6);
+    /// assert_eq!(elapsed.div_duration(total), 0.25);
+    /// assert_eq!(elapsed.div_duration(TimeDelta::zero()), f64::INFINITY);
+    /// ```
+    #[must_use]
+    pub fn div_duration(self, rhs: TimeDelta) -> f64 {
+        self.total_nanos() as f64 / rhs.total_nanos() as f64
+    }
+
+    /// Multiplies this duration by a floating-point factor.
+    ///
+    /// The result is computed exactly and rounded to the nearest nanosecond, with ties rounding
+    /// to even. It saturates at [`TimeDelta::max_value`] or [`TimeDelta::min_value`] if it is out
+    /// of range, which includes multiplying a non-zero duration by an infinite factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(10).mul_f64(1.5), TimeDelta::seconds(15));
+    /// assert_eq!(TimeDelta::seconds(10).mul_f64(-0.25), TimeDelta::milliseconds(-2500));
+    /// assert_eq!(TimeDelta::nanoseconds(5).mul_f64(0.5), TimeDelta::nanoseconds(2));
+    /// assert_eq!(TimeDelta::seconds(1).mul_f64(f64::INFINITY), TimeDelta::max_value());
+    /// ```
+    #[must_use]
+    pub fn mul_f64(self, factor: f64) -> TimeDelta {
+        assert!(!factor.is_nan(), "`TimeDelta::mul_f64` called with NaN");
+        let (factor_negative, mantissa, exp) = f64_parts(factor);
+        let nanos = self.total_nanos();
+        let result = mul_f64_parts(nanos.unsigned_abs(), mantissa, exp);
+        TimeDelta::from_nanos_saturating(result, (nanos < 0) != factor_negative)
+    }
+
+    /// Divides this duration by a floating-point divisor.
+    ///
+    /// The result is computed exactly and rounded to the nearest nanosecond, with ties rounding
+    /// to even. It saturates at [`TimeDelta::max_value`] or [`TimeDelta::min_value`] if it is out
+    /// of range, which includes dividing a non-zero duration by zero. Dividing by an infinite
+    /// value gives zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::seconds(15).div_f64(1.5), TimeDelta::seconds(10));
+    /// assert_eq!(TimeDelta::seconds(-1).div_f64(8.0), TimeDelta::milliseconds(-125));
+    /// assert_eq!(TimeDelta::nanoseconds(3).div_f64(2.0), TimeDelta::nanoseconds(2));
+    /// assert_eq!(TimeDelta::seconds(1).div_f64(-0.0), TimeDelta::min_value());
+    /// ```
+    #[must_use]
+    pub fn div_f64(self, divisor: f64) -> TimeDelta {
+        assert!(!divisor.is_nan(), "`TimeDelta::div_f64` called with NaN");
+        let (divisor_negative, mantissa, exp) = f64_parts(divisor);
+        let nanos = self.total_nanos();
+        let magnitude = nanos.unsigned_abs();
+        let result = if magnitude == 0 {
+            0
+        } else if mantissa == 0 {
+            u128::MAX
+        } else if exp >= 0 {
+            // Any divisor of at least `2^86` is more than twice the largest duration.
+            if exp > 33 {
+                0
+            } else {
+                let divisor = (mantissa as u128) << exp;
+                let (quotient, rem) = (magnitude / divisor, magnitude % divisor);
+                round_half_even(quotient, rem * 2, divisor)
+            }
+        } else {
+            // Long division of `magnitude * 2^-exp` by the mantissa, one bit at a time. Once
+            // the quotient is non-zero it doubles every step, so this stops early.
+            let mantissa = mantissa as u128;
+            let (mut quotient, mut rem) = (magnitude / mantissa, magnitude % mantissa);
+            for _ in 0..exp.unsigned_abs() {
+                if quotient >= 1 << 100 {
+                    break;
+                }
+                quotient *= 2;
+                rem *= 2;
+                if rem >= mantissa {
+                    rem -= mantissa;
+                    quotient += 1;
+                }
+            }
+            if quotient >= 1 << 100 {
+                u128::MAX
+            } else {
+                round_half_even(quotient, rem * 2, mantissa)
+            }
+        };
+        TimeDelta::from_nanos_saturating(result, (nanos < 0) != divisor_negative)
+    }
+
+    /// Makes a new `TimeDelta` from a number of nanoseconds and a sign, saturating at the
+    /// minimum and maximum duration.
+    fn from_nanos_saturating(magnitude: u128, negative: bool) -> TimeDelta {
+        let nanos = i128::try_from(magnitude).unwrap_or(i128::MAX);
+        let nanos = if negative { -nanos } else { nanos };
+        if nanos <= MIN.total_nanos() {
+            return MIN;
+        }
+        if nanos >= MAX.total_nanos() {
+            return MAX;
+        }
+        let secs = nanos.div_euclid(NANOS_PER_SEC as i128) as i64;
+        let nanos = nanos.rem_euclid(NANOS_PER_SEC as i128) as i32;
+        TimeDelta { secs, nanos }
+    }
+
+    /// Calculates the quotient of Euclidean division of this duration by another, so that
+    /// `rhs * q + r == self` with `r` being [`rem_euclid`](#method.rem_euclid).
+    ///
+    /// Returns `None` if `rhs` is zero or the quotient does not fit in an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::minutes(7).div_euclid(TimeDelta::minutes(2)), Some(3));
+    /// assert_eq!(TimeDelta::minutes(-7).div_euclid(TimeDelta::minutes(2)), Some(-4));
+    /// assert_eq!(TimeDelta::minutes(7).div_euclid(TimeDelta::zero()), None);
+    /// ```
+    #[must_use]
+    pub fn div_euclid(self, rhs: TimeDelta) -> Option<i64> {
+        let rhs = rhs.total_nanos();
+        if rhs == 0 {
+            return None;
+        }
+        i64::try_from(self.total_nanos().div_euclid(rhs)).ok()
+    }
+
+    /// Calculates the least non-negative remainder of this duration divided by another.
+    ///
+    /// The result is always in the range `TimeDelta::zero()..rhs.abs()`.
+    ///
+    /// Returns `None` if `rhs` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::minutes(7).rem_euclid(TimeDelta::minutes(2)), Some(TimeDelta::minutes(1)));
+    /// assert_eq!(TimeDelta::minutes(-7).rem_euclid(TimeDelta::minutes(2)), Some(TimeDelta::minutes(1)));
+    /// assert_eq!(TimeDelta::minutes(7).rem_euclid(TimeDelta::zero()), None);
+    /// ```
+    #[must_use]
+    pub fn rem_euclid(self, rhs: TimeDelta) -> Option<TimeDelta> {
+        let rhs = rhs.total_nanos();
+        if rhs == 0 {
+            return None;
+        }
+        let rem = self.total_nanos().rem_euclid(rhs);
+        // `rem` is less than `rhs.abs()`, so it is always in range.
+        let secs = (rem / NANOS_PER_SEC as i128) as i64;
+        let nanos = (rem % NANOS_PER_SEC as i128) as i32;
+        Some(TimeDelta { secs, nanos })
+    }
+
+    /// Divides this duration by another using Euclidean division, returning both the quotient
+    /// and the remainder.
+    ///
+    /// This is useful for tiling a duration with a step: `self` is `rhs` taken `q` times plus
+    /// `r`, where `r` is always in the range `TimeDelta::zero()..rhs.abs()`.
+    /// See [`div_euclid`](#method.div_euclid) and [`rem_euclid`](#method.rem_euclid) for the
+    /// checked versions of either part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient does not fit in an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// let (q, r) = TimeDelta::minutes(7).div_rem_euclid(TimeDelta::minutes(2));
+    /// assert_eq!((q, r), (3, TimeDelta::minutes(1)));
+    ///
+    /// let (q, r) = TimeDelta::minutes(-7).div_rem_euclid(TimeDelta::minutes(2));
+    /// assert_eq!((q, r), (-4, TimeDelta::minutes(1)));
+    /// ```
+    #[must_use]
+    pub fn div_rem_euclid(self, rhs: TimeDelta) -> (i64, TimeDelta) {
+        let q = self.div_euclid(rhs).expect("TimeDelta::div_rem_euclid out of bounds");
+        // the remainder exists whenever the quotient does
+        (q, self.rem_euclid(rhs).unwrap())
+    }
+
+    /// Returns the greatest common divisor of two durations, in nanoseconds.
+    ///
+    /// This is the coarsest step that both durations are a whole multiple of. The result is
+    /// always non-negative. If one of the durations is zero the absolute value of the other is
+    /// returned, and the greatest common divisor of two zero durations is zero.
+    ///
+    /// Like [`abs`](#method.abs), the result for [`TimeDelta::min_value`] and zero or itself
+    /// exceeds [`TimeDelta::max_value`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::TimeDelta;
+    ///
+    /// assert_eq!(TimeDelta::minutes(15).gcd(TimeDelta::minutes(20)), TimeDelta::minutes(5));
+    /// assert_eq!(TimeDelta::seconds(-6).gcd(TimeDelta::zero()), TimeDelta::seconds(6));
+    /// ```
+    #[must_use]
+    pub fn gcd(self, rhs: TimeDelta) -> TimeDelta {
+        let (mut a, mut b) = (self.total_nanos().abs(), rhs.total_nanos().abs());
+        while b != 0 {
+            let rem = a % b;
+            a = b;
+            b = rem;
+        }
+        let secs = (a / NANOS_PER_SEC as i128) as i64;
+        let nanos = (a % NANOS_PER_SEC as i128) as i32;
+        TimeDelta { secs, nanos }
+    }
+
+    /// Returns the total number of nanoseconds, which cannot overflow an `i128`.
+    const fn total_nanos(&self) -> i128 {
+        self.secs as i128 * NANOS_PER_SEC as i128 + self.nanos as i128
+    }
+
     /// Returns the duration as an absolute (non-negative) value.
     #[inline]
     pub const fn abs(&self) -> TimeDelta {
@@ -314,6 +925,19 @@ impl TimeDelta {
         }
         Ok(StdDuration::new(self.secs as u64, self.nanos as u32))
     }
+
+    /// Creates a `time::Duration` object from `std::time::Duration`, saturating at
+    /// [`TimeDelta::max_value`] when the original duration is larger than the maximum
+    /// value supported for this type.
+    pub fn from_std_saturating(duration: StdDuration) -> TimeDelta {
+        TimeDelta::from_std(duration).unwrap_or(MAX)
+    }
+
+    /// Creates a `std::time::Duration` object from `time::Duration`, saturating at zero
+    /// when the duration is less than zero.
+    pub fn to_std_saturating(&self) -> StdDuration {
+        self.to_std().unwrap_or(StdDuration::ZERO)
+    }
 }
 
 impl Neg for TimeDelta {
@@ -463,6 +1087,75 @@ impl Error for OutOfRangeError {
     }
 }
 
+/// Splits a non-NaN `f64` into its sign, mantissa and binary exponent, so that its absolute
+/// value is `mantissa * 2^exp`. Infinity is treated as `2^1024`.
+fn f64_parts(value: f64) -> (bool, u64, i32) {
+    let bits = value.to_bits();
+    let negative = bits >> 63 != 0;
+    let biased_exp = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    if biased_exp == 0 {
+        (negative, fraction, -1074)
+    } else {
+        (negative, fraction | (1 << 52), biased_exp - 1075)
+    }
+}
+
+/// Returns `magnitude * mantissa * 2^exp`, computed exactly and then rounded to the nearest
+/// integer with ties rounding to even. Saturates at `u128::MAX`, which is far out of the range of
+/// a `TimeDelta` in nanoseconds.
+fn mul_f64_parts(magnitude: u128, mantissa: u64, exp: i32) -> u128 {
+    if exp >= 0 {
+        match magnitude.checked_mul(mantissa as u128) {
+            Some(product) if exp < 128 => product.saturating_mul(1 << exp),
+            Some(0) => 0,
+            _ => u128::MAX,
+        }
+    } else {
+        let shift = exp.unsigned_abs();
+        // The exact product as a 256-bit number `hi * 2^128 + lo`.
+        let (a_hi, a_lo) = (magnitude >> 64, magnitude & u64::MAX as u128);
+        let (p_hi, p_lo) = (a_hi * mantissa as u128, a_lo * mantissa as u128);
+        let lo = p_lo.wrapping_add(p_hi << 64);
+        let hi = (p_hi >> 64) + (lo < p_lo) as u128;
+        // `hi` is only non-zero for durations of more than `2^75` nanoseconds (about a million
+        // years), e.g. `TimeDelta::max_value().mul_f64(0.5)`. All callers pass a `magnitude` below
+        // `2^84` and `mantissa` is below `2^53`, so `hi` fits in 9 bits.
+        debug_assert!(hi < 1 << 9);
+        if hi == 0 {
+            shr_round_half_even(lo, shift)
+        } else if shift <= 44 {
+            // at least `2^84` nanoseconds, which is out of range
+            u128::MAX
+        } else {
+            // `hi` is small, so we can drop the lowest 43 bits and only keep track of whether
+            // any of them was set, which is enough to round correctly.
+            let sticky = (lo & ((1 << 43) - 1) != 0) as u128;
+            shr_round_half_even((lo >> 43) | (hi << 85) | sticky, shift - 43)
+        }
+    }
+}
+
+/// Returns `value / 2^shift`, rounded to the nearest integer with ties rounding to even.
+fn shr_round_half_even(value: u128, shift: u32) -> u128 {
+    match shift {
+        0 => value,
+        1..=127 => round_half_even(value >> shift, (value & ((1 << shift) - 1)) << 1, 1 << shift),
+        128 => (value > 1 << 127) as u128,
+        _ => 0,
+    }
+}
+
+/// Rounds the `quotient` of a division by `divisor` to the nearest integer with ties rounding to
+/// even, given twice the remainder.
+fn round_half_even(quotient: u128, twice_rem: u128, divisor: u128) -> u128 {
+    if twice_rem > divisor || (twice_rem == divisor && quotient % 2 == 1) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
 #[inline]
 const fn div_mod_floor_64(this: i64, other: i64) -> (i64, i64) {
     (this.div_euclid(other), this.rem_euclid(other))
@@ -488,7 +1181,8 @@ impl arbitrary::Arbitrary<'_> for TimeDelta {
 
 #[cfg(test)]
 mod tests {
-    use super::{OutOfRangeError, TimeDelta, MAX, MIN};
+    use super::{OutOfRangeError, TimeDelta, TimeUnit, MAX, MIN};
+    use super::{SECS_PER_DAY, SECS_PER_HOUR, SECS_PER_MINUTE, SECS_PER_WEEK};
     use std::time::Duration as StdDuration;
     use std::{i32, i64};
 
@@ -513,6 +1207,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_try_constructors() {
+        let max_secs = i64::MAX / 1000;
+        assert_eq!(
+            TimeDelta::try_seconds(max_secs),
+            Some(TimeDelta::milliseconds(max_secs * 1000))
+        );
+        assert_eq!(
+            TimeDelta::try_seconds(-max_secs),
+            Some(TimeDelta::milliseconds(-max_secs * 1000))
+        );
+        assert_eq!(TimeDelta::try_seconds(max_secs + 1), None);
+        assert_eq!(TimeDelta::try_seconds(-max_secs - 1), None);
+        assert_eq!(TimeDelta::try_seconds(i64::MAX), None);
+        assert_eq!(TimeDelta::try_seconds(i64::MIN), None);
+
+        // the documented limits
+        type TryUnit = fn(i64) -> Option<TimeDelta>;
+        let cases: [(TryUnit, i64, i64); 4] = [
+            (TimeDelta::try_weeks, 15_250_284_452, SECS_PER_WEEK),
+            (TimeDelta::try_days, 106_751_991_167, SECS_PER_DAY),
+            (TimeDelta::try_hours, 2_562_047_788_015, SECS_PER_HOUR),
+            (TimeDelta::try_minutes, 153_722_867_280_912, SECS_PER_MINUTE),
+        ];
+        for (try_unit, limit, secs) in cases {
+            assert_eq!(limit, max_secs / secs);
+            assert_eq!(try_unit(limit), Some(TimeDelta::seconds(limit * secs)));
+            assert_eq!(try_unit(-limit), Some(TimeDelta::seconds(-limit * secs)));
+            assert_eq!(try_unit(limit + 1), None);
+            assert_eq!(try_unit(-limit - 1), None);
+            assert_eq!(try_unit(i64::MAX), None);
+            assert_eq!(try_unit(i64::MIN), None);
+            assert_eq!(try_unit(0), Some(TimeDelta::zero()));
+        }
+
+        assert_eq!(TimeDelta::try_weeks(-3), Some(TimeDelta::weeks(-3)));
+        assert_eq!(TimeDelta::try_days(2), Some(TimeDelta::days(2)));
+        assert_eq!(TimeDelta::try_hours(25), Some(TimeDelta::hours(25)));
+        assert_eq!(TimeDelta::try_minutes(-61), Some(TimeDelta::minutes(-61)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Duration::days out of bounds")]
+    fn test_duration_days_out_of_bounds() {
+        let _ = TimeDelta::days(i64::MAX);
+    }
+
     #[test]
     fn test_duration_num_days() {
         assert_eq!(TimeDelta::zero().num_days(), 0);
@@ -537,6 +1278,29 @@ mod tests {
         assert_eq!(TimeDelta::milliseconds(-1001).num_seconds(), -1);
     }
 
+    #[test]
+    fn test_duration_whole_seconds_subsec_nanos() {
+        let parts = |d: TimeDelta| (d.whole_seconds(), d.subsec_nanos());
+        assert_eq!(parts(TimeDelta::zero()), (0, 0));
+        assert_eq!(parts(TimeDelta::milliseconds(1500)), (1, 500_000_000));
+        assert_eq!(parts(TimeDelta::milliseconds(-1500)), (-1, -500_000_000));
+        assert_eq!(parts(TimeDelta::nanoseconds(-1)), (0, -1));
+        assert_eq!(parts(TimeDelta::nanoseconds(999_999_999)), (0, 999_999_999));
+        assert_eq!(parts(TimeDelta::seconds(-2)), (-2, 0));
+        assert_eq!(parts(MAX), (i64::MAX / 1000, 807_000_000));
+        assert_eq!(parts(MIN), (i64::MIN / 1000, -808_000_000));
+
+        for d in [
+            TimeDelta::milliseconds(-1500),
+            TimeDelta::nanoseconds(-1_000_000_001),
+            TimeDelta::nanoseconds(123_456_789_012),
+        ] {
+            let (secs, nanos) = parts(d);
+            assert_eq!(TimeDelta::seconds(secs) + TimeDelta::nanoseconds(nanos.into()), d);
+            assert_eq!(secs * 1_000_000_000 + i64::from(nanos), d.num_nanoseconds().unwrap());
+        }
+    }
+
     #[test]
     fn test_duration_num_milliseconds() {
         assert_eq!(TimeDelta::zero().num_milliseconds(), 0);
@@ -604,6 +1368,97 @@ mod tests {
         assert_eq!(TimeDelta::days(i64::MIN / NANOS_PER_DAY - 1).num_nanoseconds(), None);
     }
 
+    #[test]
+    fn test_duration_from_hms() {
+        let expected = TimeDelta::hours(1) + TimeDelta::minutes(30) + TimeDelta::seconds(45);
+        assert_eq!(TimeDelta::from_hms(1, 30, 45), Some(expected));
+        assert_eq!(TimeDelta::from_hms(0, 0, 5445), Some(expected));
+        assert_eq!(TimeDelta::from_hms(-1, -30, -45), Some(-expected));
+        assert_eq!(
+            TimeDelta::from_hms_milli(1, 30, 45, 250),
+            Some(expected + TimeDelta::milliseconds(250))
+        );
+        assert_eq!(
+            TimeDelta::from_hms_nano(1, 30, 45, -1),
+            Some(expected - TimeDelta::nanoseconds(1))
+        );
+
+        // overflow of the intermediate values and of the result
+        assert_eq!(TimeDelta::from_hms(i64::MAX / 3600 + 1, 0, 0), None);
+        assert_eq!(TimeDelta::from_hms(0, i64::MIN, 0), None);
+        assert_eq!(TimeDelta::from_hms(0, 0, i64::MAX), None);
+        assert_eq!(
+            TimeDelta::from_hms(0, 0, MAX.num_seconds()),
+            Some(TimeDelta::seconds(MAX.num_seconds()))
+        );
+        assert_eq!(TimeDelta::from_hms(0, 0, MAX.num_seconds() + 1), None);
+        assert_eq!(TimeDelta::from_hms_milli(0, 0, MAX.num_seconds(), 1_000), None);
+        assert_eq!(TimeDelta::from_hms_nano(0, 0, MIN.num_seconds(), -1_000_000_000), None);
+    }
+
+    #[test]
+    fn test_duration_hms() {
+        assert_eq!(TimeDelta::zero().hms(), (0, 0, 0));
+        assert_eq!(TimeDelta::seconds(5445).hms(), (1, 30, 45));
+        assert_eq!(TimeDelta::days(2).hms(), (48, 0, 0));
+        assert_eq!(TimeDelta::milliseconds(999).hms(), (0, 0, 0));
+
+        // negative durations are floored: only the hours are negative
+        assert_eq!(TimeDelta::seconds(-1).hms(), (-1, 59, 59));
+        assert_eq!(TimeDelta::seconds(-3600).hms(), (-1, 0, 0));
+        assert_eq!(TimeDelta::seconds(-5445).hms(), (-2, 29, 15));
+        assert_eq!(TimeDelta::milliseconds(-1).hms(), (-1, 59, 59));
+        assert_eq!(TimeDelta::milliseconds(-1500).hms(), (-1, 59, 58));
+
+        for secs in [-7201, -3601, -61, -1, 0, 1, 59, 3599, 86_400] {
+            let (h, m, s) = TimeDelta::seconds(secs).hms();
+            assert_eq!(h * 3600 + i64::from(m) * 60 + i64::from(s), secs);
+        }
+
+        assert_eq!(MAX.hms(), (2_562_047_788_015, 12, 55));
+        assert_eq!(MIN.hms(), (-2_562_047_788_016, 47, 4));
+    }
+
+    #[test]
+    fn test_duration_to_clock_string() {
+        assert_eq!(TimeDelta::zero().to_clock_string(), "00:00:00");
+        assert_eq!(TimeDelta::seconds(5445).to_clock_string(), "01:30:45");
+        assert_eq!(TimeDelta::seconds(-5445).to_clock_string(), "-01:30:45");
+        assert_eq!(TimeDelta::seconds(-1).to_clock_string(), "-00:00:01");
+        assert_eq!(TimeDelta::milliseconds(-1500).to_clock_string(), "-00:00:01");
+        assert_eq!(TimeDelta::milliseconds(-999).to_clock_string(), "00:00:00");
+        assert_eq!(TimeDelta::days(5).to_clock_string(), "120:00:00");
+        assert_eq!(MAX.to_clock_string(), "2562047788015:12:55");
+        assert_eq!(MIN.to_clock_string(), "-2562047788015:12:55");
+    }
+
+    #[test]
+    fn test_duration_coarse() {
+        assert_eq!(TimeDelta::zero().coarse(), (0, TimeUnit::Nanoseconds));
+        assert_eq!(TimeDelta::nanoseconds(999).coarse(), (999, TimeUnit::Nanoseconds));
+        assert_eq!(TimeDelta::nanoseconds(1000).coarse(), (1, TimeUnit::Microseconds));
+        assert_eq!(TimeDelta::nanoseconds(999_999).coarse(), (999, TimeUnit::Microseconds));
+        assert_eq!(TimeDelta::microseconds(1000).coarse(), (1, TimeUnit::Milliseconds));
+        assert_eq!(TimeDelta::milliseconds(59_999).coarse(), (59, TimeUnit::Seconds));
+        assert_eq!(TimeDelta::seconds(60).coarse(), (1, TimeUnit::Minutes));
+        assert_eq!(TimeDelta::minutes(119).coarse(), (1, TimeUnit::Hours));
+        assert_eq!(TimeDelta::hours(47).coarse(), (1, TimeUnit::Days));
+        assert_eq!(TimeDelta::days(6).coarse(), (6, TimeUnit::Days));
+        assert_eq!(TimeDelta::days(700).coarse(), (100, TimeUnit::Weeks));
+
+        // negative durations keep their sign and truncate towards zero
+        assert_eq!(TimeDelta::nanoseconds(-1).coarse(), (-1, TimeUnit::Nanoseconds));
+        assert_eq!(TimeDelta::nanoseconds(-1500).coarse(), (-1, TimeUnit::Microseconds));
+        assert_eq!(TimeDelta::milliseconds(-1).coarse(), (-1, TimeUnit::Milliseconds));
+        assert_eq!(TimeDelta::milliseconds(-999).coarse(), (-999, TimeUnit::Milliseconds));
+        assert_eq!(TimeDelta::seconds(-3599).coarse(), (-59, TimeUnit::Minutes));
+        assert_eq!(TimeDelta::days(-13).coarse(), (-1, TimeUnit::Weeks));
+
+        assert_eq!(MAX.coarse(), (MAX.num_weeks(), TimeUnit::Weeks));
+        assert_eq!(MIN.coarse(), (MIN.num_weeks(), TimeUnit::Weeks));
+        assert!(TimeUnit::Nanoseconds < TimeUnit::Weeks);
+    }
+
     #[test]
     fn test_duration_checked_ops() {
         assert_eq!(
@@ -755,4 +1610,307 @@ mod tests {
             Err(OutOfRangeError(()))
         );
     }
+
+    #[test]
+    fn test_duration_div_duration() {
+        assert_eq!(TimeDelta::seconds(90).div_duration(TimeDelta::minutes(6)), 0.25);
+        assert_eq!(TimeDelta::seconds(-90).div_duration(TimeDelta::minutes(6)), -0.25);
+        assert_eq!(TimeDelta::seconds(90).div_duration(TimeDelta::seconds(-90)), -1.0);
+        assert_eq!(TimeDelta::nanoseconds(1).div_duration(TimeDelta::nanoseconds(4)), 0.25);
+        assert_eq!(MAX.div_duration(MAX), 1.0);
+        assert_eq!(MIN.div_duration(MAX), -1.0);
+        assert_eq!(MAX.div_duration(TimeDelta::milliseconds(1)), i64::MAX as f64);
+        assert_eq!(TimeDelta::seconds(1).div_duration(TimeDelta::zero()), f64::INFINITY);
+        assert_eq!(TimeDelta::seconds(-1).div_duration(TimeDelta::zero()), f64::NEG_INFINITY);
+        assert!(TimeDelta::zero().div_duration(TimeDelta::zero()).is_nan());
+    }
+
+    #[test]
+    fn test_duration_div_rem_euclid() {
+        let check = |lhs: TimeDelta, rhs: TimeDelta, q: i64, r: TimeDelta| {
+            assert_eq!(lhs.div_euclid(rhs), Some(q));
+            assert_eq!(lhs.rem_euclid(rhs), Some(r));
+            assert_eq!(lhs.div_rem_euclid(rhs), (q, r));
+            assert_eq!(rhs * q as i32 + r, lhs);
+        };
+        check(TimeDelta::minutes(7), TimeDelta::minutes(2), 3, TimeDelta::minutes(1));
+        check(TimeDelta::minutes(-7), TimeDelta::minutes(2), -4, TimeDelta::minutes(1));
+        check(TimeDelta::minutes(7), TimeDelta::minutes(-2), -3, TimeDelta::minutes(1));
+        check(TimeDelta::minutes(-7), TimeDelta::minutes(-2), 4, TimeDelta::minutes(1));
+        check(TimeDelta::minutes(6), TimeDelta::minutes(2), 3, TimeDelta::zero());
+        check(
+            TimeDelta::nanoseconds(-1),
+            TimeDelta::seconds(1),
+            -1,
+            TimeDelta::nanoseconds(999_999_999),
+        );
+        check(
+            TimeDelta::milliseconds(1500),
+            TimeDelta::nanoseconds(7),
+            214_285_714,
+            TimeDelta::nanoseconds(2),
+        );
+
+        assert_eq!(MAX.div_euclid(TimeDelta::milliseconds(1)), Some(i64::MAX));
+        assert_eq!(MIN.div_euclid(TimeDelta::milliseconds(1)), Some(i64::MIN));
+        assert_eq!(MAX.div_euclid(TimeDelta::nanoseconds(1)), None);
+        assert_eq!(MAX.rem_euclid(TimeDelta::nanoseconds(1)), Some(TimeDelta::zero()));
+        assert_eq!(MIN.rem_euclid(MAX), Some(MAX - TimeDelta::milliseconds(1)));
+        assert_eq!(TimeDelta::seconds(1).div_euclid(TimeDelta::zero()), None);
+        assert_eq!(TimeDelta::seconds(1).rem_euclid(TimeDelta::zero()), None);
+        assert_eq!(MAX.div_rem_euclid(TimeDelta::milliseconds(1)), (i64::MAX, TimeDelta::zero()));
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeDelta::div_rem_euclid out of bounds")]
+    fn test_duration_div_rem_euclid_zero() {
+        let _ = TimeDelta::seconds(1).div_rem_euclid(TimeDelta::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeDelta::div_rem_euclid out of bounds")]
+    fn test_duration_div_rem_euclid_overflow() {
+        let _ = MAX.div_rem_euclid(TimeDelta::nanoseconds(1));
+    }
+
+    #[test]
+    fn test_duration_gcd() {
+        assert_eq!(TimeDelta::minutes(15).gcd(TimeDelta::minutes(20)), TimeDelta::minutes(5));
+        assert_eq!(TimeDelta::minutes(20).gcd(TimeDelta::minutes(15)), TimeDelta::minutes(5));
+        assert_eq!(TimeDelta::minutes(-15).gcd(TimeDelta::minutes(20)), TimeDelta::minutes(5));
+        assert_eq!(TimeDelta::minutes(15).gcd(TimeDelta::minutes(-20)), TimeDelta::minutes(5));
+        assert_eq!(TimeDelta::hours(1).gcd(TimeDelta::hours(1)), TimeDelta::hours(1));
+        assert_eq!(TimeDelta::seconds(1).gcd(TimeDelta::nanoseconds(1)), TimeDelta::nanoseconds(1));
+        assert_eq!(
+            TimeDelta::milliseconds(1500).gcd(TimeDelta::nanoseconds(1_000_000_250)),
+            TimeDelta::nanoseconds(250)
+        );
+        assert_eq!(TimeDelta::days(7).gcd(TimeDelta::seconds(7)), TimeDelta::seconds(7));
+        assert_eq!(
+            TimeDelta::seconds(7).gcd(TimeDelta::seconds(13)),
+            TimeDelta::nanoseconds(1_000_000_000)
+        );
+
+        // zero operands
+        assert_eq!(TimeDelta::minutes(15).gcd(TimeDelta::zero()), TimeDelta::minutes(15));
+        assert_eq!(TimeDelta::zero().gcd(TimeDelta::minutes(-15)), TimeDelta::minutes(15));
+        assert_eq!(TimeDelta::zero().gcd(TimeDelta::zero()), TimeDelta::zero());
+
+        // extremes
+        assert_eq!(MAX.gcd(MAX), MAX);
+        assert_eq!(MAX.gcd(MIN), TimeDelta::milliseconds(1));
+        assert_eq!(MIN.gcd(TimeDelta::zero()), MIN.abs());
+    }
+
+    #[test]
+    fn test_duration_as_secs_f64() {
+        assert_eq!(TimeDelta::zero().as_secs_f64(), 0.0);
+        assert_eq!(TimeDelta::nanoseconds(1).as_secs_f64(), 1e-9);
+        assert_eq!(TimeDelta::nanoseconds(-1).as_secs_f64(), -1e-9);
+        assert_eq!(TimeDelta::milliseconds(-100).as_secs_f64(), -0.1);
+        assert_eq!(TimeDelta::seconds(-3).as_secs_f64(), -3.0);
+        assert_eq!(
+            (TimeDelta::days(1) + TimeDelta::nanoseconds(5)).as_secs_f64(),
+            86_400.000_000_005
+        );
+        // `num_nanoseconds` overflows for these
+        assert_eq!(MAX.as_secs_f64(), 9_223_372_036_854_775.807);
+        assert_eq!(MIN.as_secs_f64(), -9_223_372_036_854_775.808);
+        assert_eq!(TimeDelta::days(-365_000_000).as_secs_f64(), -3.1536e13);
+
+        assert_eq!(TimeDelta::milliseconds(1500).as_secs_f32(), 1.5);
+        assert_eq!(TimeDelta::nanoseconds(1).as_secs_f32(), 1e-9);
+        assert_eq!(MAX.as_secs_f32(), 9.223_372e15);
+    }
+
+    #[test]
+    fn test_duration_try_from_secs_f64() {
+        let from = TimeDelta::try_from_secs_f64;
+        assert_eq!(from(0.0), Some(TimeDelta::zero()));
+        assert_eq!(from(-0.0), Some(TimeDelta::zero()));
+        assert_eq!(from(1.0), Some(TimeDelta::seconds(1)));
+        assert_eq!(from(-2.5), Some(TimeDelta::milliseconds(-2500)));
+        assert_eq!(from(0.1), Some(TimeDelta::milliseconds(100)));
+        assert_eq!(from(-0.1), Some(TimeDelta::milliseconds(-100)));
+        assert_eq!(from(86_400.000_000_005), Some(TimeDelta::days(1) + TimeDelta::nanoseconds(5)));
+        assert_eq!(from(1e15), Some(TimeDelta::seconds(1_000_000_000_000_000)));
+
+        // rounding to the nearest nanosecond
+        assert_eq!(from(1e-9), Some(TimeDelta::nanoseconds(1)));
+        assert_eq!(from(-1e-9), Some(TimeDelta::nanoseconds(-1)));
+        assert_eq!(from(0.499_999_9e-9), Some(TimeDelta::zero()));
+        assert_eq!(from(0.500_000_1e-9), Some(TimeDelta::nanoseconds(1)));
+        assert_eq!(from(-0.500_000_1e-9), Some(TimeDelta::nanoseconds(-1)));
+        assert_eq!(from(1.499_999_9e-9), Some(TimeDelta::nanoseconds(1)));
+        assert_eq!(from(1.500_000_1e-9), Some(TimeDelta::nanoseconds(2)));
+        assert_eq!(from(0.999_999_999_4), Some(TimeDelta::nanoseconds(999_999_999)));
+        assert_eq!(from(0.999_999_999_6), Some(TimeDelta::seconds(1)));
+        assert_eq!(from(-0.999_999_999_6), Some(TimeDelta::seconds(-1)));
+        assert_eq!(from(f64::MIN_POSITIVE), Some(TimeDelta::zero()));
+        assert_eq!(from(-f64::MIN_POSITIVE), Some(TimeDelta::zero()));
+        // exact ties round to even
+        assert_eq!(from(1.0 / 1024.0), Some(TimeDelta::nanoseconds(976_562)));
+        assert_eq!(from(3.0 / 1024.0), Some(TimeDelta::nanoseconds(2_929_688)));
+        assert_eq!(from(-1.0 / 1024.0), Some(TimeDelta::nanoseconds(-976_562)));
+
+        // round trips for durations that fit in the precision
+        for d in [
+            TimeDelta::nanoseconds(1),
+            TimeDelta::nanoseconds(-123_456_789),
+            TimeDelta::days(51) + TimeDelta::nanoseconds(999_999_999),
+            TimeDelta::days(-51) - TimeDelta::nanoseconds(1),
+        ] {
+            assert_eq!(from(d.as_secs_f64()), Some(d));
+        }
+
+        // out of range
+        assert_eq!(from(9_223_372_036_854_774.0), Some(TimeDelta::seconds(9_223_372_036_854_774)));
+        assert_eq!(from(9_223_372_036_854_776.0), None);
+        assert_eq!(from(-9_223_372_036_854_776.0), None);
+        assert_eq!(from(1e300), None);
+        assert_eq!(from(f64::MAX), None);
+        assert_eq!(from(f64::MIN), None);
+        assert_eq!(from(f64::NAN), None);
+        assert_eq!(from(f64::INFINITY), None);
+        assert_eq!(from(f64::NEG_INFINITY), None);
+
+        assert_eq!(TimeDelta::from_secs_f64(-0.25), TimeDelta::milliseconds(-250));
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeDelta::from_secs_f64 out of bounds")]
+    fn test_duration_from_secs_f64_nan() {
+        let _ = TimeDelta::from_secs_f64(f64::NAN);
+    }
+
+    #[test]
+    fn test_duration_mul_f64() {
+        let d = TimeDelta::seconds(10) + TimeDelta::nanoseconds(1);
+        assert_eq!(d.mul_f64(1.0), d);
+        assert_eq!(d.mul_f64(-1.0), -d);
+        assert_eq!(d.mul_f64(2.0), TimeDelta::seconds(20) + TimeDelta::nanoseconds(2));
+        assert_eq!(d.mul_f64(0.0), TimeDelta::zero());
+        assert_eq!(d.mul_f64(-0.0), TimeDelta::zero());
+        assert_eq!(TimeDelta::seconds(-3).mul_f64(1.5), TimeDelta::milliseconds(-4500));
+        assert_eq!(TimeDelta::seconds(-3).mul_f64(-1.5), TimeDelta::milliseconds(4500));
+        assert_eq!(TimeDelta::days(365).mul_f64(0.1), TimeDelta::milliseconds(3_153_600_000));
+
+        // ties round to even
+        assert_eq!(TimeDelta::nanoseconds(1).mul_f64(0.5), TimeDelta::zero());
+        assert_eq!(TimeDelta::nanoseconds(3).mul_f64(0.5), TimeDelta::nanoseconds(2));
+        assert_eq!(TimeDelta::nanoseconds(5).mul_f64(0.5), TimeDelta::nanoseconds(2));
+        assert_eq!(TimeDelta::nanoseconds(-5).mul_f64(0.5), TimeDelta::nanoseconds(-2));
+        assert_eq!(TimeDelta::nanoseconds(-7).mul_f64(0.5), TimeDelta::nanoseconds(-4));
+        assert_eq!(TimeDelta::nanoseconds(1).mul_f64(0.500_000_1), TimeDelta::nanoseconds(1));
+        assert_eq!(TimeDelta::nanoseconds(1).mul_f64(0.499_999_9), TimeDelta::zero());
+        assert_eq!(TimeDelta::nanoseconds(1).mul_f64(f64::MIN_POSITIVE), TimeDelta::zero());
+
+        // large values are exact
+        assert_eq!(MAX.mul_f64(1.0), MAX);
+        assert_eq!(MIN.mul_f64(1.0), MIN);
+        assert_eq!(
+            MAX.mul_f64(0.5),
+            TimeDelta::milliseconds(i64::MAX / 2) + TimeDelta::microseconds(500)
+        );
+        assert_eq!(MAX.mul_f64(-1.0), -MAX);
+        assert_eq!(
+            TimeDelta::nanoseconds(i64::MAX).mul_f64(0.25),
+            TimeDelta::nanoseconds(i64::MAX / 4 + 1)
+        );
+
+        // saturation
+        assert_eq!(MAX.mul_f64(1.000_000_1), MAX);
+        assert_eq!(MAX.mul_f64(-2.0), MIN);
+        assert_eq!(MAX.mul_f64(256.0), MAX);
+        assert_eq!(MIN.mul_f64(1e6), MIN);
+        assert_eq!(MIN.mul_f64(-1.0), MAX);
+        assert_eq!(TimeDelta::nanoseconds(1).mul_f64(1e30), MAX);
+        assert_eq!(TimeDelta::nanoseconds(-1).mul_f64(f64::MAX), MIN);
+        assert_eq!(TimeDelta::seconds(1).mul_f64(f64::INFINITY), MAX);
+        assert_eq!(TimeDelta::seconds(1).mul_f64(f64::NEG_INFINITY), MIN);
+        assert_eq!(TimeDelta::zero().mul_f64(f64::INFINITY), TimeDelta::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duration_mul_f64_nan() {
+        let _ = TimeDelta::seconds(1).mul_f64(f64::NAN);
+    }
+
+    #[test]
+    fn test_duration_div_f64() {
+        let d = TimeDelta::seconds(10) + TimeDelta::nanoseconds(2);
+        assert_eq!(d.div_f64(1.0), d);
+        assert_eq!(d.div_f64(-1.0), -d);
+        assert_eq!(d.div_f64(2.0), TimeDelta::seconds(5) + TimeDelta::nanoseconds(1));
+        assert_eq!(d.div_f64(0.5), TimeDelta::seconds(20) + TimeDelta::nanoseconds(4));
+        assert_eq!(TimeDelta::seconds(-3).div_f64(1.5), TimeDelta::seconds(-2));
+        assert_eq!(TimeDelta::seconds(-3).div_f64(-1.5), TimeDelta::seconds(2));
+        assert_eq!(TimeDelta::seconds(1).div_f64(3.0), TimeDelta::nanoseconds(333_333_333));
+        assert_eq!(TimeDelta::seconds(2).div_f64(3.0), TimeDelta::nanoseconds(666_666_667));
+        assert_eq!(TimeDelta::seconds(1).div_f64(0.125), TimeDelta::seconds(8));
+        // `1e-9` is not exactly representable
+        assert_eq!(
+            TimeDelta::seconds(1).div_f64(1e-9),
+            TimeDelta::seconds(1_000_000_000) - TimeDelta::nanoseconds(62)
+        );
+
+        // ties round to even
+        assert_eq!(TimeDelta::nanoseconds(1).div_f64(2.0), TimeDelta::zero());
+        assert_eq!(TimeDelta::nanoseconds(3).div_f64(2.0), TimeDelta::nanoseconds(2));
+        assert_eq!(TimeDelta::nanoseconds(5).div_f64(2.0), TimeDelta::nanoseconds(2));
+        assert_eq!(TimeDelta::nanoseconds(-5).div_f64(2.0), TimeDelta::nanoseconds(-2));
+        assert_eq!(TimeDelta::nanoseconds(5).div_f64(4.0), TimeDelta::nanoseconds(1));
+        assert_eq!(TimeDelta::nanoseconds(6).div_f64(4.0), TimeDelta::nanoseconds(2));
+
+        // large values are exact
+        assert_eq!(MAX.div_f64(1.0), MAX);
+        assert_eq!(MIN.div_f64(1.0), MIN);
+        assert_eq!(
+            TimeDelta::nanoseconds(i64::MAX).div_f64(4.0),
+            TimeDelta::nanoseconds(i64::MAX / 4 + 1)
+        );
+        assert_eq!(MAX.div_f64(1e30), TimeDelta::zero());
+        assert_eq!(MAX.div_f64(f64::MAX), TimeDelta::zero());
+
+        // saturation
+        assert_eq!(MAX.div_f64(0.5), MAX);
+        assert_eq!(MAX.div_f64(-0.5), MIN);
+        assert_eq!(TimeDelta::nanoseconds(1).div_f64(f64::MIN_POSITIVE), MAX);
+        assert_eq!(TimeDelta::nanoseconds(1).div_f64(-5e-324), MIN);
+        assert_eq!(TimeDelta::seconds(1).div_f64(0.0), MAX);
+        assert_eq!(TimeDelta::seconds(1).div_f64(-0.0), MIN);
+        assert_eq!(TimeDelta::seconds(-1).div_f64(0.0), MIN);
+        assert_eq!(TimeDelta::zero().div_f64(0.0), TimeDelta::zero());
+        assert_eq!(TimeDelta::seconds(1).div_f64(f64::INFINITY), TimeDelta::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duration_div_f64_nan() {
+        let _ = TimeDelta::seconds(1).div_f64(f64::NAN);
+    }
+
+    #[test]
+    fn test_std_saturating() {
+        assert_eq!(TimeDelta::seconds(1).to_std_saturating(), StdDuration::new(1, 0));
+        assert_eq!(TimeDelta::nanoseconds(777).to_std_saturating(), StdDuration::new(0, 777));
+        assert_eq!(TimeDelta::zero().to_std_saturating(), StdDuration::ZERO);
+        assert_eq!(TimeDelta::nanoseconds(-1).to_std_saturating(), StdDuration::ZERO);
+        assert_eq!(TimeDelta::seconds(-86401).to_std_saturating(), StdDuration::ZERO);
+        assert_eq!(MIN.to_std_saturating(), StdDuration::ZERO);
+        assert_eq!(MAX.to_std_saturating(), StdDuration::new(9223372036854775, 807000000));
+
+        assert_eq!(TimeDelta::from_std_saturating(StdDuration::new(1, 0)), TimeDelta::seconds(1));
+        assert_eq!(TimeDelta::from_std_saturating(StdDuration::ZERO), TimeDelta::zero());
+        assert_eq!(
+            TimeDelta::from_std_saturating(StdDuration::new(9223372036854775, 807000000)),
+            MAX
+        );
+        assert_eq!(
+            TimeDelta::from_std_saturating(StdDuration::new(9223372036854775, 807000001)),
+            MAX
+        );
+        assert_eq!(TimeDelta::from_std_saturating(StdDuration::MAX), MAX);
+    }
 }
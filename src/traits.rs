@@ -1,4 +1,6 @@
-use crate::{IsoWeek, Weekday};
+use core::fmt;
+
+use crate::{IsoWeek, Month, NaiveDate, Weekday};
 
 /// The common set of methods for date component.
 pub trait Datelike: Sized {
@@ -27,6 +29,20 @@ pub trait Datelike: Sized {
     /// The return value ranges from 0 to 11.
     fn month0(&self) -> u32;
 
+    /// Returns the month as a [`Month`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, Month, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().month_enum(), Month::February);
+    /// ```
+    #[inline]
+    fn month_enum(&self) -> Month {
+        Month::ALL[self.month0() as usize]
+    }
+
     /// Returns the day of month starting from 1.
     ///
     /// The return value ranges from 1 to 31. (The last day of month differs by months.)
@@ -53,6 +69,46 @@ pub trait Datelike: Sized {
     /// Returns the ISO week.
     fn iso_week(&self) -> IsoWeek;
 
+    /// Returns the year number of the ISO week date.
+    ///
+    /// This may differ from [`year`](#tymethod.year) for a few days around the new year: the days
+    /// at the start of January can belong to the last week of the previous ISO year, and the days
+    /// at the end of December to the first week of the next one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(2021, 1, 3).unwrap().iso_week_year(), 2020);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap().iso_week_year(), 2025);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().iso_week_year(), 2024);
+    /// ```
+    #[inline]
+    fn iso_week_year(&self) -> i32 {
+        self.iso_week().year()
+    }
+
+    /// Returns the ISO week date as the ISO year, the week number (1 to 53) and the day of week.
+    ///
+    /// As with [`iso_week_year`](#method.iso_week_year), the ISO year may differ from the
+    /// calendar year near the start of January and the end of December.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate, Weekday};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap();
+    /// assert_eq!(date.iso_week_date(), (2020, 53, Weekday::Sun));
+    /// assert_eq!(date.format("%G-W%V-%u").to_string(), "2020-W53-7");
+    /// ```
+    #[inline]
+    fn iso_week_date(&self) -> (i32, u8, Weekday) {
+        let week = self.iso_week();
+        (week.year(), week.week() as u8, self.weekday())
+    }
+
     /// Makes a new value with the year number changed, while keeping the same month and day.
     ///
     /// Returns `None` when the resulting value would be invalid.
@@ -88,6 +144,99 @@ pub trait Datelike: Sized {
     /// Returns `None` when the resulting value would be invalid.
     fn with_ordinal0(&self, ordinal0: u32) -> Option<Self>;
 
+    /// Makes a new value with the year number changed, while keeping the same month and day.
+    ///
+    /// Same as [`with_year`](#tymethod.with_year), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid.
+    #[inline]
+    fn try_with_year(&self, year: i32) -> Result<Self, DateError> {
+        self.with_year(year).ok_or_else(|| ymd_error(year, self.month(), self.day()))
+    }
+
+    /// Makes a new value with the month number (starting from 1) changed.
+    ///
+    /// Same as [`with_month`](#tymethod.with_month), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateError, Datelike, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+    /// assert_eq!(
+    ///     date.try_with_month(2),
+    ///     Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 31 })
+    /// );
+    /// assert_eq!(date.try_with_month(13), Err(DateError::MonthOutOfRange(13)));
+    /// ```
+    #[inline]
+    fn try_with_month(&self, month: u32) -> Result<Self, DateError> {
+        self.with_month(month).ok_or_else(|| ymd_error(self.year(), month, self.day()))
+    }
+
+    /// Makes a new value with the month number (starting from 0) changed.
+    ///
+    /// Same as [`with_month0`](#tymethod.with_month0), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid. The error uses month numbers starting from 1.
+    #[inline]
+    fn try_with_month0(&self, month0: u32) -> Result<Self, DateError> {
+        self.with_month0(month0)
+            .ok_or_else(|| ymd_error(self.year(), month0.saturating_add(1), self.day()))
+    }
+
+    /// Makes a new value with the day of month (starting from 1) changed.
+    ///
+    /// Same as [`with_day`](#tymethod.with_day), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateError, Datelike, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+    /// assert_eq!(date.try_with_day(28), Ok(NaiveDate::from_ymd_opt(2023, 2, 28).unwrap()));
+    /// assert_eq!(
+    ///     date.try_with_day(31),
+    ///     Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 31 })
+    /// );
+    /// ```
+    #[inline]
+    fn try_with_day(&self, day: u32) -> Result<Self, DateError> {
+        self.with_day(day).ok_or_else(|| ymd_error(self.year(), self.month(), day))
+    }
+
+    /// Makes a new value with the day of month (starting from 0) changed.
+    ///
+    /// Same as [`with_day0`](#tymethod.with_day0), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid. The error uses day numbers starting from 1.
+    #[inline]
+    fn try_with_day0(&self, day0: u32) -> Result<Self, DateError> {
+        self.with_day0(day0)
+            .ok_or_else(|| ymd_error(self.year(), self.month(), day0.saturating_add(1)))
+    }
+
+    /// Makes a new value with the day of year (starting from 1) changed.
+    ///
+    /// Same as [`with_ordinal`](#tymethod.with_ordinal), but returns a [`DateError`]
+    /// describing why the resulting value would be invalid.
+    #[inline]
+    fn try_with_ordinal(&self, ordinal: u32) -> Result<Self, DateError> {
+        self.with_ordinal(ordinal).ok_or_else(|| yo_error(self.year(), ordinal))
+    }
+
+    /// Makes a new value with the day of year (starting from 0) changed.
+    ///
+    /// Same as [`with_ordinal0`](#tymethod.with_ordinal0), but returns a [`DateError`]
+    /// describing why the resulting value would be invalid.
+    /// The error uses day of year numbers starting from 1.
+    #[inline]
+    fn try_with_ordinal0(&self, ordinal0: u32) -> Result<Self, DateError> {
+        self.with_ordinal0(ordinal0)
+            .ok_or_else(|| yo_error(self.year(), ordinal0.saturating_add(1)))
+    }
+
     /// Counts the days in the proleptic Gregorian calendar, with January 1, Year 1 (CE) as day 1.
     ///
     /// # Examples
@@ -116,6 +265,55 @@ pub trait Datelike: Sized {
         ndays += ((year * 1461) >> 2) - div_100 + (div_100 >> 2);
         ndays + self.ordinal() as i32
     }
+
+    /// Returns `true` if this is the first day of the month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert!(NaiveDate::from_ymd_opt(2023, 3, 1).unwrap().is_first_day_of_month());
+    /// assert!(!NaiveDate::from_ymd_opt(2023, 3, 2).unwrap().is_first_day_of_month());
+    /// ```
+    #[inline]
+    fn is_first_day_of_month(&self) -> bool {
+        self.day() == 1
+    }
+
+    /// Returns `true` if this is the last day of the month.
+    ///
+    /// This takes leap years into account, so February 28 is only the last day of the month in
+    /// common years.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert!(NaiveDate::from_ymd_opt(2023, 4, 30).unwrap().is_last_day_of_month());
+    /// assert!(NaiveDate::from_ymd_opt(2023, 2, 28).unwrap().is_last_day_of_month());
+    /// assert!(!NaiveDate::from_ymd_opt(2024, 2, 28).unwrap().is_last_day_of_month());
+    /// ```
+    fn is_last_day_of_month(&self) -> bool {
+        Month::try_from(self.month() as u8)
+            .map_or(false, |month| self.day() == u32::from(month.length(self.year())))
+    }
+
+    /// Returns `true` if this is the last day of the year, December 31.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// assert!(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().is_last_day_of_year());
+    /// assert!(!NaiveDate::from_ymd_opt(2024, 12, 30).unwrap().is_last_day_of_year());
+    /// ```
+    #[inline]
+    fn is_last_day_of_year(&self) -> bool {
+        self.month() == 12 && self.day() == 31
+    }
 }
 
 /// The common set of methods for time component.
@@ -170,6 +368,67 @@ pub trait Timelike: Sized {
     /// the input range can exceed 1,000,000,000 for leap seconds.
     fn with_nanosecond(&self, nano: u32) -> Option<Self>;
 
+    /// Makes a new value with the hour number changed.
+    ///
+    /// Same as [`with_hour`](#tymethod.with_hour), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid.
+    #[inline]
+    fn try_with_hour(&self, hour: u32) -> Result<Self, DateError> {
+        self.with_hour(hour).ok_or(if hour >= 24 {
+            DateError::HourOutOfRange(hour)
+        } else {
+            DateError::InvalidInTimeZone
+        })
+    }
+
+    /// Makes a new value with the minute number changed.
+    ///
+    /// Same as [`with_minute`](#tymethod.with_minute), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid.
+    #[inline]
+    fn try_with_minute(&self, min: u32) -> Result<Self, DateError> {
+        self.with_minute(min).ok_or(if min >= 60 {
+            DateError::MinuteOutOfRange(min)
+        } else {
+            DateError::InvalidInTimeZone
+        })
+    }
+
+    /// Makes a new value with the second number changed.
+    ///
+    /// Same as [`with_second`](#tymethod.with_second), but returns a [`DateError`] describing
+    /// why the resulting value would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateError, NaiveTime, Timelike};
+    ///
+    /// let time = NaiveTime::from_hms_opt(12, 34, 56).unwrap();
+    /// assert_eq!(time.try_with_second(60), Err(DateError::SecondOutOfRange(60)));
+    /// ```
+    #[inline]
+    fn try_with_second(&self, sec: u32) -> Result<Self, DateError> {
+        self.with_second(sec).ok_or(if sec >= 60 {
+            DateError::SecondOutOfRange(sec)
+        } else {
+            DateError::InvalidInTimeZone
+        })
+    }
+
+    /// Makes a new value with nanoseconds since the whole non-leap second changed.
+    ///
+    /// Same as [`with_nanosecond`](#tymethod.with_nanosecond), but returns a [`DateError`]
+    /// describing why the resulting value would be invalid.
+    #[inline]
+    fn try_with_nanosecond(&self, nano: u32) -> Result<Self, DateError> {
+        self.with_nanosecond(nano).ok_or(if nano >= 2_000_000_000 {
+            DateError::NanosecondOutOfRange(nano)
+        } else {
+            DateError::InvalidInTimeZone
+        })
+    }
+
     /// Returns the number of non-leap seconds past the last midnight.
     #[inline]
     fn num_seconds_from_midnight(&self) -> u32 {
@@ -177,10 +436,234 @@ pub trait Timelike: Sized {
     }
 }
 
+/// An error from the `try_with_*` methods of [`Datelike`] and [`Timelike`],
+/// describing why the requested value would be invalid.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
+pub enum DateError {
+    /// The year is outside of the range supported by chrono.
+    YearOutOfRange(i32),
+    /// The month number is not between 1 and 12.
+    MonthOutOfRange(u32),
+    /// The day of month does not exist in the given month.
+    DayOutOfRange {
+        /// The year of the requested date.
+        year: i32,
+        /// The month of the requested date.
+        month: u32,
+        /// The requested day of month.
+        day: u32,
+    },
+    /// The day of year does not exist in the given year.
+    OrdinalOutOfRange {
+        /// The year of the requested date.
+        year: i32,
+        /// The requested day of year.
+        ordinal: u32,
+    },
+    /// The hour number is not between 0 and 23.
+    HourOutOfRange(u32),
+    /// The minute number is not between 0 and 59.
+    MinuteOutOfRange(u32),
+    /// The second number is not between 0 and 59.
+    SecondOutOfRange(u32),
+    /// The number of nanoseconds is 2,000,000,000 or more (even accounting for leap seconds).
+    NanosecondOutOfRange(u32),
+    /// The resulting local date and time does not exist, or is ambiguous, in the time zone.
+    InvalidInTimeZone,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DateError::YearOutOfRange(year) => write!(f, "year {} is out of range", year),
+            DateError::MonthOutOfRange(month) => write!(f, "month {} is out of range", month),
+            DateError::DayOutOfRange { year, month, day } => {
+                write!(f, "day {} is invalid for month {} of year {}", day, month, year)
+            }
+            DateError::OrdinalOutOfRange { year, ordinal } => {
+                write!(f, "day of year {} is invalid for year {}", ordinal, year)
+            }
+            DateError::HourOutOfRange(hour) => write!(f, "hour {} is out of range", hour),
+            DateError::MinuteOutOfRange(min) => write!(f, "minute {} is out of range", min),
+            DateError::SecondOutOfRange(sec) => write!(f, "second {} is out of range", sec),
+            DateError::NanosecondOutOfRange(nano) => {
+                write!(f, "nanosecond {} is out of range", nano)
+            }
+            DateError::InvalidInTimeZone => {
+                write!(f, "local date and time is invalid or ambiguous in the time zone")
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for DateError {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        "error from changing a date or time field"
+    }
+}
+
+/// Works out why the year, month and day do not make a valid date.
+fn ymd_error(year: i32, month: u32, day: u32) -> DateError {
+    if NaiveDate::from_yo_opt(year, 1).is_none() {
+        DateError::YearOutOfRange(year)
+    } else if !(1..=12).contains(&month) {
+        DateError::MonthOutOfRange(month)
+    } else if NaiveDate::from_ymd_opt(year, month, day).is_none() {
+        DateError::DayOutOfRange { year, month, day }
+    } else {
+        // the date itself is fine, so the time zone must have rejected it
+        DateError::InvalidInTimeZone
+    }
+}
+
+/// Works out why the year and day of year do not make a valid date.
+fn yo_error(year: i32, ordinal: u32) -> DateError {
+    if NaiveDate::from_yo_opt(year, 1).is_none() {
+        DateError::YearOutOfRange(year)
+    } else if NaiveDate::from_yo_opt(year, ordinal).is_none() {
+        DateError::OrdinalOutOfRange { year, ordinal }
+    } else {
+        DateError::InvalidInTimeZone
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Datelike;
-    use crate::{NaiveDate, TimeDelta};
+    use super::{DateError, Datelike, Timelike};
+    use crate::{FixedOffset, Month, NaiveDate, NaiveTime, TimeDelta, TimeZone, Weekday};
+
+    #[test]
+    fn test_first_and_last_day_predicates() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // February in leap and common years
+        assert!(ymd(2023, 2, 28).is_last_day_of_month());
+        assert!(!ymd(2024, 2, 28).is_last_day_of_month());
+        assert!(ymd(2024, 2, 29).is_last_day_of_month());
+        assert!(ymd(2000, 2, 29).is_last_day_of_month());
+        assert!(ymd(1900, 2, 28).is_last_day_of_month());
+        assert!(ymd(2024, 3, 1).is_first_day_of_month());
+        assert!(!ymd(2024, 2, 29).is_first_day_of_month());
+
+        for date in ymd(2023, 12, 1).iter_days().take(800) {
+            let next = date.succ_opt().unwrap();
+            assert_eq!(date.is_first_day_of_month(), date.day() == 1);
+            assert_eq!(date.is_last_day_of_month(), next.month() != date.month(), "{}", date);
+            assert_eq!(date.is_last_day_of_year(), next.year() != date.year(), "{}", date);
+        }
+
+        assert!(NaiveDate::MIN.is_first_day_of_month());
+        assert!(NaiveDate::MAX.is_last_day_of_month());
+        assert!(NaiveDate::MAX.is_last_day_of_year());
+
+        // the local date is used
+        let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 1, 1, 0, 30, 0);
+        let dt = dt.unwrap();
+        assert!(dt.is_first_day_of_month());
+        assert!(dt.naive_utc().is_last_day_of_year());
+        assert!(dt.naive_utc().is_last_day_of_month());
+    }
+
+    #[test]
+    fn test_month_enum() {
+        for (i, month) in Month::ALL.iter().enumerate() {
+            let date = NaiveDate::from_ymd_opt(2024, i as u32 + 1, 1).unwrap();
+            assert_eq!(date.month_enum(), *month);
+        }
+        let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 3, 1, 0, 30, 0);
+        assert_eq!(dt.unwrap().month_enum(), Month::March);
+        assert_eq!(dt.unwrap().naive_utc().month_enum(), Month::February);
+    }
+
+    #[test]
+    fn test_iso_week_date() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(ymd(2024, 6, 1).iso_week_date(), (2024, 22, Weekday::Sat));
+        assert_eq!(ymd(2021, 1, 1).iso_week_date(), (2020, 53, Weekday::Fri));
+        assert_eq!(ymd(2021, 1, 4).iso_week_date(), (2021, 1, Weekday::Mon));
+        assert_eq!(ymd(2024, 12, 29).iso_week_date(), (2024, 52, Weekday::Sun));
+        assert_eq!(ymd(2024, 12, 30).iso_week_date(), (2025, 1, Weekday::Mon));
+        assert_eq!(ymd(2026, 12, 31).iso_week_date(), (2026, 53, Weekday::Thu));
+
+        // the parts agree with `iso_week` and `weekday`, and round-trip through `from_isoywd_opt`
+        for date in ymd(2019, 12, 20).iter_days().take(2200) {
+            let (year, week, weekday) = date.iso_week_date();
+            assert_eq!(year, date.iso_week_year());
+            assert_eq!(year, date.iso_week().year());
+            assert_eq!(u32::from(week), date.iso_week().week());
+            assert_eq!(weekday, date.weekday());
+            assert_eq!(NaiveDate::from_isoywd_opt(year, week.into(), weekday), Some(date));
+        }
+
+        // `NaiveDateTime` and `DateTime` use the local date
+        let dt = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2025, 1, 1, 0, 30, 0);
+        assert_eq!(dt.unwrap().iso_week_date(), (2025, 1, Weekday::Wed));
+        assert_eq!(dt.unwrap().naive_local().iso_week_year(), 2025);
+        assert_eq!(dt.unwrap().naive_utc().iso_week_date(), (2025, 1, Weekday::Tue));
+        assert_eq!(dt.unwrap().naive_utc().year(), 2024);
+    }
+
+    #[test]
+    fn test_try_with_date_fields() {
+        let feb = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap();
+        assert_eq!(feb.try_with_day(28), Ok(NaiveDate::from_ymd_opt(2023, 2, 28).unwrap()));
+        assert_eq!(
+            feb.try_with_day(31),
+            Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 31 })
+        );
+        assert_eq!(
+            feb.try_with_day(31).unwrap_err().to_string(),
+            "day 31 is invalid for month 2 of year 2023"
+        );
+        assert_eq!(
+            feb.try_with_day0(28),
+            Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 })
+        );
+        assert_eq!(
+            feb.try_with_day(0),
+            Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 0 })
+        );
+
+        let leap_day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(
+            leap_day.try_with_year(2023),
+            Err(DateError::DayOutOfRange { year: 2023, month: 2, day: 29 })
+        );
+        assert_eq!(leap_day.try_with_year(300_000), Err(DateError::YearOutOfRange(300_000)));
+        assert_eq!(leap_day.try_with_month(0), Err(DateError::MonthOutOfRange(0)));
+        assert_eq!(leap_day.try_with_month0(12), Err(DateError::MonthOutOfRange(13)));
+        assert_eq!(
+            leap_day.try_with_ordinal(366),
+            Ok(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+        );
+        assert_eq!(
+            feb.try_with_ordinal(366),
+            Err(DateError::OrdinalOutOfRange { year: 2023, ordinal: 366 })
+        );
+        assert_eq!(
+            feb.try_with_ordinal0(365),
+            Err(DateError::OrdinalOutOfRange { year: 2023, ordinal: 366 })
+        );
+    }
+
+    #[test]
+    fn test_try_with_time_fields() {
+        let time = NaiveTime::from_hms_opt(12, 34, 56).unwrap();
+        assert_eq!(time.try_with_hour(23), Ok(NaiveTime::from_hms_opt(23, 34, 56).unwrap()));
+        assert_eq!(time.try_with_hour(24), Err(DateError::HourOutOfRange(24)));
+        assert_eq!(time.try_with_minute(60), Err(DateError::MinuteOutOfRange(60)));
+        assert_eq!(time.try_with_second(60), Err(DateError::SecondOutOfRange(60)));
+        assert!(time.try_with_nanosecond(1_500_000_000).is_ok());
+        assert_eq!(
+            time.try_with_nanosecond(2_000_000_000),
+            Err(DateError::NanosecondOutOfRange(2_000_000_000))
+        );
+    }
 
     /// Tests `Datelike::num_days_from_ce` against an alternative implementation.
     ///
@@ -1,4 +1,5 @@
 use core::fmt;
+use core::iter::FusedIterator;
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -50,6 +51,58 @@ pub enum Weekday {
 }
 
 impl Weekday {
+    /// All days of the week, starting with Monday.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// let names: Vec<_> = Weekday::ALL.iter().map(|w| w.to_string()).collect();
+    /// assert_eq!(names, ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]);
+    /// ```
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+
+    /// Returns an iterator over the seven days of the week, beginning at `start` and wrapping
+    /// around.
+    ///
+    /// The iterator always yields exactly seven weekdays.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// let week: Vec<_> = Weekday::iter_from(Weekday::Sun).collect();
+    /// assert_eq!(
+    ///     week,
+    ///     [
+    ///         Weekday::Sun,
+    ///         Weekday::Mon,
+    ///         Weekday::Tue,
+    ///         Weekday::Wed,
+    ///         Weekday::Thu,
+    ///         Weekday::Fri,
+    ///         Weekday::Sat
+    ///     ]
+    /// );
+    /// assert_eq!(Weekday::iter_from(Weekday::Thu).len(), 7);
+    /// ```
+    #[inline]
+    pub fn iter_from(
+        start: Weekday,
+    ) -> impl ExactSizeIterator<Item = Weekday> + DoubleEndedIterator + FusedIterator + Clone {
+        (0..7).map(move |n| Weekday::ALL[(start as usize + n) % 7])
+    }
+
     /// The next day in the week.
     ///
     /// `w`:        | `Mon` | `Tue` | `Wed` | `Thu` | `Fri` | `Sat` | `Sun`
@@ -128,6 +181,24 @@ impl Weekday {
         self.num_days_from(Weekday::Sun)
     }
 
+    /// The number of days since the given day.
+    ///
+    /// This is the number of days one has to go forward from `other` to reach `self`,
+    /// in the range `0..=6`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday::*;
+    /// assert_eq!(Mon.days_since(Mon), 0);
+    /// assert_eq!(Sun.days_since(Tue), 5);
+    /// assert_eq!(Wed.days_since(Sun), 3);
+    /// ```
+    #[inline]
+    pub const fn days_since(&self, other: Weekday) -> u32 {
+        self.num_days_from(other)
+    }
+
     /// Returns a day-of-week number starting from the parameter `day` (D) = 0.
     ///
     /// `w`:                        | `D`   | `D+1` | `D+2` | `D+3` | `D+4` | `D+5` | `D+6`
@@ -230,6 +301,9 @@ mod weekday_serde {
         }
     }
 
+    /// Deserializes a weekday from any name accepted by [`FromStr`](core::str::FromStr): the
+    /// short or long English name in any case, or one of the abbreviations "Tues", "Thur" and
+    /// "Thurs".
     impl<'de> de::Deserialize<'de> for Weekday {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
@@ -276,6 +350,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_weekday_all() {
+        assert_eq!(Weekday::ALL.len(), 7);
+        assert_eq!(Weekday::ALL[0], Weekday::Mon);
+        for (i, weekday) in Weekday::ALL.iter().enumerate() {
+            assert_eq!(weekday.num_days_from_monday(), i as u32);
+            assert_eq!(Weekday::try_from(i as u8), Ok(*weekday));
+            assert_eq!(Weekday::ALL[(i + 1) % 7], weekday.succ());
+        }
+    }
+
+    #[test]
+    fn test_iter_from() {
+        for start in Weekday::ALL {
+            let mut iter = Weekday::iter_from(start);
+            assert_eq!(iter.len(), 7);
+            let mut expected = start;
+            for remaining in (0..7).rev() {
+                assert_eq!(iter.next(), Some(expected));
+                assert_eq!(iter.len(), remaining);
+                expected = expected.succ();
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+
+            assert_eq!(Weekday::iter_from(start).next_back(), Some(start.pred()));
+        }
+    }
+
+    #[test]
+    fn test_days_since() {
+        for i in 0..7 {
+            let base_day = Weekday::try_from(i).unwrap();
+            for j in 0..7 {
+                let other_day = Weekday::try_from(j).unwrap();
+                let expected = (i as u32 + 7 - j as u32) % 7;
+                assert_eq!(base_day.days_since(other_day), expected);
+
+                // going forward `days_since` days from `other_day` gives `base_day`
+                let mut day = other_day;
+                for _ in 0..base_day.days_since(other_day) {
+                    day = day.succ();
+                }
+                assert_eq!(day, base_day);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weekday_const_fns() {
+        // A lookup table of the weekday `n` days after each weekday, built at compile time.
+        const fn days_after(n: u32) -> [Weekday; 7] {
+            let mut table = Weekday::ALL;
+            let mut i = 0;
+            while i < 7 {
+                let mut j = 0;
+                while j < n % 7 {
+                    table[i] = table[i].succ();
+                    j += 1;
+                }
+                i += 1;
+            }
+            table
+        }
+        const AFTER_TWO: [Weekday; 7] = days_after(2);
+        const SAT: Weekday = Weekday::Mon.pred().pred();
+        const SAT_FROM_MONDAY: u32 = SAT.number_from_monday();
+        const SAT_DAYS_FROM_MONDAY: u32 = SAT.num_days_from_monday();
+        const SAT_FROM_SUNDAY: u32 = SAT.succ().pred().number_from_sunday();
+        const SAT_DAYS_FROM_SUNDAY: u32 = SAT.num_days_from_sunday();
+
+        assert_eq!(SAT, Weekday::Sat);
+        assert_eq!(SAT_FROM_MONDAY, 6);
+        assert_eq!(SAT_DAYS_FROM_MONDAY, 5);
+        assert_eq!(SAT_FROM_SUNDAY, 7);
+        assert_eq!(SAT_DAYS_FROM_SUNDAY, 6);
+        for day in Weekday::ALL {
+            assert_eq!(AFTER_TWO[day.num_days_from_monday() as usize], day.succ().succ());
+        }
+    }
+
+    #[test]
+    fn test_weekday_from_str() {
+        let names = [
+            ("mon", "monday", Weekday::Mon),
+            ("tue", "tuesday", Weekday::Tue),
+            ("wed", "wednesday", Weekday::Wed),
+            ("thu", "thursday", Weekday::Thu),
+            ("fri", "friday", Weekday::Fri),
+            ("sat", "saturday", Weekday::Sat),
+            ("sun", "sunday", Weekday::Sun),
+        ];
+        for (short, long, weekday) in names {
+            assert_eq!(short.parse::<Weekday>(), Ok(weekday));
+            assert_eq!(long.parse::<Weekday>(), Ok(weekday));
+            assert_eq!(short.to_uppercase().parse::<Weekday>(), Ok(weekday));
+            assert_eq!(long.to_uppercase().parse::<Weekday>(), Ok(weekday));
+            assert_eq!(weekday.to_string().parse::<Weekday>(), Ok(weekday));
+
+            // trailing input is not accepted
+            assert!(format!("{} ", short).parse::<Weekday>().is_err());
+            assert!(format!("{}x", long).parse::<Weekday>().is_err());
+            assert!(format!(" {}", long).parse::<Weekday>().is_err());
+        }
+
+        assert!("".parse::<Weekday>().is_err());
+        assert!("mo".parse::<Weekday>().is_err());
+        assert!("satur".parse::<Weekday>().is_err());
+        assert!("wednes".parse::<Weekday>().is_err());
+        assert_eq!("Tues".parse::<Weekday>(), Ok(Weekday::Tue));
+        assert_eq!("Thur".parse::<Weekday>(), Ok(Weekday::Thu));
+        assert_eq!("Thurs".parse::<Weekday>(), Ok(Weekday::Thu));
+        assert!("1".parse::<Weekday>().is_err());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serde_serialize() {
@@ -321,6 +510,9 @@ mod tests {
             ("\"saturday\"", Sat),
             ("\"sun\"", Sun),
             ("\"sunday\"", Sun),
+            ("\"tues\"", Tue),
+            ("\"thur\"", Thu),
+            ("\"THURS\"", Thu),
         ];
 
         for (str, expected_weekday) in cases {
@@ -329,7 +521,7 @@ mod tests {
         }
 
         let errors: Vec<&str> =
-            vec!["\"not a weekday\"", "\"monDAYs\"", "\"mond\"", "mon", "\"thur\"", "\"thurs\""];
+            vec!["\"not a weekday\"", "\"monDAYs\"", "\"mond\"", "mon", "\"thursd\"", "\"weds\""];
 
         for str in errors {
             from_str::<Weekday>(str).unwrap_err();
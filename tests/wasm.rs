@@ -62,6 +62,15 @@ fn from_is_exact() {
     assert_eq!(now.get_time() as i64, dt.timestamp_millis());
 }
 
+#[wasm_bindgen_test]
+fn js_millis_match_get_time() {
+    let now = js_sys::Date::new_0();
+
+    let dt = DateTime::<Utc>::from_js_millis(now.get_time()).unwrap();
+    assert_eq!(dt.to_js_millis(), now.get_time());
+    assert_eq!(js_sys::Date::from(dt).get_time(), now.get_time());
+}
+
 #[wasm_bindgen_test]
 fn local_from_local_datetime() {
     let now = Local::now();